@@ -1,6 +1,10 @@
+use std::env;
 use std::process::Command;
 use std::str;
 
+/// Publisher keys trusted to sign modpack manifests, see `core::manifest_signature`
+const MANIFEST_KEYS_ENV: &str = "MODPACKSTORE_MANIFEST_KEYS";
+
 fn main() {
     let output = Command::new("git")
         .args(["rev-parse", "--short", "HEAD"])
@@ -22,5 +26,36 @@ fn main() {
 
     println!("cargo:rustc-env=GIT_HASH_BUILD_TIME={}", git_hash);
 
+    check_manifest_keys();
+
     tauri_build::build()
 }
+
+/// A release built without pinned publisher keys would reject every signed manifest,
+/// so refuse to produce one. Debug builds only get a warning.
+fn check_manifest_keys() {
+    println!("cargo:rerun-if-env-changed={}", MANIFEST_KEYS_ENV);
+
+    let raw = env::var(MANIFEST_KEYS_ENV).unwrap_or_default();
+    let pinned = raw
+        .split(',')
+        .filter_map(|entry| entry.trim().split_once(':'))
+        .filter(|(id, key)| !id.trim().is_empty() && !key.trim().is_empty())
+        .count();
+
+    if pinned > 0 {
+        return;
+    }
+
+    if env::var("PROFILE").as_deref() == Ok("release") {
+        panic!(
+            "{} must list at least one `keyId:base64PublicKey` entry for release builds",
+            MANIFEST_KEYS_ENV
+        );
+    }
+
+    println!(
+        "cargo:warning={} is not set: this build trusts no manifest publisher keys",
+        MANIFEST_KEYS_ENV
+    );
+}
@@ -175,6 +175,53 @@ impl ConfigManager {
             .and_then(Value::as_u64)
             .map(|v| v as u32)
     }
+
+    pub fn get_fabric_meta_url(&self) -> String {
+        self.get("fabricMetaUrl")
+            .and_then(Value::as_str)
+            .filter(|url| !url.trim().is_empty())
+            .unwrap_or(crate::core::bootstrap::fabric::DEFAULT_FABRIC_META_URL)
+            .to_string()
+    }
+
+    /// Proveedor desde el que se descargan los runtimes de Java
+    pub fn get_java_provider(&self) -> crate::core::java_manager::JavaProvider {
+        self.get("javaProvider")
+            .and_then(Value::as_str)
+            .map(crate::core::java_manager::JavaProvider::from_name)
+            .unwrap_or(crate::core::java_manager::JavaProvider::Adoptium)
+    }
+
+    /// Comandos que envuelven la invocación de Java (p. ej. `gamemoderun`), en orden
+    pub fn get_launch_wrappers(&self) -> Vec<String> {
+        self.get("launchWrappers")
+            .and_then(Value::as_array)
+            .map(|wrappers| {
+                wrappers
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter(|wrapper| !wrapper.trim().is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Tiempo durante el que los metadatos en caché se usan sin revalidarlos
+    pub fn get_metadata_cache_ttl(&self) -> std::time::Duration {
+        self.get("metadataCacheTtl")
+            .and_then(Value::as_u64)
+            .map(|minutes| std::time::Duration::from_secs(minutes * 60))
+            .unwrap_or(crate::core::bootstrap::meta_cache::DEFAULT_METADATA_TTL)
+    }
+
+    pub fn get_quilt_meta_url(&self) -> String {
+        self.get("quiltMetaUrl")
+            .and_then(Value::as_str)
+            .filter(|url| !url.trim().is_empty())
+            .unwrap_or(crate::core::bootstrap::quilt::DEFAULT_QUILT_META_URL)
+            .to_string()
+    }
 }
 
 // Convierte un Value en un HashMap
@@ -1,6 +1,7 @@
 // src/core/bootstrap/download.rs
 // Download-related functionality extracted from instance_bootstrap.rs
 
+use crate::core::bootstrap::store::{is_valid_sha1, SharedStore};
 use crate::core::bootstrap::tasks::{emit_status, emit_status_with_stage, Stage};
 use crate::core::minecraft_instance::MinecraftInstance;
 use crate::core::modpack_file_manager::DownloadManager;
@@ -58,32 +59,30 @@ pub async fn download_file_with_manager(
     destination: &Path,
     expected_hash: Option<&str>,
 ) -> Result<(), String> {
-    match expected_hash {
-        Some(hash) => {
-            download_manager
-                .download_file_with_hash(url, destination, hash)
-                .await
-        }
-        None => {
-            // If no hash is provided, we still use the DownloadManager but with a dummy hash
-            // and just verify the file was downloaded successfully
-            let dummy_hash = "0000000000000000000000000000000000000000";
-            match download_manager
-                .download_file_with_hash(url, destination, dummy_hash)
-                .await
-            {
-                Ok(()) => Ok(()),
-                Err(e) if e.contains("Hash mismatch") => {
-                    // Ignore hash mismatch errors when no hash was expected
-                    if destination.exists() {
-                        Ok(())
-                    } else {
-                        Err("File was not downloaded successfully".to_string())
-                    }
-                }
-                Err(e) => Err(e),
-            }
+    // An empty hash tells the DownloadManager to skip verification
+    download_manager
+        .download_file_with_hash(url, destination, expected_hash.unwrap_or(""))
+        .await
+}
+
+/// Places a file at `target_path`, going through the shared store when its SHA-1 is
+/// known so identical files are kept only once across instances.
+/// Falls back to a plain download when there is no store or no usable hash.
+pub fn download_file_via_store(
+    client: &reqwest::blocking::Client,
+    store: Option<&SharedStore>,
+    url: &str,
+    sha1: Option<&str>,
+    target_path: &Path,
+    instance: &MinecraftInstance,
+) -> Result<(), String> {
+    match (store, sha1.filter(|hash| is_valid_sha1(hash))) {
+        (Some(store), Some(hash)) => {
+            store.materialize(hash, target_path, &instance.instanceId, |staging| {
+                download_file(client, url, staging)
+            })
         }
+        _ => download_file(client, url, target_path),
     }
 }
 
@@ -102,6 +101,10 @@ pub fn download_libraries(
     let mut downloaded_libraries = 0;
     let mut skipped_libraries = 0;
 
+    let store = SharedStore::open()
+        .map_err(|e| log::warn!("Shared store unavailable, using per-instance files: {}", e))
+        .ok();
+
     // Pre-scan to compute how many libraries will be skipped so we can use a stable total
     let is_allowed = |library: &Value| -> bool {
         if let Some(rules) = library.get("rules") {
@@ -218,8 +221,15 @@ pub fn download_libraries(
                         &format!("Descargando librería: {}", artifact_path),
                     );
 
-                    download_file(client, artifact_url, &target_path)
-                        .map_err(|e| format!("Error downloading library: {}", e))?;
+                    download_file_via_store(
+                        client,
+                        store.as_ref(),
+                        artifact_url,
+                        artifact["sha1"].as_str(),
+                        &target_path,
+                        instance,
+                    )
+                    .map_err(|e| format!("Error downloading library: {}", e))?;
                 } else {
                     emit_status(
                         instance,
@@ -284,8 +294,15 @@ pub fn download_libraries(
                                 &format!("Descargando biblioteca nativa: {}", classifier_path),
                             );
 
-                            download_file(client, classifier_url, &target_path)
-                                .map_err(|e| format!("Error downloading native library: {}", e))?;
+                            download_file_via_store(
+                                client,
+                                store.as_ref(),
+                                classifier_url,
+                                classifier_info["sha1"].as_str(),
+                                &target_path,
+                                instance,
+                            )
+                            .map_err(|e| format!("Error downloading native library: {}", e))?;
                         } else {
                             emit_status(
                                 instance,
@@ -404,6 +421,10 @@ pub fn download_forge_libraries(
     let total_libraries = libraries.len();
     let mut downloaded_libraries = 0;
 
+    let store = SharedStore::open()
+        .map_err(|e| log::warn!("Shared store unavailable, using per-instance files: {}", e))
+        .ok();
+
     // Pre-scan to compute how many forge libraries will be skipped so we can use a stable total
     let is_allowed = |library: &Value| -> bool {
         if let Some(rules) = library.get("rules") {
@@ -506,8 +527,15 @@ pub fn download_forge_libraries(
 
                 // Descargar si el archivo no existe
                 if !target_path.exists() {
-                    download_file(client, url, &target_path)
-                        .map_err(|e| format!("Error al descargar librería: {}", e))?;
+                    download_file_via_store(
+                        client,
+                        store.as_ref(),
+                        url,
+                        artifact["sha1"].as_str(),
+                        &target_path,
+                        instance,
+                    )
+                    .map_err(|e| format!("Error al descargar librería: {}", e))?;
                 }
             }
 
@@ -539,8 +567,15 @@ pub fn download_forge_libraries(
 
                     // Descargar si el archivo no existe
                     if !target_path.exists() {
-                        download_file(client, url, &target_path)
-                            .map_err(|e| format!("Error al descargar librería nativa: {}", e))?;
+                        download_file_via_store(
+                            client,
+                            store.as_ref(),
+                            url,
+                            native["sha1"].as_str(),
+                            &target_path,
+                            instance,
+                        )
+                        .map_err(|e| format!("Error al descargar librería nativa: {}", e))?;
                     }
                 }
             }
@@ -648,8 +683,9 @@ pub async fn download_libraries_enhanced(
                 {
                     let target_path = libraries_dir.join(artifact_path);
 
-                    // Only download if file doesn't exist
-                    if !target_path.exists() {
+                    // Only download if file doesn't exist. Forge lists the artifacts its
+                    // installer bundles or generates with an empty URL.
+                    if !target_path.exists() && !artifact_url.is_empty() {
                         // Extract hash if available for verification
                         let expected_hash = artifact["sha1"].as_str().unwrap_or("");
 
@@ -692,11 +728,9 @@ pub async fn download_libraries_enhanced(
                 build_maven_download_info(name, library, libraries_dir)
             {
                 if !target_path.exists() {
-                    downloads_to_process.push((
-                        url,
-                        target_path,
-                        String::new(), // No hash available for Maven downloads
-                    ));
+                    // Fabric-style entries list the hash next to the name; Forge ones don't
+                    let expected_hash = library["sha1"].as_str().unwrap_or("");
+                    downloads_to_process.push((url, target_path, expected_hash.to_string()));
                 }
             }
         }
@@ -881,7 +915,7 @@ pub async fn download_forge_libraries_enhanced(
 }
 
 /// Helper function to check if a library should be downloaded based on OS rules
-fn is_library_allowed(library: &Value) -> bool {
+pub(crate) fn is_library_allowed(library: &Value) -> bool {
     if let Some(rules) = library.get("rules") {
         let mut allowed = false;
         for rule in rules.as_array().unwrap_or(&Vec::new()) {
@@ -1174,6 +1208,10 @@ mod tests {
             instanceDirectory: Some(temp_dir.path().to_string_lossy().to_string()),
             forgeVersion: None,
             javaPath: None,
+            javaPathPinned: false,
+            loader: None,
+            loaderVersion: None,
+            launchOptions: Default::default(),
         };
 
         let version_details = json!({
@@ -0,0 +1,135 @@
+// src/core/bootstrap/fabric.rs
+// Fabric loader installation. Fabric Meta serves a launcher profile that inherits from
+// the vanilla version, so it is stored under versions/ like any other version JSON and
+// merged with vanilla by `ManifestMerger` both here and at launch time.
+
+use super::manifest::{fetch_loader_profile, is_valid_loader_version};
+use serde_json::Value;
+use tauri_plugin_http::reqwest;
+
+pub const DEFAULT_FABRIC_META_URL: &str = "https://meta.fabricmc.net";
+
+/// Version id Fabric uses for a loader profile, e.g. `fabric-loader-0.15.11-1.20.1`
+pub fn fabric_version_id(minecraft_version: &str, loader_version: &str) -> String {
+    format!("fabric-loader-{}-{}", loader_version, minecraft_version)
+}
+
+/// Builds the Fabric Meta URL of the launcher profile for a Minecraft/loader pair
+pub fn fabric_profile_url(meta_url: &str, minecraft_version: &str, loader_version: &str) -> String {
+    format!(
+        "{}/v2/versions/loader/{}/{}/profile/json",
+        meta_url.trim_end_matches('/'),
+        minecraft_version,
+        loader_version
+    )
+}
+
+/// Downloads and validates the Fabric launcher profile from `meta_url`
+pub fn fetch_fabric_profile(
+    client: &reqwest::blocking::Client,
+    meta_url: &str,
+    minecraft_version: &str,
+    loader_version: &str,
+) -> Result<Value, String> {
+    if !is_valid_loader_version(loader_version) {
+        return Err(format!("Versión de Fabric inválida: {}", loader_version));
+    }
+
+    fetch_loader_profile(
+        client,
+        &fabric_profile_url(meta_url, minecraft_version, loader_version),
+        &fabric_version_id(minecraft_version, loader_version),
+        minecraft_version,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bootstrap::manifest::{
+        install_version_json, merge_with_vanilla, validate_loader_profile,
+    };
+    use serde_json::json;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn sample_profile() -> Value {
+        json!({
+            "id": "fabric-loader-0.15.11-1.20.1",
+            "inheritsFrom": "1.20.1",
+            "type": "release",
+            "mainClass": "net.fabricmc.loader.impl.launch.knot.KnotClient",
+            "arguments": { "game": [], "jvm": ["-DFabricMcEmu= net.minecraft.client.main.Main "] },
+            "libraries": [
+                {
+                    "name": "net.fabricmc:fabric-loader:0.15.11",
+                    "url": "https://maven.fabricmc.net/",
+                    "sha1": "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d"
+                },
+                { "name": "org.ow2.asm:asm:9.6", "url": "https://maven.fabricmc.net/" }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_profile_url_and_version_id() {
+        assert_eq!(
+            fabric_profile_url("https://meta.fabricmc.net/", "1.20.1", "0.15.11"),
+            "https://meta.fabricmc.net/v2/versions/loader/1.20.1/0.15.11/profile/json"
+        );
+        assert_eq!(
+            fabric_version_id("1.20.1", "0.15.11"),
+            "fabric-loader-0.15.11-1.20.1"
+        );
+    }
+
+    #[test]
+    fn test_validate_fabric_profile() {
+        let profile = sample_profile();
+        let validate = |mc: &str, loader: &str| {
+            validate_loader_profile(&profile, &fabric_version_id(mc, loader), mc)
+        };
+        assert!(validate("1.20.1", "0.15.11").is_ok());
+        assert!(validate("1.20.1", "0.14.0").is_err());
+        assert!(validate("1.19.4", "0.15.11").is_err());
+    }
+
+    #[test]
+    fn test_install_and_merge_with_vanilla() {
+        let dir = tempdir().unwrap();
+        let versions_dir = dir.path().join("versions");
+        fs::create_dir_all(versions_dir.join("1.20.1")).unwrap();
+        fs::write(
+            versions_dir.join("1.20.1/1.20.1.json"),
+            json!({
+                "id": "1.20.1",
+                "mainClass": "net.minecraft.client.main.Main",
+                "arguments": { "game": ["--username", "${auth_player_name}"], "jvm": ["-cp", "${classpath}"] },
+                "libraries": [{ "name": "com.mojang:brigadier:1.1.8" }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let profile = sample_profile();
+        let path = install_version_json(
+            &versions_dir,
+            &fabric_version_id("1.20.1", "0.15.11"),
+            &profile,
+        )
+        .unwrap();
+        assert_eq!(
+            path,
+            versions_dir.join("fabric-loader-0.15.11-1.20.1/fabric-loader-0.15.11-1.20.1.json")
+        );
+
+        let merged = merge_with_vanilla(&versions_dir, &profile).unwrap();
+        assert_eq!(
+            merged["mainClass"],
+            "net.fabricmc.loader.impl.launch.knot.KnotClient"
+        );
+        assert_eq!(merged["libraries"].as_array().unwrap().len(), 3);
+        assert_eq!(merged["arguments"]["game"].as_array().unwrap().len(), 2);
+        assert_eq!(merged["arguments"]["jvm"].as_array().unwrap().len(), 3);
+    }
+}
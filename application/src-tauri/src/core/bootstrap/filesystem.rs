@@ -3,6 +3,7 @@
 
 use crate::core::bootstrap::tasks::{emit_status, emit_status_with_stage, Stage};
 use crate::core::minecraft_instance::MinecraftInstance;
+use crate::utils::safe_path::resolve_within;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
@@ -840,7 +841,8 @@ fn extract_jar_file(
         // Obtenemos solo el nombre del archivo final (ej: "lwjgl.dll")
         if let Some(file_name_only) = full_path_in_zip.file_name() {
             // Construimos la ruta de salida directamente en el directorio de destino
-            let output_path = target_dir.join(file_name_only);
+            let output_path = resolve_within(target_dir, &file_name_only.to_string_lossy())
+                .map_err(|e| format!("Entrada insegura en {}: {}", jar_path.display(), e))?;
 
             // Ya no es necesario crear directorios padres, porque estamos aplanando la estructura
             // if let Some(parent) = output_path.parent() { ... } // <- Esta parte se elimina o se vuelve innecesaria.
@@ -934,7 +936,8 @@ fn extract_jar_file_enhanced(
 
         // Extract only the final filename (flattening the directory structure)
         if let Some(file_name_only) = full_path_in_zip.file_name() {
-            let output_path = target_dir.join(file_name_only);
+            let output_path = resolve_within(target_dir, &file_name_only.to_string_lossy())
+                .map_err(|e| format!("Entrada insegura en {}: {}", jar_path.display(), e))?;
 
             // Check for duplicates
             if output_path.exists() {
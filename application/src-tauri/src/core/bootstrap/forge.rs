@@ -0,0 +1,747 @@
+// src/core/bootstrap/forge.rs
+// Native Forge installation. Reads `install_profile.json` and `version.json` from the
+// installer jar and runs its processors directly instead of shelling out to the
+// installer's own GUI/CLI. Legacy installers (1.7.10–1.12.2) carry the version JSON in
+// `versionInfo` and only need the universal jar copied into the libraries directory.
+
+use super::store::sha1_of_file;
+use crate::utils::safe_path::resolve_within;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A `processors[]` entry of `install_profile.json`
+#[derive(Deserialize, Debug, Clone)]
+pub struct ForgeProcessor {
+    pub jar: String,
+    #[serde(default)]
+    pub classpath: Vec<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub outputs: HashMap<String, String>,
+    pub sides: Option<Vec<String>>,
+}
+
+impl ForgeProcessor {
+    fn runs_on_client(&self) -> bool {
+        self.sides
+            .as_ref()
+            .is_none_or(|sides| sides.iter().any(|side| side == "client"))
+    }
+}
+
+/// Version id Forge instances are installed under, e.g. `1.12.2-forge-14.23.5.2859`
+pub fn forge_version_id(minecraft_version: &str, forge_version: &str) -> String {
+    format!("{}-forge-{}", minecraft_version, forge_version)
+}
+
+/// Locations the installer's built-in data entries (`{ROOT}`, `{MINECRAFT_JAR}`...) point at
+pub struct ForgeInstallContext<'a> {
+    pub minecraft_dir: &'a Path,
+    pub libraries_dir: &'a Path,
+    pub minecraft_version: &'a str,
+    pub minecraft_jar: &'a Path,
+}
+
+/// Contents of a Forge installer jar
+pub struct ForgeInstaller {
+    installer_path: PathBuf,
+    pub install_profile: Value,
+    pub version_json: Value,
+}
+
+impl ForgeInstaller {
+    pub fn open(installer_path: &Path) -> Result<Self, String> {
+        let mut archive = open_archive(installer_path)?;
+
+        let install_profile: Value =
+            serde_json::from_str(&read_entry(&mut archive, "install_profile.json")?)
+                .map_err(|e| format!("install_profile.json inválido: {}", e))?;
+
+        if install_profile.get("versionInfo").is_some() {
+            let version_json = legacy_version_json(&install_profile)?;
+            return Ok(Self {
+                installer_path: installer_path.to_path_buf(),
+                install_profile,
+                version_json,
+            });
+        }
+
+        let version_entry = install_profile["json"]
+            .as_str()
+            .unwrap_or("/version.json")
+            .trim_start_matches('/')
+            .to_string();
+        let version_json: Value = serde_json::from_str(&read_entry(&mut archive, &version_entry)?)
+            .map_err(|e| format!("{} inválido: {}", version_entry, e))?;
+
+        if version_json["id"].as_str().is_none() {
+            return Err(format!("{} no indica el id de la versión", version_entry));
+        }
+
+        Ok(Self {
+            installer_path: installer_path.to_path_buf(),
+            install_profile,
+            version_json,
+        })
+    }
+
+    /// Id of the version the installer creates, e.g. `1.20.1-forge-47.2.0`
+    pub fn version_id(&self) -> &str {
+        self.version_json["id"].as_str().unwrap_or_default()
+    }
+
+    /// Pre-1.13 installers: no processors, the version JSON lives in `versionInfo`
+    pub fn is_legacy(&self) -> bool {
+        self.install_profile.get("versionInfo").is_some()
+    }
+
+    /// Processors that apply to the client, in execution order
+    pub fn client_processors(&self) -> Result<Vec<ForgeProcessor>, String> {
+        let processors: Vec<ForgeProcessor> = match self.install_profile.get("processors") {
+            Some(processors) => serde_json::from_value(processors.clone())
+                .map_err(|e| format!("Procesadores de Forge inválidos: {}", e))?,
+            None => Vec::new(),
+        };
+        Ok(processors
+            .into_iter()
+            .filter(ForgeProcessor::runs_on_client)
+            .collect())
+    }
+
+    /// Copies the libraries bundled under `maven/` (or the legacy universal jar) into the
+    /// libraries directory
+    pub fn extract_embedded_libraries(&self, libraries_dir: &Path) -> Result<usize, String> {
+        let mut archive = open_archive(&self.installer_path)?;
+
+        if self.is_legacy() {
+            let install = &self.install_profile["install"];
+            let (Some(file_path), Some(coordinate)) =
+                (install["filePath"].as_str(), install["path"].as_str())
+            else {
+                return Err("install_profile.json no indica el jar universal de Forge".into());
+            };
+            let mut entry = archive
+                .by_name(file_path)
+                .map_err(|e| format!("{} no está en el instalador: {}", file_path, e))?;
+            write_entry(&mut entry, &artifact_path(libraries_dir, coordinate)?)?;
+            return Ok(1);
+        }
+
+        let mut extracted = 0;
+
+        for i in 0..archive.len() {
+            let mut entry = archive
+                .by_index(i)
+                .map_err(|e| format!("Error leyendo instalador de Forge: {}", e))?;
+            let Some(relative) = entry.name().strip_prefix("maven/").map(str::to_string) else {
+                continue;
+            };
+            if entry.is_dir() || relative.is_empty() {
+                continue;
+            }
+
+            let target = resolve_within(libraries_dir, &relative)?;
+            write_entry(&mut entry, &target)?;
+            extracted += 1;
+        }
+
+        Ok(extracted)
+    }
+
+    /// Resolves the `data` section for the client side. Files embedded in the installer
+    /// (e.g. `/data/client.lzma`) are extracted below `work_dir`.
+    pub fn resolve_data(
+        &self,
+        context: &ForgeInstallContext,
+        work_dir: &Path,
+    ) -> Result<HashMap<String, String>, String> {
+        let mut data = HashMap::new();
+        data.insert("SIDE".to_string(), "client".to_string());
+        data.insert(
+            "MINECRAFT_JAR".to_string(),
+            context.minecraft_jar.to_string_lossy().to_string(),
+        );
+        data.insert(
+            "MINECRAFT_VERSION".to_string(),
+            context.minecraft_version.to_string(),
+        );
+        data.insert(
+            "ROOT".to_string(),
+            context.minecraft_dir.to_string_lossy().to_string(),
+        );
+        data.insert(
+            "INSTALLER".to_string(),
+            self.installer_path.to_string_lossy().to_string(),
+        );
+        data.insert(
+            "LIBRARY_DIR".to_string(),
+            context.libraries_dir.to_string_lossy().to_string(),
+        );
+
+        let Some(entries) = self.install_profile["data"].as_object() else {
+            return Ok(data);
+        };
+
+        let mut archive = open_archive(&self.installer_path)?;
+        for (key, sides) in entries {
+            let Some(value) = sides["client"].as_str().filter(|value| !value.is_empty()) else {
+                continue;
+            };
+
+            let resolved = if is_artifact_reference(value) || is_literal(value) {
+                substitute(value, &data, context.libraries_dir)?
+            } else {
+                let relative = value.trim_start_matches('/');
+                let target = resolve_within(work_dir, relative)?;
+                let mut entry = archive
+                    .by_name(relative)
+                    .map_err(|e| format!("{} no está en el instalador: {}", value, e))?;
+                write_entry(&mut entry, &target)?;
+                target.to_string_lossy().to_string()
+            };
+            data.insert(key.clone(), resolved);
+        }
+
+        Ok(data)
+    }
+}
+
+/// Mojang's library host, used by launchers for entries without a `url`
+const MOJANG_LIBRARIES_URL: &str = "https://libraries.minecraft.net/";
+
+/// Builds a launcher version JSON from a legacy `versionInfo`. Old installers point at
+/// the retired `files.minecraftforge.net` maven and 1.7.10 doesn't declare `inheritsFrom`.
+fn legacy_version_json(install_profile: &Value) -> Result<Value, String> {
+    let mut version_json = install_profile["versionInfo"].clone();
+    if version_json["id"].as_str().is_none() || !version_json["libraries"].is_array() {
+        return Err("versionInfo no contiene id o libraries".into());
+    }
+
+    if version_json.get("inheritsFrom").is_none() {
+        let minecraft = install_profile["install"]["minecraft"]
+            .as_str()
+            .ok_or("install_profile.json no indica la versión de Minecraft")?;
+        version_json["inheritsFrom"] = Value::String(minecraft.to_string());
+    }
+
+    for library in version_json["libraries"]
+        .as_array_mut()
+        .into_iter()
+        .flatten()
+    {
+        let url = match library["url"].as_str() {
+            Some(url) if url.contains("files.minecraftforge.net/maven") => {
+                "https://maven.minecraftforge.net/"
+            }
+            Some(_) => continue,
+            None => MOJANG_LIBRARIES_URL,
+        };
+        library["url"] = Value::String(url.to_string());
+    }
+
+    Ok(version_json)
+}
+
+/// Runs every client processor in order. Processors whose outputs already exist with the
+/// expected hashes are skipped; `on_progress` gets `(current, total, jar)` before each one.
+pub fn run_processors<F>(
+    java_path: &str,
+    processors: &[ForgeProcessor],
+    data: &HashMap<String, String>,
+    libraries_dir: &Path,
+    mut on_progress: F,
+) -> Result<(), String>
+where
+    F: FnMut(usize, usize, &str),
+{
+    let total = processors.len();
+
+    for (index, processor) in processors.iter().enumerate() {
+        on_progress(index + 1, total, &processor.jar);
+
+        let outputs = resolve_outputs(processor, data, libraries_dir)?;
+        if !outputs.is_empty() && verify_outputs(&outputs).is_ok() {
+            log::info!(
+                "[Forge] Skipping processor {} ({}/{}): outputs are up to date",
+                processor.jar,
+                index + 1,
+                total
+            );
+            continue;
+        }
+
+        let mut command = processor_command(java_path, processor, data, libraries_dir)?;
+        log::info!(
+            "[Forge] Running processor {}/{}: {:?}",
+            index + 1,
+            total,
+            command
+        );
+
+        let output = command
+            .output()
+            .map_err(|e| format!("Error al ejecutar el procesador {}: {}", processor.jar, e))?;
+        if !output.status.success() {
+            log::error!(
+                "[Forge] Processor {} failed - Exit code: {:?}\n{}\n{}",
+                processor.jar,
+                output.status.code(),
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+            return Err(format!(
+                "El procesador {} falló (código {:?})",
+                processor.jar,
+                output.status.code()
+            ));
+        }
+
+        if let Err(e) = verify_outputs(&outputs) {
+            for (path, _) in &outputs {
+                let _ = fs::remove_file(path);
+            }
+            return Err(format!("El procesador {} generó {}", processor.jar, e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the java command for a processor: its jar plus classpath, run through the
+/// jar's `Main-Class` with every argument substituted.
+pub fn processor_command(
+    java_path: &str,
+    processor: &ForgeProcessor,
+    data: &HashMap<String, String>,
+    libraries_dir: &Path,
+) -> Result<Command, String> {
+    let jar = artifact_path(libraries_dir, &processor.jar)?;
+    let main_class = jar_main_class(&jar)?;
+
+    let mut classpath = vec![jar];
+    for coordinate in &processor.classpath {
+        classpath.push(artifact_path(libraries_dir, coordinate)?);
+    }
+    let classpath = std::env::join_paths(&classpath)
+        .map_err(|e| format!("Classpath inválido para {}: {}", processor.jar, e))?;
+
+    let mut command = Command::new(java_path);
+    command.arg("-cp").arg(classpath).arg(main_class);
+    for arg in &processor.args {
+        command.arg(substitute(arg, data, libraries_dir)?);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        command.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    Ok(command)
+}
+
+/// Resolves a processor's `outputs` into `(file, expected sha1)` pairs
+fn resolve_outputs(
+    processor: &ForgeProcessor,
+    data: &HashMap<String, String>,
+    libraries_dir: &Path,
+) -> Result<Vec<(PathBuf, String)>, String> {
+    processor
+        .outputs
+        .iter()
+        .map(|(file, hash)| {
+            Ok((
+                PathBuf::from(substitute(file, data, libraries_dir)?),
+                substitute(hash, data, libraries_dir)?.to_lowercase(),
+            ))
+        })
+        .collect()
+}
+
+/// Checks every output exists with its expected hash
+fn verify_outputs(outputs: &[(PathBuf, String)]) -> Result<(), String> {
+    for (path, expected) in outputs {
+        let actual = sha1_of_file(path)?;
+        if &actual != expected {
+            return Err(format!(
+                "{} con hash {} (se esperaba {})",
+                path.display(),
+                actual,
+                expected
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn is_artifact_reference(value: &str) -> bool {
+    value.len() > 2 && value.starts_with('[') && value.ends_with(']')
+}
+
+fn is_literal(value: &str) -> bool {
+    value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'')
+}
+
+/// Applies installer substitutions: `[group:artifact:version]` becomes the library path,
+/// `'text'` the literal text and every `{KEY}` the resolved data value (`\` escapes).
+pub fn substitute(
+    value: &str,
+    data: &HashMap<String, String>,
+    libraries_dir: &Path,
+) -> Result<String, String> {
+    if is_artifact_reference(value) {
+        return Ok(artifact_path(libraries_dir, &value[1..value.len() - 1])?
+            .to_string_lossy()
+            .to_string());
+    }
+    if is_literal(value) {
+        return Ok(value[1..value.len() - 1].to_string());
+    }
+
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    result.push(escaped);
+                }
+            }
+            '{' => {
+                let key: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let replacement = data.get(&key).ok_or_else(|| {
+                    format!("Dato desconocido en el instalador de Forge: {}", key)
+                })?;
+                result.push_str(replacement);
+            }
+            _ => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+/// Maps a maven coordinate (`group:artifact:version[:classifier][@ext]`) to its path
+/// inside `libraries_dir`
+pub fn artifact_path(libraries_dir: &Path, coordinate: &str) -> Result<PathBuf, String> {
+    let (coordinate, extension) = coordinate.split_once('@').unwrap_or((coordinate, "jar"));
+    let parts: Vec<&str> = coordinate.split(':').collect();
+    if !(3..=4).contains(&parts.len()) || parts.iter().any(|part| part.is_empty()) {
+        return Err(format!("Coordenada de Maven inválida: {}", coordinate));
+    }
+
+    let (group, artifact, version) = (parts[0], parts[1], parts[2]);
+    let file_name = match parts.get(3) {
+        Some(classifier) => format!("{}-{}-{}.{}", artifact, version, classifier, extension),
+        None => format!("{}-{}.{}", artifact, version, extension),
+    };
+
+    resolve_within(
+        libraries_dir,
+        &format!(
+            "{}/{}/{}/{}",
+            group.replace('.', "/"),
+            artifact,
+            version,
+            file_name
+        ),
+    )
+}
+
+/// Reads `Main-Class` from a jar's manifest
+pub fn jar_main_class(jar: &Path) -> Result<String, String> {
+    let mut archive = open_archive(jar)?;
+    let manifest = read_entry(&mut archive, "META-INF/MANIFEST.MF")?;
+
+    // Manifest lines wrap at 72 bytes; continuation lines start with a single space
+    let mut unfolded = String::with_capacity(manifest.len());
+    for line in manifest.lines() {
+        match line.strip_prefix(' ') {
+            Some(continuation) => unfolded.push_str(continuation),
+            None => {
+                unfolded.push('\n');
+                unfolded.push_str(line);
+            }
+        }
+    }
+
+    unfolded
+        .lines()
+        .find_map(|line| line.strip_prefix("Main-Class:"))
+        .map(|class| class.trim().to_string())
+        .ok_or_else(|| format!("{} no declara Main-Class", jar.display()))
+}
+
+fn open_archive(path: &Path) -> Result<zip::ZipArchive<BufReader<File>>, String> {
+    let file = File::open(path).map_err(|e| format!("Error abriendo {}: {}", path.display(), e))?;
+    zip::ZipArchive::new(BufReader::new(file))
+        .map_err(|e| format!("{} no es un jar válido: {}", path.display(), e))
+}
+
+fn read_entry(
+    archive: &mut zip::ZipArchive<BufReader<File>>,
+    name: &str,
+) -> Result<String, String> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|e| format!("{} no encontrado: {}", name, e))?;
+    let mut content = String::new();
+    entry
+        .read_to_string(&mut content)
+        .map_err(|e| format!("Error leyendo {}: {}", name, e))?;
+    Ok(content)
+}
+
+fn write_entry(entry: &mut impl Read, target: &Path) -> Result<(), String> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Error creando {}: {}", parent.display(), e))?;
+    }
+    let mut output =
+        File::create(target).map_err(|e| format!("Error creando {}: {}", target.display(), e))?;
+    io::copy(entry, &mut output)
+        .map_err(|e| format!("Error extrayendo {}: {}", target.display(), e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::Write;
+    use tempfile::tempdir;
+    use zip::write::SimpleFileOptions;
+
+    fn write_jar(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut writer = zip::ZipWriter::new(File::create(path).unwrap());
+        for (name, content) in entries {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn sample_installer(dir: &Path) -> PathBuf {
+        let install_profile = json!({
+            "spec": 1,
+            "version": "1.20.1-forge-47.2.0",
+            "minecraft": "1.20.1",
+            "json": "/version.json",
+            "data": {
+                "MAPPINGS": { "client": "[de.oceanlabs.mcp:mcp_config:1.20.1:mappings@txt]", "server": "" },
+                "MC_SLIM_SHA": { "client": "'AABBCC'", "server": "'ddeeff'" },
+                "BINPATCH": { "client": "/data/client.lzma", "server": "/data/server.lzma" }
+            },
+            "processors": [
+                { "jar": "net.minecraftforge:installertools:1.3.0", "args": ["--task", "MCP_DATA"] },
+                { "sides": ["server"], "jar": "net.minecraftforge:installertools:1.3.0", "args": [] },
+                {
+                    "sides": ["client"],
+                    "jar": "net.minecraftforge:binarypatcher:1.1.1",
+                    "classpath": ["commons-io:commons-io:2.4"],
+                    "args": ["--clean", "{MINECRAFT_JAR}", "--patch", "{BINPATCH}"],
+                    "outputs": { "{ROOT}/patched.jar": "{MC_SLIM_SHA}" }
+                }
+            ],
+            "libraries": []
+        });
+        let version_json = json!({
+            "id": "1.20.1-forge-47.2.0",
+            "inheritsFrom": "1.20.1",
+            "mainClass": "cpw.mods.bootstraplauncher.BootstrapLauncher",
+            "libraries": []
+        });
+
+        let installer = dir.join("forge-installer.jar");
+        write_jar(
+            &installer,
+            &[
+                ("install_profile.json", install_profile.to_string().as_bytes()),
+                ("version.json", version_json.to_string().as_bytes()),
+                ("data/client.lzma", b"patches"),
+                (
+                    "maven/net/minecraftforge/forge/1.20.1-47.2.0/forge-1.20.1-47.2.0-universal.jar",
+                    b"universal",
+                ),
+            ],
+        );
+        installer
+    }
+
+    #[test]
+    fn test_artifact_path() {
+        let libraries = Path::new("/libs");
+        assert_eq!(
+            artifact_path(libraries, "net.minecraftforge:forge:1.20.1-47.2.0:client").unwrap(),
+            libraries.join("net/minecraftforge/forge/1.20.1-47.2.0/forge-1.20.1-47.2.0-client.jar")
+        );
+        assert_eq!(
+            artifact_path(libraries, "de.oceanlabs.mcp:mcp_config:1.20.1:mappings@txt").unwrap(),
+            libraries.join("de/oceanlabs/mcp/mcp_config/1.20.1/mcp_config-1.20.1-mappings.txt")
+        );
+        assert!(artifact_path(libraries, "net.minecraftforge:forge").is_err());
+        assert!(artifact_path(libraries, "net:..:1.0").is_err());
+    }
+
+    #[test]
+    fn test_substitute() {
+        let libraries = Path::new("/libs");
+        let data = HashMap::from([("SIDE".to_string(), "client".to_string())]);
+
+        assert_eq!(
+            substitute("--side={SIDE}", &data, libraries).unwrap(),
+            "--side=client"
+        );
+        assert_eq!(substitute("'{SIDE}'", &data, libraries).unwrap(), "{SIDE}");
+        assert_eq!(substitute("\\{SIDE}", &data, libraries).unwrap(), "{SIDE}");
+        assert_eq!(
+            substitute("[org.ow2.asm:asm:9.5]", &data, libraries).unwrap(),
+            libraries
+                .join("org/ow2/asm/asm/9.5/asm-9.5.jar")
+                .to_string_lossy()
+        );
+        assert!(substitute("{MISSING}", &data, libraries).is_err());
+    }
+
+    #[test]
+    fn test_open_installer_and_resolve_data() {
+        let dir = tempdir().unwrap();
+        let installer = ForgeInstaller::open(&sample_installer(dir.path())).unwrap();
+        assert_eq!(installer.version_id(), "1.20.1-forge-47.2.0");
+
+        let processors = installer.client_processors().unwrap();
+        assert_eq!(processors.len(), 2);
+        assert_eq!(processors[1].jar, "net.minecraftforge:binarypatcher:1.1.1");
+
+        let libraries_dir = dir.path().join("libraries");
+        assert_eq!(
+            installer
+                .extract_embedded_libraries(&libraries_dir)
+                .unwrap(),
+            1
+        );
+        assert!(libraries_dir
+            .join("net/minecraftforge/forge/1.20.1-47.2.0/forge-1.20.1-47.2.0-universal.jar")
+            .exists());
+
+        let minecraft_jar = dir.path().join("versions/1.20.1/1.20.1.jar");
+        let context = ForgeInstallContext {
+            minecraft_dir: dir.path(),
+            libraries_dir: &libraries_dir,
+            minecraft_version: "1.20.1",
+            minecraft_jar: &minecraft_jar,
+        };
+        let work_dir = dir.path().join("work");
+        let data = installer.resolve_data(&context, &work_dir).unwrap();
+
+        assert_eq!(data["SIDE"], "client");
+        assert_eq!(data["MC_SLIM_SHA"], "AABBCC");
+        assert!(data["MAPPINGS"].ends_with("mcp_config-1.20.1-mappings.txt"));
+        assert_eq!(fs::read(&data["BINPATCH"]).unwrap(), b"patches");
+
+        let outputs = resolve_outputs(&processors[1], &data, &libraries_dir).unwrap();
+        assert_eq!(
+            outputs,
+            vec![(dir.path().join("patched.jar"), "aabbcc".to_string())]
+        );
+        assert!(verify_outputs(&outputs).is_err());
+    }
+
+    #[test]
+    fn test_legacy_installer() {
+        let dir = tempdir().unwrap();
+        let installer_path = dir.path().join("forge-installer.jar");
+        let install_profile = json!({
+            "install": {
+                "profileName": "Forge",
+                "target": "1.7.10-Forge10.13.4.1614-1.7.10",
+                "path": "net.minecraftforge:forge:1.7.10-10.13.4.1614-1.7.10",
+                "version": "forge 1.7.10-10.13.4.1614-1.7.10",
+                "filePath": "forge-1.7.10-10.13.4.1614-1.7.10-universal.jar",
+                "minecraft": "1.7.10"
+            },
+            "versionInfo": {
+                "id": "1.7.10-Forge10.13.4.1614-1.7.10",
+                "minecraftArguments": "--username ${auth_player_name} --tweakClass cpw.mods.fml.common.launcher.FMLTweaker",
+                "mainClass": "net.minecraft.launchwrapper.Launch",
+                "libraries": [
+                    { "name": "net.minecraftforge:forge:1.7.10-10.13.4.1614-1.7.10", "url": "http://files.minecraftforge.net/maven/" },
+                    { "name": "net.minecraft:launchwrapper:1.12" },
+                    { "name": "com.typesafe:config:1.2.1", "url": "https://example.org/maven/", "serverreq": true }
+                ]
+            }
+        });
+        write_jar(
+            &installer_path,
+            &[
+                (
+                    "install_profile.json",
+                    install_profile.to_string().as_bytes(),
+                ),
+                (
+                    "forge-1.7.10-10.13.4.1614-1.7.10-universal.jar",
+                    b"universal",
+                ),
+            ],
+        );
+
+        let installer = ForgeInstaller::open(&installer_path).unwrap();
+        assert!(installer.is_legacy());
+        assert!(installer.client_processors().unwrap().is_empty());
+        assert_eq!(installer.version_json["inheritsFrom"], "1.7.10");
+
+        let urls: Vec<&str> = installer.version_json["libraries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|library| library["url"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://maven.minecraftforge.net/",
+                "https://libraries.minecraft.net/",
+                "https://example.org/maven/"
+            ]
+        );
+
+        let libraries_dir = dir.path().join("libraries");
+        assert_eq!(
+            installer
+                .extract_embedded_libraries(&libraries_dir)
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            fs::read(libraries_dir.join(
+                "net/minecraftforge/forge/1.7.10-10.13.4.1614-1.7.10/forge-1.7.10-10.13.4.1614-1.7.10.jar"
+            ))
+            .unwrap(),
+            b"universal"
+        );
+    }
+
+    #[test]
+    fn test_jar_main_class() {
+        let dir = tempdir().unwrap();
+        let jar = dir.path().join("binarypatcher.jar");
+        write_jar(
+            &jar,
+            &[(
+                "META-INF/MANIFEST.MF",
+                b"Manifest-Version: 1.0\r\nMain-Class: net.minecraftforge.binarypatcher.Cons\r\n ole\r\n\r\n",
+            )],
+        );
+        assert_eq!(
+            jar_main_class(&jar).unwrap(),
+            "net.minecraftforge.binarypatcher.Console"
+        );
+    }
+}
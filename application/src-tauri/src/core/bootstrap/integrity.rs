@@ -0,0 +1,305 @@
+// src/core/bootstrap/integrity.rs
+// Verifies the client jar, libraries and natives against the `sha1`/`size` declared in
+// the version JSON and re-downloads the ones that are missing or corrupted.
+
+use super::download::{download_file_via_store, is_library_allowed};
+use super::store::{is_valid_sha1, sha1_of_file, SharedStore};
+use crate::core::minecraft_instance::MinecraftInstance;
+use rayon::prelude::*;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri_plugin_http::reqwest;
+
+/// A file the version JSON declares, with what it is expected to contain
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclaredFile {
+    /// Name shown in the repair summary (artifact path or jar name)
+    pub name: String,
+    pub path: PathBuf,
+    pub url: String,
+    pub sha1: Option<String>,
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileState {
+    Valid,
+    Missing,
+    Corrupted,
+}
+
+/// Outcome of a verification pass, reported as the result of the repair task
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairSummary {
+    pub checked: usize,
+    pub repaired: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+impl RepairSummary {
+    pub fn is_clean(&self) -> bool {
+        self.repaired.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// Checks a file against its declared size (cheap) and SHA-1
+pub fn check_file(path: &Path, sha1: Option<&str>, size: Option<u64>) -> FileState {
+    let Ok(metadata) = fs::metadata(path) else {
+        return FileState::Missing;
+    };
+    if size.is_some_and(|size| size != metadata.len()) {
+        return FileState::Corrupted;
+    }
+    match sha1.filter(|hash| is_valid_sha1(hash)) {
+        Some(expected) => match sha1_of_file(path) {
+            Ok(actual) if actual.eq_ignore_ascii_case(expected) => FileState::Valid,
+            _ => FileState::Corrupted,
+        },
+        None => FileState::Valid,
+    }
+}
+
+fn declared_file(name: &str, path: PathBuf, download: &Value) -> Option<DeclaredFile> {
+    let url = download["url"].as_str().filter(|url| !url.is_empty())?;
+    Some(DeclaredFile {
+        name: name.to_string(),
+        path,
+        url: url.to_string(),
+        sha1: download["sha1"].as_str().map(str::to_string),
+        size: download["size"].as_u64(),
+    })
+}
+
+/// Classifier holding this library's natives for the current OS, if it has any
+fn native_classifier(library: &Value) -> Option<String> {
+    let os = if cfg!(target_os = "windows") {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    };
+    let arch = if cfg!(target_pointer_width = "64") {
+        "64"
+    } else {
+        "32"
+    };
+
+    match library["natives"][os].as_str() {
+        Some(classifier) => Some(classifier.replace("${arch}", arch)),
+        None => library["downloads"]["classifiers"]
+            .get(format!("natives-{}", os))
+            .map(|_| format!("natives-{}", os)),
+    }
+}
+
+/// Client jar, libraries and natives the version JSON declares for this platform
+pub fn declared_files(
+    version_details: &Value,
+    client_jar: &Path,
+    libraries_dir: &Path,
+) -> Vec<DeclaredFile> {
+    let mut files = Vec::new();
+
+    let client_name = client_jar
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    files.extend(declared_file(
+        &client_name,
+        client_jar.to_path_buf(),
+        &version_details["downloads"]["client"],
+    ));
+
+    for library in version_details["libraries"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        if !is_library_allowed(library) {
+            continue;
+        }
+
+        let downloads = &library["downloads"];
+        if let Some(path) = downloads["artifact"]["path"].as_str() {
+            files.extend(declared_file(
+                path,
+                libraries_dir.join(path),
+                &downloads["artifact"],
+            ));
+        }
+
+        let native = native_classifier(library)
+            .and_then(|classifier| downloads["classifiers"].get(classifier));
+        if let Some(native) = native {
+            if let Some(path) = native["path"].as_str() {
+                files.extend(declared_file(path, libraries_dir.join(path), native));
+            }
+        }
+    }
+
+    files
+}
+
+/// Re-downloads a missing or corrupted file, making sure the result matches its hash
+fn repair_file(
+    client: &reqwest::blocking::Client,
+    store: Option<&SharedStore>,
+    file: &DeclaredFile,
+    instance: &MinecraftInstance,
+) -> Result<(), String> {
+    let sha1 = file.sha1.as_deref();
+
+    if file.path.exists() {
+        fs::remove_file(&file.path)
+            .map_err(|e| format!("Error eliminando {}: {}", file.path.display(), e))?;
+    }
+    // Instance files are hardlinks into the store, so a corrupted file means a corrupted blob
+    if let (Some(store), Some(hash)) = (store, sha1) {
+        store.evict_if_corrupted(hash)?;
+    }
+
+    download_file_via_store(client, store, &file.url, sha1, &file.path, instance)?;
+
+    match check_file(&file.path, sha1, file.size) {
+        FileState::Valid => Ok(()),
+        _ => Err(format!(
+            "{} no coincide con el hash declarado tras descargarlo",
+            file.name
+        )),
+    }
+}
+
+/// Verifies every declared file (in parallel) and repairs the ones that fail
+pub fn verify_and_repair(
+    client: &reqwest::blocking::Client,
+    files: &[DeclaredFile],
+    instance: &MinecraftInstance,
+) -> RepairSummary {
+    let broken: Vec<(&DeclaredFile, FileState)> = files
+        .par_iter()
+        .map(|file| {
+            let state = check_file(&file.path, file.sha1.as_deref(), file.size);
+            (file, state)
+        })
+        .filter(|(_, state)| *state != FileState::Valid)
+        .collect();
+
+    let mut summary = RepairSummary {
+        checked: files.len(),
+        ..Default::default()
+    };
+    if broken.is_empty() {
+        return summary;
+    }
+
+    let store = SharedStore::open()
+        .map_err(|e| log::warn!("Shared store unavailable, repairing in place: {}", e))
+        .ok();
+
+    for (file, state) in broken {
+        log::warn!(
+            "[Instance: {}] {} is {:?}, downloading it again",
+            instance.instanceId,
+            file.name,
+            state
+        );
+        match repair_file(client, store.as_ref(), file, instance) {
+            Ok(()) => summary.repaired.push(file.name.clone()),
+            Err(e) => {
+                log::error!(
+                    "[Instance: {}] Could not repair {}: {}",
+                    instance.instanceId,
+                    file.name,
+                    e
+                );
+                summary.failed.push(file.name.clone());
+            }
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_check_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("lib.jar");
+        // SHA-1 of "hello"
+        let sha1 = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+        assert_eq!(check_file(&path, Some(sha1), Some(5)), FileState::Missing);
+
+        fs::write(&path, "hello").unwrap();
+        assert_eq!(check_file(&path, Some(sha1), Some(5)), FileState::Valid);
+        assert_eq!(check_file(&path, Some(sha1), Some(6)), FileState::Corrupted);
+        assert_eq!(check_file(&path, None, None), FileState::Valid);
+
+        fs::write(&path, "hellO").unwrap();
+        assert_eq!(check_file(&path, Some(sha1), Some(5)), FileState::Corrupted);
+    }
+
+    #[test]
+    fn test_declared_files() {
+        let os = if cfg!(target_os = "windows") {
+            "windows"
+        } else if cfg!(target_os = "macos") {
+            "osx"
+        } else {
+            "linux"
+        };
+        let native_key = format!("natives-{}", os);
+        let version_details = json!({
+            "downloads": {
+                "client": { "url": "https://example.org/client.jar", "sha1": "aa", "size": 10 }
+            },
+            "libraries": [
+                {
+                    "name": "org.lwjgl.lwjgl:lwjgl-platform:2.9.4",
+                    "natives": { os: "natives-${arch}-unused", "other": "x" },
+                    "downloads": {
+                        "artifact": { "path": "a/a.jar", "url": "https://example.org/a.jar", "sha1": "bb", "size": 20 },
+                        "classifiers": {
+                            native_key.clone(): { "path": "a/a-natives.jar", "url": "https://example.org/a-natives.jar" }
+                        }
+                    }
+                },
+                {
+                    "name": "org.lwjgl:lwjgl:3.3.1",
+                    "downloads": {
+                        "classifiers": {
+                            native_key.clone(): { "path": "b/b-natives.jar", "url": "https://example.org/b-natives.jar", "sha1": "cc" }
+                        }
+                    }
+                },
+                {
+                    "name": "com.example:excluded:1.0",
+                    "rules": [{ "action": "allow", "os": { "name": "no-such-os" } }],
+                    "downloads": { "artifact": { "path": "c/c.jar", "url": "https://example.org/c.jar" } }
+                },
+                {
+                    "name": "net.minecraftforge:forge:1.20.1-47.2.0:client",
+                    "downloads": { "artifact": { "path": "d/d.jar", "url": "" } }
+                }
+            ]
+        });
+
+        let libraries = Path::new("/libs");
+        let files = declared_files(&version_details, Path::new("/v/1.20.1.jar"), libraries);
+        let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+        // The first library's `natives` entry points at a classifier it doesn't ship
+        assert_eq!(names, vec!["1.20.1.jar", "a/a.jar", "b/b-natives.jar"]);
+        assert_eq!(files[0].size, Some(10));
+        assert_eq!(files[1].path, libraries.join("a/a.jar"));
+        assert_eq!(files[2].sha1.as_deref(), Some("cc"));
+    }
+}
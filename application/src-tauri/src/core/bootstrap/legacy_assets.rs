@@ -0,0 +1,217 @@
+// src/core/bootstrap/legacy_assets.rs
+// Asset layouts older than the hashed `assets/objects` store. The `legacy` index (1.6–1.7.2)
+// is `virtual` and read from `assets/virtual/<index>`, and `pre-1.6` uses
+// `map_to_resources` and reads files by name from `<game dir>/resources`.
+
+use crate::utils::safe_path::resolve_within;
+use serde_json::Value;
+use std::fs;
+use std::io::{self, Result as IoResult};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegacyAssetLayout {
+    Virtual,
+    Resources,
+}
+
+impl LegacyAssetLayout {
+    /// Layout an asset index asks for, `None` for the modern hashed layout
+    pub fn of(index: &Value) -> Option<Self> {
+        if index["map_to_resources"].as_bool() == Some(true) {
+            Some(Self::Resources)
+        } else if index["virtual"].as_bool() == Some(true) {
+            Some(Self::Virtual)
+        } else {
+            None
+        }
+    }
+
+    /// Directory the game reads the named asset files from
+    pub fn target_dir(self, assets_dir: &Path, game_dir: &Path, index_id: &str) -> PathBuf {
+        match self {
+            Self::Virtual => assets_dir.join("virtual").join(index_id),
+            Self::Resources => game_dir.join("resources"),
+        }
+    }
+}
+
+/// Value of `${game_assets}`: the named-file directory for legacy indexes, otherwise
+/// the regular assets directory
+pub fn game_assets_dir(assets_dir: &Path, game_dir: &Path, index_id: &str) -> PathBuf {
+    let index_file = assets_dir
+        .join("indexes")
+        .join(format!("{}.json", index_id));
+    let layout = fs::read_to_string(&index_file)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+        .and_then(|index| LegacyAssetLayout::of(&index));
+
+    match layout {
+        Some(layout) => layout.target_dir(assets_dir, game_dir, index_id),
+        None => assets_dir.to_path_buf(),
+    }
+}
+
+/// Copies the objects of a legacy index to their named paths. Files already present with
+/// the expected size are left alone; returns how many files were written.
+pub fn materialize_legacy_assets(
+    index: &Value,
+    index_id: &str,
+    assets_dir: &Path,
+    game_dir: &Path,
+) -> IoResult<usize> {
+    let Some(layout) = LegacyAssetLayout::of(index) else {
+        return Ok(0);
+    };
+    let objects = index["objects"].as_object().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "No se encontraron objetos de assets en el índice",
+        )
+    })?;
+
+    let target_dir = layout.target_dir(assets_dir, game_dir, index_id);
+    let objects_dir = assets_dir.join("objects");
+    let mut written = 0;
+
+    for (name, info) in objects {
+        let hash = info["hash"]
+            .as_str()
+            .filter(|hash| hash.len() > 2)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Hash inválido para asset: {}", name),
+                )
+            })?;
+        let source = objects_dir.join(&hash[..2]).join(hash);
+        let target = resolve_within(&target_dir, name)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let expected_size = info["size"].as_u64();
+        if let Ok(metadata) = fs::metadata(&target) {
+            if expected_size.is_none_or(|size| size == metadata.len()) {
+                continue;
+            }
+            fs::remove_file(&target)?;
+        }
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if fs::hard_link(&source, &target).is_err() {
+            fs::copy(&source, &target)?;
+        }
+        written += 1;
+    }
+
+    log::info!(
+        "Materialized {} legacy assets ({:?}) in {}",
+        written,
+        layout,
+        target_dir.display()
+    );
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn fixture(name: &str) -> Value {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures/assets")
+            .join(name);
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    fn write_objects(assets_dir: &Path, index: &Value) {
+        for info in index["objects"].as_object().unwrap().values() {
+            let hash = info["hash"].as_str().unwrap();
+            let dir = assets_dir.join("objects").join(&hash[..2]);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join(hash),
+                vec![b'x'; info["size"].as_u64().unwrap() as usize],
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_virtual_index() {
+        let dir = tempdir().unwrap();
+        let assets_dir = dir.path().join("assets");
+        let index = fixture("legacy.json");
+        assert_eq!(
+            LegacyAssetLayout::of(&index),
+            Some(LegacyAssetLayout::Virtual)
+        );
+        write_objects(&assets_dir, &index);
+
+        assert_eq!(
+            materialize_legacy_assets(&index, "legacy", &assets_dir, dir.path()).unwrap(),
+            3
+        );
+        assert!(assets_dir
+            .join("virtual/legacy/sounds/random/click.ogg")
+            .exists());
+        assert_eq!(
+            materialize_legacy_assets(&index, "legacy", &assets_dir, dir.path()).unwrap(),
+            0
+        );
+
+        fs::create_dir_all(assets_dir.join("indexes")).unwrap();
+        fs::write(assets_dir.join("indexes/legacy.json"), index.to_string()).unwrap();
+        assert_eq!(
+            game_assets_dir(&assets_dir, dir.path(), "legacy"),
+            assets_dir.join("virtual/legacy")
+        );
+        assert_eq!(game_assets_dir(&assets_dir, dir.path(), "1.12"), assets_dir);
+    }
+
+    #[test]
+    fn test_map_to_resources_index() {
+        let dir = tempdir().unwrap();
+        let assets_dir = dir.path().join("assets");
+        let index = fixture("pre-1.6.json");
+        assert_eq!(
+            LegacyAssetLayout::of(&index),
+            Some(LegacyAssetLayout::Resources)
+        );
+        write_objects(&assets_dir, &index);
+
+        // A truncated copy from an interrupted run gets replaced
+        let click = dir.path().join("resources/sound3/random/click.ogg");
+        fs::create_dir_all(click.parent().unwrap()).unwrap();
+        fs::write(&click, "").unwrap();
+
+        assert_eq!(
+            materialize_legacy_assets(&index, "pre-1.6", &assets_dir, dir.path()).unwrap(),
+            2
+        );
+        assert_eq!(fs::metadata(&click).unwrap().len(), 16);
+        assert_eq!(
+            fs::metadata(dir.path().join("resources/music/calm1.ogg"))
+                .unwrap()
+                .len(),
+            48
+        );
+    }
+
+    #[test]
+    fn test_modern_index_is_left_alone() {
+        let dir = tempdir().unwrap();
+        let index = serde_json::json!({
+            "objects": { "minecraft/sounds/random/click.ogg": { "hash": "aabbcc", "size": 1 } }
+        });
+        assert_eq!(LegacyAssetLayout::of(&index), None);
+        assert_eq!(
+            materialize_legacy_assets(&index, "1.12", &dir.path().join("assets"), dir.path())
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -1,54 +1,46 @@
 // src/core/bootstrap/manifest.rs
 // Manifest processing functionality extracted from instance_bootstrap.rs
 
+use super::meta_cache::MetadataCache;
+use crate::core::minecraft::ManifestMerger;
 use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
 use tauri_plugin_http::reqwest;
 
 /// Constants for manifest URLs
 pub const MOJANG_VERSION_MANIFEST_URL: &str =
     "https://launchermeta.mojang.com/mc/game/version_manifest.json";
 pub const FORGE_API_BASE_URL: &str = "https://mc-versions-api.net/api/forge";
-pub const CACHE_EXPIRY_MS: u64 = 3600000; // 1 hour
 
-/// Fetches and caches the Mojang version manifest
+/// Fetches the Mojang version manifest through the metadata cache
 pub fn get_version_manifest(
     client: &reqwest::blocking::Client,
-    cache: &mut Option<(Value, u64)>,
-) -> Result<Value, reqwest::Error> {
-    let current_time = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_millis() as u64;
-
-    // Check cache first
-    if let Some((cached_manifest, cache_time)) = cache {
-        if current_time - *cache_time < CACHE_EXPIRY_MS {
-            return Ok(cached_manifest.clone());
-        }
-    }
-
-    // Fetch new manifest
-    let manifest = client
-        .get(MOJANG_VERSION_MANIFEST_URL)
-        .send()?
-        .json::<Value>()?;
-
-    // Update cache
-    *cache = Some((manifest.clone(), current_time));
-
-    Ok(manifest)
+    cache: &MetadataCache,
+) -> Result<Value, String> {
+    cache.fetch_json(client, MOJANG_VERSION_MANIFEST_URL)
 }
 
 /// Gets detailed information for a specific Minecraft version
 pub fn get_version_details(
     client: &reqwest::blocking::Client,
-    cache: &mut Option<(Value, u64)>,
+    cache: &MetadataCache,
     version: &str,
 ) -> Result<Value, String> {
     // Get the version manifest
     let version_manifest = get_version_manifest(client, cache)
         .map_err(|e| format!("Error fetching version manifest: {}", e))?;
 
+    let version_url = version_json_url(&version_manifest, version)?;
+
+    // Download version details
+    cache
+        .fetch_json(client, &version_url)
+        .map_err(|e| format!("Error fetching version details: {}", e))
+}
+
+/// URL of a version JSON as listed in the version manifest
+pub fn version_json_url(version_manifest: &Value, version: &str) -> Result<String, String> {
     let versions_node = version_manifest["versions"]
         .as_array()
         .ok_or_else(|| "Invalid version manifest format".to_string())?;
@@ -59,17 +51,10 @@ pub fn get_version_details(
         .find(|v| v["id"].as_str() == Some(version))
         .ok_or_else(|| format!("Version {} not found in manifest", version))?;
 
-    let version_url = version_info["url"]
+    version_info["url"]
         .as_str()
-        .ok_or_else(|| "Invalid version info format".to_string())?;
-
-    // Download version details
-    client
-        .get(version_url)
-        .send()
-        .map_err(|e| format!("Error fetching version details: {}", e))?
-        .json::<Value>()
-        .map_err(|e| format!("Error parsing version details: {}", e))
+        .map(str::to_string)
+        .ok_or_else(|| "Invalid version info format".to_string())
 }
 
 /// Extracts Java version requirements from version details
@@ -87,6 +72,13 @@ pub fn get_java_version_requirement(version_details: &Value) -> Result<String, S
     Ok(java_major_version)
 }
 
+/// Mojang runtime component (`javaVersion.component`) the version asks for, if any
+pub fn get_java_runtime_component(version_details: &Value) -> Option<String> {
+    version_details["javaVersion"]["component"]
+        .as_str()
+        .map(str::to_string)
+}
+
 /// Gets download URLs for client and version JSON files
 pub fn get_download_urls(version_details: &Value) -> Result<(String, String), String> {
     let client_url = version_details["downloads"]["client"]["url"]
@@ -123,6 +115,21 @@ pub fn get_asset_index_info(version_details: &Value) -> Result<(String, String),
     Ok((assets_index_id.to_string(), assets_index_url.to_string()))
 }
 
+/// Log4j configuration the version ships for the client (`logging.client`): file id, URL and
+/// the JVM argument that enables it, with `${path}` standing for the downloaded file
+pub fn get_logging_config_info(version_details: &Value) -> Option<(String, String, String)> {
+    let client = version_details.get("logging")?.get("client")?;
+    let id = client["file"]["id"].as_str()?;
+    let url = client["file"]["url"].as_str()?;
+    let argument = client["argument"].as_str()?;
+
+    // The id becomes a file name
+    if Path::new(id).file_name() != Some(std::ffi::OsStr::new(id)) {
+        return None;
+    }
+    Some((id.to_string(), url.to_string(), argument.to_string()))
+}
+
 /// Builds Forge installer download URL
 pub fn build_forge_installer_url(minecraft_version: &str, forge_version: &str) -> String {
     format!(
@@ -131,6 +138,110 @@ pub fn build_forge_installer_url(minecraft_version: &str, forge_version: &str) -
     )
 }
 
+/// Installer URLs to try for a Forge version. Some 1.7.10–1.10 builds were published with
+/// the Minecraft version repeated as a branch suffix (`1.7.10-10.13.4.1614-1.7.10`).
+pub fn forge_installer_urls(minecraft_version: &str, forge_version: &str) -> Vec<String> {
+    let mut urls = vec![build_forge_installer_url(minecraft_version, forge_version)];
+    if !forge_version.ends_with(&format!("-{}", minecraft_version)) {
+        urls.push(build_forge_installer_url(
+            minecraft_version,
+            &format!("{}-{}", forge_version, minecraft_version),
+        ));
+    }
+    urls
+}
+
+/// Loader versions end up in paths and URLs, so only plain version characters are allowed
+pub fn is_valid_loader_version(version: &str) -> bool {
+    !version.is_empty()
+        && version != "."
+        && version != ".."
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '_'))
+}
+
+/// Downloads a loader profile (Fabric/Quilt meta) and checks it is the expected version
+pub fn fetch_loader_profile(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    expected_id: &str,
+    minecraft_version: &str,
+) -> Result<Value, String> {
+    log::info!("[Loader] Fetching loader profile from {}", url);
+
+    let profile = client
+        .get(url)
+        .send()
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error descargando perfil de {}: {}", expected_id, e))?
+        .json::<Value>()
+        .map_err(|e| format!("Perfil de {} inválido: {}", expected_id, e))?;
+
+    validate_loader_profile(&profile, expected_id, minecraft_version)?;
+    Ok(profile)
+}
+
+/// Checks that a loader profile is the one requested and carries what the launcher needs
+pub fn validate_loader_profile(
+    profile: &Value,
+    expected_id: &str,
+    minecraft_version: &str,
+) -> Result<(), String> {
+    if profile["id"].as_str() != Some(expected_id) {
+        return Err(format!(
+            "El perfil no corresponde a {} (id: {})",
+            expected_id, profile["id"]
+        ));
+    }
+    if profile["inheritsFrom"].as_str() != Some(minecraft_version) {
+        return Err(format!(
+            "El perfil {} no hereda de Minecraft {}",
+            expected_id, minecraft_version
+        ));
+    }
+    if profile["mainClass"].as_str().is_none() || !profile["libraries"].is_array() {
+        return Err(format!(
+            "El perfil {} no contiene mainClass o libraries",
+            expected_id
+        ));
+    }
+    Ok(())
+}
+
+/// Writes a version JSON to `versions/<id>/<id>.json` and returns its path
+pub fn install_version_json(
+    versions_dir: &Path,
+    version_id: &str,
+    version_json: &Value,
+) -> Result<PathBuf, String> {
+    let version_dir = versions_dir.join(version_id);
+    fs::create_dir_all(&version_dir)
+        .map_err(|e| format!("Error creando directorio de {}: {}", version_id, e))?;
+
+    let path = version_dir.join(format!("{}.json", version_id));
+    let content = serde_json::to_string_pretty(version_json)
+        .map_err(|e| format!("Error serializando {}: {}", version_id, e))?;
+    fs::write(&path, content).map_err(|e| format!("Error guardando {}: {}", version_id, e))?;
+
+    Ok(path)
+}
+
+/// Merges a loader version JSON into the vanilla version JSON it inherits from
+pub fn merge_with_vanilla(versions_dir: &Path, profile: &Value) -> Result<Value, String> {
+    let parent = profile["inheritsFrom"]
+        .as_str()
+        .ok_or("El perfil no indica la versión base (inheritsFrom)")?;
+    let vanilla_path = versions_dir.join(parent).join(format!("{}.json", parent));
+    let vanilla: Value = serde_json::from_str(
+        &fs::read_to_string(&vanilla_path)
+            .map_err(|e| format!("Error leyendo {}: {}", vanilla_path.display(), e))?,
+    )
+    .map_err(|e| format!("Error parseando {}: {}", vanilla_path.display(), e))?;
+
+    Ok(ManifestMerger::merge(vanilla, profile.clone()))
+}
+
 /// Validates a version manifest format
 pub fn validate_version_manifest(manifest: &Value) -> Result<(), String> {
     if !manifest.is_object() {
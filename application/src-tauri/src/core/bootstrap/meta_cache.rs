@@ -0,0 +1,330 @@
+// src/core/bootstrap/meta_cache.rs
+// On-disk cache for launcher metadata (version manifest, version JSONs, asset indexes,
+// loader installers). Entries are revalidated with ETag/If-Modified-Since once their TTL
+// expires, and a stale copy is served when the network is unavailable so instances
+// that are already installed can be launched offline.
+
+use super::store::staging_path;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri_plugin_http::reqwest::{
+    self,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    StatusCode,
+};
+
+pub const DEFAULT_METADATA_TTL: Duration = Duration::from_secs(3600);
+
+/// Validators and fetch time stored next to each cached body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// Seconds since the Unix epoch of the last successful fetch or revalidation
+    fetched_at: u64,
+}
+
+/// Metadata cache living in `meta/<sha1 of the url>.{json,bin}`.
+///
+/// Fresh entries (younger than the TTL) are served without touching the network. In
+/// offline mode every cached entry is served regardless of age and nothing is fetched.
+#[derive(Debug, Clone)]
+pub struct MetadataCache {
+    root: PathBuf,
+    ttl: Duration,
+    offline: bool,
+}
+
+impl MetadataCache {
+    /// Opens the cache under the launcher config directory, using the configured TTL
+    pub fn open() -> Result<Self, String> {
+        let root = dirs::config_dir()
+            .ok_or_else(|| "No se pudo obtener el directorio de configuración".to_string())?
+            .join("dev.alexitoo.modpackstore")
+            .join("meta");
+        let ttl = crate::config::get_config_manager()
+            .lock()
+            .ok()
+            .and_then(|config| config.as_ref().ok().map(|c| c.get_metadata_cache_ttl()))
+            .unwrap_or(DEFAULT_METADATA_TTL);
+        Self::at(root, ttl)
+    }
+
+    /// Opens (creating if needed) a cache rooted at the given directory
+    pub fn at(root: impl Into<PathBuf>, ttl: Duration) -> Result<Self, String> {
+        let root = root.into();
+        fs::create_dir_all(&root)
+            .map_err(|e| format!("Error creating metadata cache directory: {}", e))?;
+        Ok(Self {
+            root,
+            ttl,
+            offline: false,
+        })
+    }
+
+    /// Same cache, but only ever answering from disk
+    pub fn offline(mut self) -> Self {
+        self.offline = true;
+        self
+    }
+
+    /// Returns the body for `url`, from the cache when it is fresh (or we are offline),
+    /// otherwise from the network. Falls back to a stale copy if the request fails.
+    pub fn fetch(&self, client: &reqwest::blocking::Client, url: &str) -> Result<Vec<u8>, String> {
+        let entry = self.load_entry(url);
+        let cached = entry
+            .as_ref()
+            .and_then(|_| fs::read(self.body_path(url)).ok());
+
+        if let (Some(entry), Some(body)) = (&entry, &cached) {
+            if self.offline || self.is_fresh(entry, unix_now()) {
+                return Ok(body.clone());
+            }
+        }
+        if self.offline {
+            return Err(format!("{} no está disponible sin conexión", url));
+        }
+
+        let mut request = client.get(url);
+        if let (Some(entry), Some(_)) = (&entry, &cached) {
+            if let Some(etag) = &entry.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let error = match request.send() {
+            Ok(response) if response.status() == StatusCode::NOT_MODIFIED => {
+                match (&entry, &cached) {
+                    (Some(entry), Some(body)) => {
+                        self.write_entry(&CacheEntry {
+                            fetched_at: unix_now(),
+                            ..entry.clone()
+                        });
+                        return Ok(body.clone());
+                    }
+                    _ => "respuesta 304 sin copia en caché".to_string(),
+                }
+            }
+            Ok(response) if response.status().is_success() => {
+                let header = |name| {
+                    response
+                        .headers()
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(str::to_string)
+                };
+                let etag = header(ETAG);
+                let last_modified = header(LAST_MODIFIED);
+                match response.bytes() {
+                    Ok(body) => {
+                        self.store(url, &body, etag, last_modified);
+                        return Ok(body.to_vec());
+                    }
+                    Err(e) => e.to_string(),
+                }
+            }
+            Ok(response) => format!("estado HTTP {}", response.status()),
+            Err(e) => e.to_string(),
+        };
+
+        match cached {
+            Some(body) => {
+                log::warn!("Using stale cached copy of {} ({})", url, error);
+                Ok(body)
+            }
+            None => Err(format!("Error descargando {}: {}", url, error)),
+        }
+    }
+
+    /// `fetch` parsed as JSON. A body that doesn't parse is dropped from the cache so the
+    /// next call downloads it again instead of serving it until the TTL expires.
+    pub fn fetch_json(
+        &self,
+        client: &reqwest::blocking::Client,
+        url: &str,
+    ) -> Result<Value, String> {
+        let body = self.fetch(client, url)?;
+        serde_json::from_slice(&body).map_err(|e| {
+            self.remove(url);
+            format!("JSON inválido en {}: {}", url, e)
+        })
+    }
+
+    /// `fetch` written to `destination`, creating parent directories
+    pub fn fetch_to_file(
+        &self,
+        client: &reqwest::blocking::Client,
+        url: &str,
+        destination: &Path,
+    ) -> Result<(), String> {
+        let body = self.fetch(client, url)?;
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Error creating directory: {}", e))?;
+        }
+        fs::write(destination, body).map_err(|e| format!("Error writing file: {}", e))
+    }
+
+    /// True when `url` can be answered without the network
+    pub fn contains(&self, url: &str) -> bool {
+        self.load_entry(url).is_some() && self.body_path(url).is_file()
+    }
+
+    fn is_fresh(&self, entry: &CacheEntry, now: u64) -> bool {
+        now.saturating_sub(entry.fetched_at) < self.ttl.as_secs()
+    }
+
+    fn key(url: &str) -> String {
+        format!("{:x}", Sha1::digest(url.as_bytes()))
+    }
+
+    fn body_path(&self, url: &str) -> PathBuf {
+        self.root.join(format!("{}.bin", Self::key(url)))
+    }
+
+    fn entry_path(&self, url: &str) -> PathBuf {
+        self.root.join(format!("{}.json", Self::key(url)))
+    }
+
+    fn load_entry(&self, url: &str) -> Option<CacheEntry> {
+        let content = fs::read_to_string(self.entry_path(url)).ok()?;
+        serde_json::from_str::<CacheEntry>(&content)
+            .ok()
+            .filter(|entry| entry.url == url)
+    }
+
+    /// Cache failures are logged and otherwise ignored: the caller already has the data
+    fn store(&self, url: &str, body: &[u8], etag: Option<String>, last_modified: Option<String>) {
+        let body_path = self.body_path(url);
+        let staging = staging_path(&body_path);
+        let result = fs::write(&staging, body).and_then(|_| fs::rename(&staging, &body_path));
+        if let Err(e) = result {
+            log::warn!("Failed to cache {}: {}", url, e);
+            let _ = fs::remove_file(&staging);
+            return;
+        }
+
+        self.write_entry(&CacheEntry {
+            url: url.to_string(),
+            etag,
+            last_modified,
+            fetched_at: unix_now(),
+        });
+    }
+
+    fn remove(&self, url: &str) {
+        let _ = fs::remove_file(self.entry_path(url));
+        let _ = fs::remove_file(self.body_path(url));
+    }
+
+    fn write_entry(&self, entry: &CacheEntry) {
+        let result = serde_json::to_string_pretty(entry)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                fs::write(self.entry_path(&entry.url), json).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::warn!("Failed to write cache entry for {}: {}", entry.url, e);
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    // Nothing listens on the discard port, so requests fail right away
+    const UNREACHABLE_URL: &str = "http://127.0.0.1:9/mc/game/version_manifest.json";
+
+    #[test]
+    fn test_fresh_entry_skips_network() {
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::at(dir.path(), DEFAULT_METADATA_TTL).unwrap();
+        let client = reqwest::blocking::Client::new();
+
+        assert!(cache.fetch(&client, UNREACHABLE_URL).is_err());
+
+        cache.store(
+            UNREACHABLE_URL,
+            br#"{"versions":[]}"#,
+            Some("\"abc\"".into()),
+            None,
+        );
+        assert!(cache.contains(UNREACHABLE_URL));
+        assert_eq!(
+            cache.fetch_json(&client, UNREACHABLE_URL).unwrap(),
+            serde_json::json!({ "versions": [] })
+        );
+    }
+
+    #[test]
+    fn test_stale_entry_is_served_when_request_fails() {
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::at(dir.path(), Duration::ZERO).unwrap();
+        let client = reqwest::blocking::Client::new();
+
+        cache.store(
+            UNREACHABLE_URL,
+            b"stale",
+            None,
+            Some("Mon, 01 Jan 2024 00:00:00 GMT".into()),
+        );
+        let entry = cache.load_entry(UNREACHABLE_URL).unwrap();
+        assert!(!cache.is_fresh(&entry, unix_now()));
+        assert_eq!(cache.fetch(&client, UNREACHABLE_URL).unwrap(), b"stale");
+    }
+
+    #[test]
+    fn test_unparsable_json_is_dropped_from_cache() {
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::at(dir.path(), DEFAULT_METADATA_TTL).unwrap();
+        let client = reqwest::blocking::Client::new();
+
+        cache.store(UNREACHABLE_URL, b"{\"versions\":[", None, None);
+        assert!(cache.fetch_json(&client, UNREACHABLE_URL).is_err());
+        assert!(!cache.contains(UNREACHABLE_URL));
+        assert!(!cache.body_path(UNREACHABLE_URL).exists());
+    }
+
+    #[test]
+    fn test_offline_mode_only_reads_disk() {
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::at(dir.path(), Duration::ZERO)
+            .unwrap()
+            .offline();
+        let client = reqwest::blocking::Client::new();
+
+        let err = cache
+            .fetch(
+                &client,
+                "https://piston-meta.mojang.com/v1/packages/x/1.20.1.json",
+            )
+            .unwrap_err();
+        assert!(err.contains("sin conexión"));
+
+        cache.store(UNREACHABLE_URL, b"cached", None, None);
+        assert_eq!(cache.fetch(&client, UNREACHABLE_URL).unwrap(), b"cached");
+
+        let target = dir.path().join("versions/1.20.1/1.20.1.json");
+        cache
+            .fetch_to_file(&client, UNREACHABLE_URL, &target)
+            .unwrap();
+        assert_eq!(fs::read(target).unwrap(), b"cached");
+    }
+}
@@ -2,8 +2,16 @@
 // Main entry point for the bootstrap module - refactored from instance_bootstrap.rs
 
 pub mod download;
+pub mod fabric;
 pub mod filesystem;
+pub mod forge;
+pub mod integrity;
+pub mod legacy_assets;
 pub mod manifest;
+pub mod meta_cache;
+pub mod neoforge;
+pub mod quilt;
+pub mod store;
 pub mod tasks;
 pub mod validate;
 
@@ -11,5 +19,6 @@ pub mod validate;
 pub use download::*;
 pub use filesystem::*;
 pub use manifest::*;
+pub use store::*;
 pub use tasks::*;
 pub use validate::*;
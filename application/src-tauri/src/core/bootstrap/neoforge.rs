@@ -0,0 +1,135 @@
+// src/core/bootstrap/neoforge.rs
+// NeoForge loader installation. The installer comes from the NeoForge maven and writes a
+// version JSON inheriting from vanilla, which is merged by `ManifestMerger` like Forge's.
+
+use super::manifest::is_valid_loader_version;
+use std::path::{Path, PathBuf};
+
+pub const NEOFORGE_MAVEN_URL: &str = "https://maven.neoforged.net/releases";
+
+/// 1.20.1 NeoForge kept Forge's artifact and version naming (`net.neoforged:forge`)
+fn is_legacy_neoforge(minecraft_version: &str) -> bool {
+    minecraft_version == "1.20.1"
+}
+
+/// Builds the NeoForge installer download URL
+pub fn build_neoforge_installer_url(
+    minecraft_version: &str,
+    neoforge_version: &str,
+) -> Result<String, String> {
+    if !is_valid_loader_version(neoforge_version) {
+        return Err(format!(
+            "Versión de NeoForge inválida: {}",
+            neoforge_version
+        ));
+    }
+
+    Ok(if is_legacy_neoforge(minecraft_version) {
+        format!(
+            "{}/net/neoforged/forge/{mc}-{v}/forge-{mc}-{v}-installer.jar",
+            NEOFORGE_MAVEN_URL,
+            mc = minecraft_version,
+            v = neoforge_version
+        )
+    } else {
+        format!(
+            "{}/net/neoforged/neoforge/{v}/neoforge-{v}-installer.jar",
+            NEOFORGE_MAVEN_URL,
+            v = neoforge_version
+        )
+    })
+}
+
+/// Version ids the NeoForge installer may create, most likely first
+pub fn neoforge_version_ids(minecraft_version: &str, neoforge_version: &str) -> Vec<String> {
+    let modern = format!("neoforge-{}", neoforge_version);
+    let legacy = format!("{}-forge-{}", minecraft_version, neoforge_version);
+    if is_legacy_neoforge(minecraft_version) {
+        vec![legacy, modern]
+    } else {
+        vec![modern, legacy]
+    }
+}
+
+/// Locates the version JSON written by the NeoForge installer
+pub fn find_neoforge_version_json(
+    versions_dir: &Path,
+    minecraft_version: &str,
+    neoforge_version: &str,
+) -> Option<PathBuf> {
+    neoforge_version_ids(minecraft_version, neoforge_version)
+        .into_iter()
+        .map(|id| versions_dir.join(&id).join(format!("{}.json", id)))
+        .find(|path| path.exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bootstrap::manifest::merge_with_vanilla;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_installer_url() {
+        assert_eq!(
+            build_neoforge_installer_url("1.21.1", "21.1.77").unwrap(),
+            "https://maven.neoforged.net/releases/net/neoforged/neoforge/21.1.77/neoforge-21.1.77-installer.jar"
+        );
+        assert_eq!(
+            build_neoforge_installer_url("1.20.1", "47.1.106").unwrap(),
+            "https://maven.neoforged.net/releases/net/neoforged/forge/1.20.1-47.1.106/forge-1.20.1-47.1.106-installer.jar"
+        );
+        assert!(build_neoforge_installer_url("1.21.1", "../21.1.77").is_err());
+        assert!(build_neoforge_installer_url("1.21.1", "").is_err());
+    }
+
+    #[test]
+    fn test_find_and_merge_version_json() {
+        let dir = tempdir().unwrap();
+        let versions_dir = dir.path().join("versions");
+        assert!(find_neoforge_version_json(&versions_dir, "1.21.1", "21.1.77").is_none());
+
+        fs::create_dir_all(versions_dir.join("1.21.1")).unwrap();
+        fs::write(
+            versions_dir.join("1.21.1/1.21.1.json"),
+            json!({
+                "id": "1.21.1",
+                "mainClass": "net.minecraft.client.main.Main",
+                "libraries": [{ "name": "org.ow2.asm:asm:9.3" }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        fs::create_dir_all(versions_dir.join("neoforge-21.1.77")).unwrap();
+        let neoforge_json = json!({
+            "id": "neoforge-21.1.77",
+            "inheritsFrom": "1.21.1",
+            "mainClass": "cpw.mods.bootstraplauncher.BootstrapLauncher",
+            "libraries": [
+                { "name": "org.ow2.asm:asm:9.7" },
+                { "name": "net.neoforged.fancymodloader:loader:4.0.31" }
+            ]
+        });
+        fs::write(
+            versions_dir.join("neoforge-21.1.77/neoforge-21.1.77.json"),
+            neoforge_json.to_string(),
+        )
+        .unwrap();
+
+        let found = find_neoforge_version_json(&versions_dir, "1.21.1", "21.1.77").unwrap();
+        assert!(found.ends_with("neoforge-21.1.77/neoforge-21.1.77.json"));
+
+        let merged = merge_with_vanilla(&versions_dir, &neoforge_json).unwrap();
+        assert_eq!(
+            merged["mainClass"],
+            "cpw.mods.bootstraplauncher.BootstrapLauncher"
+        );
+        let libraries = merged["libraries"].as_array().unwrap();
+        assert_eq!(libraries.len(), 2);
+        assert!(libraries
+            .iter()
+            .any(|lib| lib["name"] == "org.ow2.asm:asm:9.7"));
+    }
+}
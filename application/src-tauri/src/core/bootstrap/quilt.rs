@@ -0,0 +1,95 @@
+// src/core/bootstrap/quilt.rs
+// Quilt loader installation. Quilt Meta serves the same kind of launcher profile as
+// Fabric Meta, so it goes through the same fetch/install/merge helpers.
+
+use super::manifest::{fetch_loader_profile, is_valid_loader_version};
+use serde_json::Value;
+use tauri_plugin_http::reqwest;
+
+pub const DEFAULT_QUILT_META_URL: &str = "https://meta.quiltmc.org";
+
+/// Version id Quilt uses for a loader profile, e.g. `quilt-loader-0.26.4-1.20.1`
+pub fn quilt_version_id(minecraft_version: &str, loader_version: &str) -> String {
+    format!("quilt-loader-{}-{}", loader_version, minecraft_version)
+}
+
+/// Builds the Quilt Meta URL of the launcher profile for a Minecraft/loader pair
+pub fn quilt_profile_url(meta_url: &str, minecraft_version: &str, loader_version: &str) -> String {
+    format!(
+        "{}/v3/versions/loader/{}/{}/profile/json",
+        meta_url.trim_end_matches('/'),
+        minecraft_version,
+        loader_version
+    )
+}
+
+/// Downloads and validates the Quilt launcher profile from `meta_url`
+pub fn fetch_quilt_profile(
+    client: &reqwest::blocking::Client,
+    meta_url: &str,
+    minecraft_version: &str,
+    loader_version: &str,
+) -> Result<Value, String> {
+    if !is_valid_loader_version(loader_version) {
+        return Err(format!("Versión de Quilt inválida: {}", loader_version));
+    }
+
+    fetch_loader_profile(
+        client,
+        &quilt_profile_url(meta_url, minecraft_version, loader_version),
+        &quilt_version_id(minecraft_version, loader_version),
+        minecraft_version,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bootstrap::manifest::{merge_with_vanilla, validate_loader_profile};
+    use serde_json::json;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_quilt_profile_url_and_merge() {
+        assert_eq!(
+            quilt_profile_url("https://meta.quiltmc.org", "1.20.1", "0.26.4"),
+            "https://meta.quiltmc.org/v3/versions/loader/1.20.1/0.26.4/profile/json"
+        );
+
+        let dir = tempdir().unwrap();
+        let versions_dir = dir.path().join("versions");
+        fs::create_dir_all(versions_dir.join("1.20.1")).unwrap();
+        fs::write(
+            versions_dir.join("1.20.1/1.20.1.json"),
+            json!({
+                "id": "1.20.1",
+                "mainClass": "net.minecraft.client.main.Main",
+                "libraries": [{ "name": "com.mojang:brigadier:1.1.8" }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let profile = json!({
+            "id": quilt_version_id("1.20.1", "0.26.4"),
+            "inheritsFrom": "1.20.1",
+            "mainClass": "org.quiltmc.loader.impl.launch.knot.KnotClient",
+            "libraries": [
+                { "name": "org.quiltmc:quilt-loader:0.26.4", "url": "https://maven.quiltmc.org/repository/release/" },
+                { "name": "net.fabricmc:intermediary:1.20.1", "url": "https://maven.fabricmc.net/" }
+            ]
+        });
+        assert!(
+            validate_loader_profile(&profile, &quilt_version_id("1.20.1", "0.26.4"), "1.20.1")
+                .is_ok()
+        );
+
+        let merged = merge_with_vanilla(&versions_dir, &profile).unwrap();
+        assert_eq!(
+            merged["mainClass"],
+            "org.quiltmc.loader.impl.launch.knot.KnotClient"
+        );
+        assert_eq!(merged["libraries"].as_array().unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,467 @@
+// src/core/bootstrap/store.rs
+// Content-addressed blob store shared by every instance (libraries, assets, client jars)
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+const REFS_FILE: &str = "refs.json";
+
+/// Several bootstrap threads may update the reference table at the same time
+static REFS_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+/// Makes staging names unique when threads of this process stage the same object
+static STAGING_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// Reference table persisted next to the blobs: SHA-1 -> instances using it
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RefTable {
+    #[serde(default)]
+    blobs: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Global store of immutable files keyed by their SHA-1.
+///
+/// Blobs live in `objects/<2 first hex chars>/<sha1>` and are exposed to instances
+/// through hardlinks (falling back to a copy when the filesystem refuses the link).
+/// Each blob keeps the set of instance IDs that reference it, so deleting an
+/// instance can release its references and collect unreferenced blobs.
+#[derive(Debug, Clone)]
+pub struct SharedStore {
+    root: PathBuf,
+}
+
+impl SharedStore {
+    /// Opens the store under the launcher config directory
+    pub fn open() -> Result<Self, String> {
+        let root = dirs::config_dir()
+            .ok_or_else(|| "No se pudo obtener el directorio de configuración".to_string())?
+            .join("dev.alexitoo.modpackstore")
+            .join("store");
+        Self::at(root)
+    }
+
+    /// Opens (creating if needed) a store rooted at the given directory
+    pub fn at(root: impl Into<PathBuf>) -> Result<Self, String> {
+        let root = root.into();
+        fs::create_dir_all(root.join("objects"))
+            .map_err(|e| format!("Error creating shared store directory: {}", e))?;
+        Ok(Self { root })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Path where the blob with the given hash is (or would be) stored
+    pub fn blob_path(&self, sha1: &str) -> PathBuf {
+        let sha1 = sha1.to_ascii_lowercase();
+        let prefix = sha1.get(0..2).unwrap_or("xx").to_string();
+        self.root.join("objects").join(prefix).join(sha1)
+    }
+
+    /// Returns true if the blob is already present in the store
+    pub fn contains(&self, sha1: &str) -> bool {
+        is_valid_sha1(sha1) && self.blob_path(sha1).is_file()
+    }
+
+    /// Returns the blob path only if the blob is present
+    pub fn resolve(&self, sha1: &str) -> Option<PathBuf> {
+        if self.contains(sha1) {
+            Some(self.blob_path(sha1))
+        } else {
+            None
+        }
+    }
+
+    /// Deletes the blob if its content no longer matches its hash, so the next
+    /// `materialize` fetches it again. Returns true if a blob was removed.
+    pub fn evict_if_corrupted(&self, sha1: &str) -> Result<bool, String> {
+        let Some(blob) = self.resolve(sha1) else {
+            return Ok(false);
+        };
+        if sha1_of_file(&blob)?.eq_ignore_ascii_case(sha1) {
+            return Ok(false);
+        }
+
+        log::warn!("Evicting corrupted blob {} from shared store", sha1);
+        fs::remove_file(&blob)
+            .map_err(|e| format!("Error removing corrupted blob {}: {}", sha1, e))?;
+        Ok(true)
+    }
+
+    /// Copies an existing file into the store after verifying its hash
+    pub fn import_file(&self, source: &Path, sha1: &str) -> Result<PathBuf, String> {
+        if !is_valid_sha1(sha1) {
+            return Err(format!("Invalid SHA-1 for shared store: {}", sha1));
+        }
+
+        let blob = self.blob_path(sha1);
+        if blob.is_file() {
+            return Ok(blob);
+        }
+
+        let actual = sha1_of_file(source)?;
+        if !actual.eq_ignore_ascii_case(sha1) {
+            return Err(format!(
+                "Hash mismatch importing {}: expected {}, got {}",
+                source.display(),
+                sha1,
+                actual
+            ));
+        }
+
+        if let Some(parent) = blob.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Error creating shared store directory: {}", e))?;
+        }
+
+        // Write to a temporary name first so a crash never leaves a truncated blob
+        let staging = staging_path(&blob);
+        fs::copy(source, &staging)
+            .map_err(|e| format!("Error copying {} into store: {}", source.display(), e))?;
+        fs::rename(&staging, &blob).map_err(|e| {
+            let _ = fs::remove_file(&staging);
+            format!("Error finalizing shared store blob {}: {}", sha1, e)
+        })?;
+
+        Ok(blob)
+    }
+
+    /// Makes the blob available at `destination`, preferring a hardlink over a copy.
+    /// An existing destination is left untouched.
+    pub fn link_into(&self, sha1: &str, destination: &Path) -> Result<(), String> {
+        let blob = self
+            .resolve(sha1)
+            .ok_or_else(|| format!("Blob {} not found in shared store", sha1))?;
+
+        if destination.exists() {
+            return Ok(());
+        }
+
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Error creating directory: {}", e))?;
+        }
+
+        if let Err(link_err) = fs::hard_link(&blob, destination) {
+            log::debug!(
+                "Hardlink failed for {} ({}), copying instead",
+                destination.display(),
+                link_err
+            );
+            fs::copy(&blob, destination).map_err(|e| {
+                format!(
+                    "Error copying blob {} to {}: {}",
+                    sha1,
+                    destination.display(),
+                    e
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Ensures the blob is in the store (fetching it with `fetch` if needed),
+    /// records the reference for `instance_id` and links it into `destination`.
+    ///
+    /// `fetch` receives a staging path it must write the file to; the content is
+    /// verified against `sha1` before it is moved into the store.
+    pub fn materialize<F>(
+        &self,
+        sha1: &str,
+        destination: &Path,
+        instance_id: &str,
+        fetch: F,
+    ) -> Result<(), String>
+    where
+        F: FnOnce(&Path) -> Result<(), String>,
+    {
+        if !is_valid_sha1(sha1) {
+            return Err(format!("Invalid SHA-1 for shared store: {}", sha1));
+        }
+
+        if !self.contains(sha1) {
+            let blob = self.blob_path(sha1);
+            if let Some(parent) = blob.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Error creating shared store directory: {}", e))?;
+            }
+
+            let staging = staging_path(&blob);
+            fetch(&staging)?;
+            self.commit_staged(&staging, sha1)
+                .map_err(|e| format!("{} ({})", e, destination.display()))?;
+        }
+
+        // Only blobs already in place are referenced, so failed fetches leave no dangling refs
+        self.add_references([sha1], instance_id)?;
+        self.link_into(sha1, destination)
+    }
+
+    /// Unique path, next to the blob, where an object can be fetched before `commit_staged`
+    pub fn staging_path(&self, sha1: &str) -> PathBuf {
+        staging_path(&self.blob_path(sha1))
+    }
+
+    /// Verifies a fetched staging file against `sha1` and moves it into the store. The
+    /// staging file is removed either way.
+    pub fn commit_staged(&self, staging: &Path, sha1: &str) -> Result<PathBuf, String> {
+        let blob = self.blob_path(sha1);
+        let actual = sha1_of_file(staging).map_err(|e| {
+            let _ = fs::remove_file(staging);
+            e
+        })?;
+        if !actual.eq_ignore_ascii_case(sha1) {
+            let _ = fs::remove_file(staging);
+            return Err(format!("Hash mismatch: expected {}, got {}", sha1, actual));
+        }
+
+        fs::rename(staging, &blob).map_err(|e| {
+            let _ = fs::remove_file(staging);
+            format!("Error finalizing shared store blob {}: {}", sha1, e)
+        })?;
+        Ok(blob)
+    }
+
+    /// Records that `instance_id` uses each of the given blobs
+    pub fn add_references<'s, I>(&self, hashes: I, instance_id: &str) -> Result<(), String>
+    where
+        I: IntoIterator<Item = &'s str>,
+    {
+        if instance_id.is_empty() {
+            return Ok(());
+        }
+
+        self.update_refs(|table| {
+            for hash in hashes {
+                if is_valid_sha1(hash) {
+                    table
+                        .blobs
+                        .entry(hash.to_ascii_lowercase())
+                        .or_default()
+                        .insert(instance_id.to_string());
+                }
+            }
+        })
+    }
+
+    /// Number of instances currently referencing the blob
+    pub fn reference_count(&self, sha1: &str) -> usize {
+        let _guard = REFS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        self.load_refs()
+            .blobs
+            .get(&sha1.to_ascii_lowercase())
+            .map(|set| set.len())
+            .unwrap_or(0)
+    }
+
+    /// Drops every reference held by `instance_id`. Returns how many were released.
+    pub fn release_instance(&self, instance_id: &str) -> Result<usize, String> {
+        let mut released = 0;
+        self.update_refs(|table| {
+            for owners in table.blobs.values_mut() {
+                if owners.remove(instance_id) {
+                    released += 1;
+                }
+            }
+        })?;
+        Ok(released)
+    }
+
+    /// Removes blobs no instance references anymore. Returns how many were deleted.
+    pub fn collect_garbage(&self) -> Result<usize, String> {
+        let mut orphaned = Vec::new();
+        self.update_refs(|table| {
+            table.blobs.retain(|hash, owners| {
+                if owners.is_empty() {
+                    orphaned.push(hash.clone());
+                    false
+                } else {
+                    true
+                }
+            });
+        })?;
+
+        let mut removed = 0;
+        for hash in orphaned {
+            let blob = self.blob_path(&hash);
+            match fs::remove_file(&blob) {
+                Ok(()) => removed += 1,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => log::warn!("Failed to remove blob {}: {}", blob.display(), e),
+            }
+        }
+
+        if removed > 0 {
+            log::info!("Shared store GC removed {} unreferenced blobs", removed);
+        }
+
+        Ok(removed)
+    }
+
+    fn refs_path(&self) -> PathBuf {
+        self.root.join(REFS_FILE)
+    }
+
+    fn load_refs(&self) -> RefTable {
+        fs::read_to_string(self.refs_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn update_refs<F>(&self, update: F) -> Result<(), String>
+    where
+        F: FnOnce(&mut RefTable),
+    {
+        let _guard = REFS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut table = self.load_refs();
+        update(&mut table);
+
+        let content = serde_json::to_string(&table)
+            .map_err(|e| format!("Error serializing store references: {}", e))?;
+        let path = self.refs_path();
+        let staging = staging_path(&path);
+        fs::write(&staging, content)
+            .map_err(|e| format!("Error writing store references: {}", e))?;
+        fs::rename(&staging, &path).map_err(|e| format!("Error writing store references: {}", e))
+    }
+}
+
+/// Checks that the value looks like a hex-encoded SHA-1
+pub fn is_valid_sha1(hash: &str) -> bool {
+    hash.len() == 40 && hash.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Computes the SHA-1 of a file without loading it fully into memory
+pub fn sha1_of_file(path: &Path) -> Result<String, String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open file {}: {}", path.display(), e))?;
+    let mut hasher = Sha1::new();
+    let mut buffer = [0u8; 64 * 1024];
+
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read file {}: {}", path.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Unique sibling of `target` to write into before renaming it into place
+pub(crate) fn staging_path(target: &Path) -> PathBuf {
+    let mut name = target
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    let sequence = STAGING_SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    name.push(format!(".{}.{}.tmp", std::process::id(), sequence));
+    target.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const HELLO_SHA1: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+    #[test]
+    fn test_materialize_links_and_counts_references() {
+        let dir = tempdir().unwrap();
+        let store = SharedStore::at(dir.path().join("store")).unwrap();
+
+        let dest_a = dir.path().join("a/libraries/hello.jar");
+        let dest_b = dir.path().join("b/libraries/hello.jar");
+
+        store
+            .materialize(HELLO_SHA1, &dest_a, "instance-a", |staging| {
+                fs::write(staging, b"hello").map_err(|e| e.to_string())
+            })
+            .unwrap();
+        // Second instance must be served from the store without fetching
+        store
+            .materialize(HELLO_SHA1, &dest_b, "instance-b", |_| {
+                Err("should not download twice".to_string())
+            })
+            .unwrap();
+
+        assert_eq!(fs::read(&dest_a).unwrap(), b"hello");
+        assert_eq!(fs::read(&dest_b).unwrap(), b"hello");
+        assert_eq!(store.reference_count(HELLO_SHA1), 2);
+    }
+
+    #[test]
+    fn test_materialize_rejects_wrong_content() {
+        let dir = tempdir().unwrap();
+        let store = SharedStore::at(dir.path().join("store")).unwrap();
+        let dest = dir.path().join("hello.jar");
+
+        let result = store.materialize(HELLO_SHA1, &dest, "instance-a", |staging| {
+            fs::write(staging, b"tampered").map_err(|e| e.to_string())
+        });
+
+        assert!(result.is_err());
+        assert!(!store.contains(HELLO_SHA1));
+        assert!(!dest.exists());
+        assert_eq!(store.reference_count(HELLO_SHA1), 0);
+    }
+
+    #[test]
+    fn test_staging_paths_are_unique() {
+        let blob = Path::new("/store/aa/aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d");
+        let first = staging_path(blob);
+        assert_ne!(first, staging_path(blob));
+        assert_eq!(first.parent(), blob.parent());
+    }
+
+    #[test]
+    fn test_evict_if_corrupted() {
+        let dir = tempdir().unwrap();
+        let store = SharedStore::at(dir.path().join("store")).unwrap();
+        let dest = dir.path().join("hello.jar");
+        store
+            .materialize(HELLO_SHA1, &dest, "a", |staging| {
+                fs::write(staging, b"hello").map_err(|e| e.to_string())
+            })
+            .unwrap();
+
+        assert!(!store.evict_if_corrupted(HELLO_SHA1).unwrap());
+        fs::write(store.blob_path(HELLO_SHA1), b"bit rot").unwrap();
+        assert!(store.evict_if_corrupted(HELLO_SHA1).unwrap());
+        assert!(!store.contains(HELLO_SHA1));
+    }
+
+    #[test]
+    fn test_gc_only_removes_unreferenced_blobs() {
+        let dir = tempdir().unwrap();
+        let store = SharedStore::at(dir.path().join("store")).unwrap();
+        let write_hello = |staging: &Path| fs::write(staging, b"hello").map_err(|e| e.to_string());
+
+        store
+            .materialize(HELLO_SHA1, &dir.path().join("a.jar"), "a", write_hello)
+            .unwrap();
+        store
+            .materialize(HELLO_SHA1, &dir.path().join("b.jar"), "b", write_hello)
+            .unwrap();
+
+        store.release_instance("a").unwrap();
+        assert_eq!(store.collect_garbage().unwrap(), 0);
+        assert!(store.contains(HELLO_SHA1));
+
+        store.release_instance("b").unwrap();
+        assert_eq!(store.collect_garbage().unwrap(), 1);
+        assert!(!store.contains(HELLO_SHA1));
+    }
+}
@@ -18,6 +18,7 @@ pub enum Stage {
     ValidatingAssets { current: usize, total: usize },
     DownloadingForgeLibraries { current: usize, total: usize },
     DownloadingModpackFiles { current: usize, total: usize },
+    RunningForgeProcessors { current: usize, total: usize },
 }
 
 /// Emits a status update event to the frontend.
@@ -159,6 +160,17 @@ fn format_stage_message(stage: &Stage) -> String {
                 current, total, percentage
             )
         }
+        Stage::RunningForgeProcessors { current, total } => {
+            let percentage = if *total > 0 {
+                (*current as f32 * 100.0) / *total as f32
+            } else {
+                0.0
+            };
+            format!(
+                "Ejecutando procesadores de Forge: {}/{} ({:.1}%)",
+                current, total, percentage
+            )
+        }
     }
 }
 
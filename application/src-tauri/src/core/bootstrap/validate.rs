@@ -3,11 +3,15 @@
 
 use crate::core::bootstrap::download::download_file;
 use crate::core::bootstrap::filesystem::create_asset_directories;
-use crate::core::bootstrap::manifest::get_asset_index_info;
+use crate::core::bootstrap::legacy_assets::materialize_legacy_assets;
+use crate::core::bootstrap::manifest::{get_asset_index_info, get_logging_config_info};
+use crate::core::bootstrap::meta_cache::MetadataCache;
+use crate::core::bootstrap::store::SharedStore;
 use crate::core::bootstrap::tasks::{emit_status, emit_status_with_stage, Stage};
 use crate::core::minecraft_instance::MinecraftInstance;
 use crate::core::modpack_file_manager::DownloadManager;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Result as IoResult};
 use std::path::{Path, PathBuf};
@@ -16,6 +20,7 @@ use tauri_plugin_http::reqwest;
 /// Revalidates and downloads missing assets for a Minecraft instance
 pub fn revalidate_assets(
     client: &reqwest::blocking::Client,
+    metadata: &MetadataCache,
     instance: &MinecraftInstance,
     version_details: &Value,
 ) -> IoResult<()> {
@@ -48,12 +53,14 @@ pub fn revalidate_assets(
             "Descargando índice de assets para la versión {}",
             instance.minecraftVersion
         );
-        download_file(client, &assets_index_url, &assets_index_file).map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::Other,
-                format!("Error al descargar índice de assets: {}", e),
-            )
-        })?;
+        metadata
+            .fetch_to_file(client, &assets_index_url, &assets_index_file)
+            .map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("Error al descargar índice de assets: {}", e),
+                )
+            })?;
     }
 
     // Leer y procesar el índice de assets
@@ -77,6 +84,27 @@ pub fn revalidate_assets(
 
     download_missing_assets(client, instance, &assets_objects_dir, objects)?;
 
+    // Indexes from before 1.7.3 are read by file name instead of by hash
+    materialize_legacy_assets(
+        &assets_index_root,
+        &assets_index_id,
+        &minecraft_folder.join("assets"),
+        &minecraft_folder,
+    )?;
+
+    // Log4j configuration that makes the game print structured log events
+    if let Some((config_id, config_url, _)) = get_logging_config_info(version_details) {
+        let config_file = minecraft_folder
+            .join("assets")
+            .join("log_configs")
+            .join(&config_id);
+        if !config_file.exists() {
+            if let Err(e) = metadata.fetch_to_file(client, &config_url, &config_file) {
+                log::warn!("Failed to download log configuration {}: {}", config_id, e);
+            }
+        }
+    }
+
     log::info!("Asset revalidation completed");
 
     // Emitir evento de finalización
@@ -102,6 +130,15 @@ fn download_missing_assets(
     let mut processed_assets = 0;
     let mut missing_assets_info = Vec::new();
 
+    // Assets are shared through the global store; without it we fall back to per-instance copies
+    let store = SharedStore::open()
+        .map_err(|e| log::warn!("Shared store unavailable, using per-instance assets: {}", e))
+        .ok();
+    let mut store_hashes: Vec<String> = Vec::new();
+    let mut pending_links: Vec<(String, PathBuf)> = Vec::new();
+    // Blob hash -> staging file it is downloaded to before entering the store
+    let mut staged_blobs: HashMap<String, PathBuf> = HashMap::new();
+
     log::info!("Validando {} assets...", total_assets);
 
     // First pass: identify missing assets and collect download information
@@ -128,13 +165,30 @@ fn download_missing_assets(
                 hash_prefix, hash
             );
 
-            missing_assets_info.push((asset_url.clone(), asset_file.clone(), hash.to_string()));
+            match &store {
+                Some(store) => {
+                    store_hashes.push(hash.to_string());
+                    // Several asset names may share one blob; fetch it only once
+                    // Several asset names may share one blob; fetch it only once
+                    if !store.contains(hash) && !staged_blobs.contains_key(hash) {
+                        let staging = store.staging_path(hash);
+                        staged_blobs.insert(hash.to_string(), staging.clone());
+                        missing_assets_info.push((asset_url, staging, hash.to_string()));
+                    }
+                    pending_links.push((hash.to_string(), asset_file));
+                }
+                None => {
+                    missing_assets_info.push((asset_url, asset_file, hash.to_string()));
+                }
+            }
         }
     }
 
     let missing_count = missing_assets_info.len();
 
     if missing_count == 0 {
+        commit_store_assets(store.as_ref(), &staged_blobs, &store_hashes, instance)?;
+        link_assets_from_store(store.as_ref(), &pending_links)?;
         log::info!("Todos los assets están validados.");
         return Ok(());
     }
@@ -152,7 +206,7 @@ fn download_missing_assets(
         )
     })?;
 
-    runtime.block_on(async {
+    let download_result = runtime.block_on(async {
         // Create DownloadManager optimized for asset downloads (higher concurrency for small files)
         let download_manager = DownloadManager::with_concurrency(8);
 
@@ -186,7 +240,64 @@ fn download_missing_assets(
             missing_count
         );
         Ok::<(), io::Error>(())
-    })?;
+    });
+
+    // Blobs that did download still enter the store even if others failed
+    commit_store_assets(store.as_ref(), &staged_blobs, &store_hashes, instance)?;
+    download_result?;
+    link_assets_from_store(store.as_ref(), &pending_links)?;
+
+    Ok(())
+}
+
+/// Moves downloaded blobs into the store after verifying them, then references the
+/// instance's assets that are in the store, so failed downloads leave no dangling refs
+fn commit_store_assets(
+    store: Option<&SharedStore>,
+    staged_blobs: &HashMap<String, PathBuf>,
+    store_hashes: &[String],
+    instance: &MinecraftInstance,
+) -> IoResult<()> {
+    let store = match store {
+        Some(store) => store,
+        None => return Ok(()),
+    };
+
+    for (hash, staging) in staged_blobs {
+        if !staging.is_file() {
+            continue;
+        }
+        if let Err(e) = store.commit_staged(staging, hash) {
+            log::warn!("Asset {} rejected by the shared store: {}", hash, e);
+        }
+    }
+
+    store
+        .add_references(
+            store_hashes
+                .iter()
+                .map(String::as_str)
+                .filter(|hash| store.contains(hash)),
+            &instance.instanceId,
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+}
+
+/// Exposes shared store blobs inside the instance `assets/objects` directory
+fn link_assets_from_store(
+    store: Option<&SharedStore>,
+    pending_links: &[(String, PathBuf)],
+) -> IoResult<()> {
+    let store = match store {
+        Some(store) => store,
+        None => return Ok(()),
+    };
+
+    for (hash, asset_file) in pending_links {
+        store
+            .link_into(hash, asset_file)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    }
 
     Ok(())
 }
@@ -369,6 +480,39 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_commit_store_assets_references_only_landed_blobs() {
+        const HELLO_SHA1: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+        const MISSING_SHA1: &str = "da39a3ee5e6b4b0d3255bfef95601890afd80709";
+        const TAMPERED_SHA1: &str = "0beec7b5ea3f0fdbc95d0dd47f3c5bc275da8a33";
+
+        let temp_dir = tempdir().unwrap();
+        let store = SharedStore::at(temp_dir.path().join("store")).unwrap();
+        let mut instance = MinecraftInstance::new();
+        instance.instanceId = "test".to_string();
+
+        let mut staged = HashMap::new();
+        for (hash, content) in [(HELLO_SHA1, "hello"), (TAMPERED_SHA1, "tampered")] {
+            let staging = store.staging_path(hash);
+            fs::create_dir_all(staging.parent().unwrap()).unwrap();
+            fs::write(&staging, content).unwrap();
+            staged.insert(hash.to_string(), staging);
+        }
+        // Its download failed, nothing was staged
+        staged.insert(MISSING_SHA1.to_string(), store.staging_path(MISSING_SHA1));
+
+        let hashes = [HELLO_SHA1, MISSING_SHA1, TAMPERED_SHA1].map(String::from);
+        commit_store_assets(Some(&store), &staged, &hashes, &instance).unwrap();
+
+        assert!(store.contains(HELLO_SHA1));
+        assert_eq!(store.reference_count(HELLO_SHA1), 1);
+        for hash in [MISSING_SHA1, TAMPERED_SHA1] {
+            assert!(!store.contains(hash));
+            assert_eq!(store.reference_count(hash), 0);
+        }
+        assert!(staged.values().all(|staging| !staging.exists()));
+    }
+
     /// Test the enhanced asset download logic structure
     #[tokio::test]
     async fn test_download_missing_assets_structure() {
@@ -391,6 +535,10 @@ mod tests {
             instanceDirectory: Some(temp_dir.path().to_string_lossy().to_string()),
             forgeVersion: None,
             javaPath: None,
+            javaPathPinned: false,
+            loader: None,
+            loaderVersion: None,
+            launchOptions: Default::default(),
         };
 
         // Create mock assets index with some test assets
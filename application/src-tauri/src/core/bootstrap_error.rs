@@ -16,6 +16,8 @@ pub enum BootstrapStep {
     DownloadingForgeInstaller,
     RunningForgeInstaller,
     CreatingLauncherProfiles,
+    VerifyingManifestSignature,
+    InstallingModLoader,
 }
 
 impl fmt::Display for BootstrapStep {
@@ -33,6 +35,8 @@ impl fmt::Display for BootstrapStep {
             BootstrapStep::DownloadingForgeInstaller => "Descargando instalador de Forge",
             BootstrapStep::RunningForgeInstaller => "Ejecutando instalador de Forge",
             BootstrapStep::CreatingLauncherProfiles => "Creando perfiles del launcher",
+            BootstrapStep::VerifyingManifestSignature => "Verificando firma del modpack",
+            BootstrapStep::InstallingModLoader => "Instalando mod loader",
         };
         write!(f, "{}", step_name)
     }
@@ -46,6 +50,7 @@ pub enum ErrorCategory {
     Java,
     Forge,
     Configuration,
+    Security,
     Other,
 }
 
@@ -110,6 +115,18 @@ impl BootstrapError {
         Self::new(step, ErrorCategory::Filesystem, message)
             .with_suggestion("Verifica los permisos de escritura en el directorio de la instancia")
     }
+
+    /// Create a manifest signature error
+    pub fn signature_error(message: impl Into<String>) -> Self {
+        Self::new(
+            BootstrapStep::VerifyingManifestSignature,
+            ErrorCategory::Security,
+            message,
+        )
+        .with_suggestion(
+            "No se instalará ningún archivo. Verifica tu conexión (podría estar siendo interceptada) o actualiza el launcher",
+        )
+    }
 }
 
 impl fmt::Display for BootstrapError {
@@ -0,0 +1,269 @@
+// src-tauri/src/core/file_hash_index.rs
+// Per-instance cache of file hashes so validation only re-hashes files that changed
+
+use crate::core::bootstrap::store::sha1_of_file;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const INDEX_FILE: &str = "file_index.json";
+const INDEX_VERSION: u32 = 1;
+
+/// Files modified this recently are hashed but not cached: a write landing in the
+/// same timestamp tick could otherwise keep the old size/mtime with new content.
+const RACY_WINDOW: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexedFile {
+    size: u64,
+    #[serde(rename = "mtimeSecs")]
+    mtime_secs: u64,
+    #[serde(rename = "mtimeNanos")]
+    mtime_nanos: u32,
+    hash: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IndexFile {
+    version: u32,
+    #[serde(default)]
+    files: HashMap<String, IndexedFile>,
+}
+
+/// Cache of `(path, size, mtime) -> SHA-1` stored in `<instance>/.modpack_cache`.
+///
+/// Paths are kept relative to the instance's minecraft directory with `/` separators.
+/// A cached hash is only trusted while the file's size and modification time match.
+#[derive(Debug)]
+pub struct FileHashIndex {
+    index_path: PathBuf,
+    root: PathBuf,
+    files: HashMap<String, IndexedFile>,
+    dirty: bool,
+}
+
+impl FileHashIndex {
+    /// Loads the index of an instance, starting empty if it is missing or unreadable
+    pub fn load(instance_dir: &Path, minecraft_dir: &Path) -> Self {
+        let index_path = instance_dir.join(".modpack_cache").join(INDEX_FILE);
+
+        let files = fs::read_to_string(&index_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<IndexFile>(&content).ok())
+            .filter(|index| index.version == INDEX_VERSION)
+            .map(|index| index.files)
+            .unwrap_or_default();
+
+        log::debug!(
+            "[HashIndex] Loaded {} cached hashes from {}",
+            files.len(),
+            index_path.display()
+        );
+
+        Self {
+            index_path,
+            root: minecraft_dir.to_path_buf(),
+            files,
+            dirty: false,
+        }
+    }
+
+    /// Returns the SHA-1 of the file, hashing it only if it changed since it was indexed
+    pub fn hash_of(&mut self, path: &Path) -> Result<String, String> {
+        let metadata = fs::metadata(path)
+            .map_err(|e| format!("Failed to read metadata for {}: {}", path.display(), e))?;
+        let (mtime_secs, mtime_nanos) = modification_time(&metadata);
+
+        let key = self.key_for(path);
+        if let Some(entry) = key.as_ref().and_then(|k| self.files.get(k)) {
+            if entry.size == metadata.len()
+                && entry.mtime_secs == mtime_secs
+                && entry.mtime_nanos == mtime_nanos
+            {
+                return Ok(entry.hash.clone());
+            }
+        }
+
+        let hash = sha1_of_file(path)?;
+
+        if let Some(key) = key {
+            if is_settled(mtime_secs, mtime_nanos) {
+                self.files.insert(
+                    key,
+                    IndexedFile {
+                        size: metadata.len(),
+                        mtime_secs,
+                        mtime_nanos,
+                        hash: hash.clone(),
+                    },
+                );
+            } else {
+                self.files.remove(&key);
+            }
+            self.dirty = true;
+        }
+
+        Ok(hash)
+    }
+
+    /// Moves the cached entry along with a renamed file (rename keeps size and mtime)
+    pub fn rename(&mut self, from: &Path, to: &Path) {
+        let (Some(from_key), Some(to_key)) = (self.key_for(from), self.key_for(to)) else {
+            return;
+        };
+        if let Some(entry) = self.files.remove(&from_key) {
+            self.files.insert(to_key, entry);
+            self.dirty = true;
+        }
+    }
+
+    /// Number of cached entries
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Drops entries for deleted files and writes the index if anything changed
+    pub fn save(&mut self) -> Result<(), String> {
+        let root = self.root.clone();
+        let before = self.files.len();
+        self.files.retain(|key, _| root.join(key).is_file());
+        if self.files.len() != before {
+            self.dirty = true;
+        }
+
+        if !self.dirty {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.index_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+
+        let content = serde_json::to_string(&serde_json::json!({
+            "version": INDEX_VERSION,
+            "files": &self.files,
+        }))
+        .map_err(|e| format!("Failed to serialize hash index: {}", e))?;
+
+        // Write next to the index and rename so a crash never leaves a truncated file
+        let staging = self.index_path.with_extension("json.tmp");
+        fs::write(&staging, content).map_err(|e| format!("Failed to write hash index: {}", e))?;
+        fs::rename(&staging, &self.index_path)
+            .map_err(|e| format!("Failed to write hash index: {}", e))?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    fn key_for(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let parts: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join("/"))
+        }
+    }
+}
+
+fn modification_time(metadata: &fs::Metadata) -> (u64, u32) {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|d| (d.as_secs(), d.subsec_nanos()))
+        .unwrap_or((0, 0))
+}
+
+fn is_settled(mtime_secs: u64, mtime_nanos: u32) -> bool {
+    let modified = UNIX_EPOCH + Duration::new(mtime_secs, mtime_nanos);
+    SystemTime::now()
+        .duration_since(modified)
+        .map(|age| age >= RACY_WINDOW)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const HELLO_SHA1: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+    /// Backdates a file so it falls outside the racy window
+    fn write_settled(path: &Path, content: &[u8], age_secs: u64) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+        let file = fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(age_secs))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_cached_hash_is_reused_until_file_changes() {
+        let dir = tempdir().unwrap();
+        let minecraft_dir = dir.path().join("minecraft");
+        let file = minecraft_dir.join("mods/a.jar");
+        write_settled(&file, b"hello", 60);
+
+        let mut index = FileHashIndex::load(dir.path(), &minecraft_dir);
+        assert_eq!(index.hash_of(&file).unwrap(), HELLO_SHA1);
+        index.save().unwrap();
+
+        // A stale cached hash proves the file was not read again
+        let mut index = FileHashIndex::load(dir.path(), &minecraft_dir);
+        index.files.get_mut("mods/a.jar").unwrap().hash = "cached".to_string();
+        assert_eq!(index.hash_of(&file).unwrap(), "cached");
+
+        // Changing the content (and therefore size/mtime) forces a re-hash
+        write_settled(&file, b"hello world", 30);
+        assert_ne!(index.hash_of(&file).unwrap(), "cached");
+    }
+
+    #[test]
+    fn test_recently_modified_files_are_not_cached() {
+        let dir = tempdir().unwrap();
+        let minecraft_dir = dir.path().join("minecraft");
+        let file = minecraft_dir.join("config/fresh.toml");
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(&file, b"hello").unwrap();
+
+        let mut index = FileHashIndex::load(dir.path(), &minecraft_dir);
+        assert_eq!(index.hash_of(&file).unwrap(), HELLO_SHA1);
+        assert!(index.is_empty());
+    }
+
+    #[test]
+    fn test_save_prunes_deleted_files_and_follows_renames() {
+        let dir = tempdir().unwrap();
+        let minecraft_dir = dir.path().join("minecraft");
+        let kept = minecraft_dir.join("mods/kept.jar");
+        let deleted = minecraft_dir.join("mods/deleted.jar");
+        write_settled(&kept, b"hello", 60);
+        write_settled(&deleted, b"bye", 60);
+
+        let mut index = FileHashIndex::load(dir.path(), &minecraft_dir);
+        index.hash_of(&kept).unwrap();
+        index.hash_of(&deleted).unwrap();
+
+        let moved = minecraft_dir.join("mods/moved.jar");
+        fs::rename(&kept, &moved).unwrap();
+        index.rename(&kept, &moved);
+        fs::remove_file(&deleted).unwrap();
+        index.save().unwrap();
+
+        let index = FileHashIndex::load(dir.path(), &minecraft_dir);
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.files["mods/moved.jar"].hash, HELLO_SHA1);
+    }
+}
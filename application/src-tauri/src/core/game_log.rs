@@ -0,0 +1,512 @@
+// src/core/game_log.rs
+// Live game output: stdout/stderr lines are turned into structured records (Minecraft prints
+// log4j XML events when launched with its logging configuration, plain pattern lines
+// otherwise), kept in a bounded buffer per instance for the UI and written to a session log
+// file under the instance directory.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::sync::Mutex;
+use std::thread;
+
+/// Records kept per instance for the log view
+pub const LOG_BUFFER_CAPACITY: usize = 5000;
+/// Lines kept for the exit event (crash dialog and error detection)
+const EXIT_TAIL_LINES: usize = 200;
+
+lazy_static! {
+    static ref LOG_BUFFERS: Mutex<HashMap<String, LogBuffer>> = Mutex::new(HashMap::new());
+    // [12:34:56] [Render thread/INFO]: message, Forge adds the logger: [...] [thread/INFO] [logger/]: message
+    static ref RE_PATTERN_LINE: Regex = Regex::new(
+        r"^\[[^\]]+\] \[(?P<thread>.+?)/(?P<level>TRACE|DEBUG|INFO|WARN|ERROR|FATAL)\](?: \[(?P<logger>[^\]]*)\])?: (?P<message>.*)$"
+    )
+    .unwrap();
+    static ref RE_XML_ATTRIBUTE: Regex = Regex::new(r#"(\w+)="([^"]*)""#).unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogRecord {
+    pub stream: LogStream,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+    pub level: String,
+    pub thread: Option<String>,
+    pub logger: Option<String>,
+    pub message: String,
+    pub throwable: Option<String>,
+}
+
+impl LogRecord {
+    /// Output that is not a log4j event, e.g. the JVM's own messages
+    fn plain(stream: LogStream, line: &str) -> Self {
+        Self {
+            stream,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            level: match stream {
+                LogStream::Stdout => "INFO",
+                LogStream::Stderr => "ERROR",
+            }
+            .to_string(),
+            thread: None,
+            logger: None,
+            message: line.to_string(),
+            throwable: None,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.stream == LogStream::Stderr || self.level == "ERROR" || self.level == "FATAL"
+    }
+
+    /// Text form used for the session file: the game's own pattern layout
+    pub fn render(&self) -> String {
+        let mut text = match &self.thread {
+            Some(thread) => {
+                let time = chrono::DateTime::from_timestamp_millis(self.timestamp)
+                    .map(|time| {
+                        time.with_timezone(&chrono::Local)
+                            .format("%H:%M:%S")
+                            .to_string()
+                    })
+                    .unwrap_or_default();
+                match &self.logger {
+                    Some(logger) => format!(
+                        "[{}] [{}/{}] [{}]: {}",
+                        time, thread, self.level, logger, self.message
+                    ),
+                    None => format!("[{}] [{}/{}]: {}", time, thread, self.level, self.message),
+                }
+            }
+            None => self.message.clone(),
+        };
+        if let Some(throwable) = &self.throwable {
+            text.push('\n');
+            text.push_str(throwable.trim_end());
+        }
+        text
+    }
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Text of `<log4j:{name}>`, which log4j writes as CDATA (split in several sections when the
+/// text itself contains `]]>`)
+fn element_text(event: &str, name: &str) -> Option<String> {
+    let open = format!("<log4j:{}>", name);
+    let close = format!("</log4j:{}>", name);
+    let start = event.find(&open)? + open.len();
+    let end = start + event[start..].find(&close)?;
+    let content = event[start..end].trim();
+
+    match content
+        .strip_prefix("<![CDATA[")
+        .and_then(|content| content.strip_suffix("]]>"))
+    {
+        Some(cdata) => Some(cdata.replace("]]><![CDATA[", "")),
+        None => Some(unescape_xml(content)),
+    }
+}
+
+/// Parses one `<log4j:Event ...>...</log4j:Event>` block
+pub fn parse_log4j_event(event: &str, stream: LogStream) -> Option<LogRecord> {
+    let tag_start = event.find("<log4j:Event")?;
+    let tag_end = tag_start + event[tag_start..].find('>')?;
+    let attributes: HashMap<&str, String> = RE_XML_ATTRIBUTE
+        .captures_iter(&event[tag_start..tag_end])
+        .filter_map(|captures| {
+            Some((
+                captures.get(1)?.as_str(),
+                unescape_xml(captures.get(2)?.as_str()),
+            ))
+        })
+        .collect();
+
+    Some(LogRecord {
+        stream,
+        timestamp: attributes
+            .get("timestamp")
+            .and_then(|timestamp| timestamp.parse().ok())
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis()),
+        level: attributes
+            .get("level")
+            .cloned()
+            .unwrap_or_else(|| "INFO".to_string()),
+        thread: attributes.get("thread").cloned(),
+        logger: attributes.get("logger").cloned(),
+        message: element_text(event, "Message").unwrap_or_default(),
+        throwable: element_text(event, "Throwable"),
+    })
+}
+
+/// Parses a line printed with the game's default pattern layout
+pub fn parse_pattern_line(line: &str, stream: LogStream) -> LogRecord {
+    let Some(captures) = RE_PATTERN_LINE.captures(line) else {
+        return LogRecord::plain(stream, line);
+    };
+    LogRecord {
+        thread: Some(captures["thread"].to_string()),
+        level: captures["level"].to_string(),
+        logger: captures
+            .name("logger")
+            .map(|logger| logger.as_str().trim_end_matches('/').to_string())
+            .filter(|logger| !logger.is_empty()),
+        message: captures["message"].to_string(),
+        ..LogRecord::plain(stream, line)
+    }
+}
+
+/// Turns the lines of one stream into records, joining the lines of multi-line XML events
+#[derive(Debug)]
+pub struct Log4jParser {
+    stream: LogStream,
+    pending: Option<String>,
+}
+
+impl Log4jParser {
+    pub fn new(stream: LogStream) -> Self {
+        Self {
+            stream,
+            pending: None,
+        }
+    }
+
+    pub fn push_line(&mut self, line: &str) -> Option<LogRecord> {
+        let line = line.trim_end_matches(['\r', '\n']);
+
+        let event = match self.pending.take() {
+            Some(mut pending) => {
+                pending.push('\n');
+                pending.push_str(line);
+                pending
+            }
+            None if line.trim_start().starts_with("<log4j:Event") => line.to_string(),
+            None if line.trim().is_empty() => return None,
+            None => return Some(parse_pattern_line(line, self.stream)),
+        };
+
+        if event.contains("</log4j:Event>") {
+            parse_log4j_event(&event, self.stream)
+        } else {
+            self.pending = Some(event);
+            None
+        }
+    }
+
+    /// An event cut short when the stream closed, kept as plain text
+    pub fn finish(&mut self) -> Option<LogRecord> {
+        self.pending
+            .take()
+            .map(|pending| LogRecord::plain(self.stream, &pending))
+    }
+}
+
+/// Most recent records of an instance, oldest first
+#[derive(Debug)]
+pub struct LogBuffer {
+    records: VecDeque<LogRecord>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: VecDeque::with_capacity(capacity.min(1024)),
+            capacity,
+        }
+    }
+
+    pub fn push(&mut self, record: LogRecord) {
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records.iter().cloned().collect()
+    }
+}
+
+/// Output of one game session: parsed, buffered for the UI and written to disk
+pub struct GameLogSession {
+    instance_id: String,
+    stdout: Log4jParser,
+    stderr: Log4jParser,
+    file: Option<BufWriter<File>>,
+    path: PathBuf,
+    output_tail: VecDeque<String>,
+    error_tail: VecDeque<String>,
+}
+
+impl GameLogSession {
+    /// Starts a session, replacing the buffer of the previous one. The log file is
+    /// `logs/session-<date>.log` inside `instance_dir`.
+    pub fn start(instance_id: &str, instance_dir: &Path) -> Self {
+        if let Ok(mut buffers) = LOG_BUFFERS.lock() {
+            buffers.insert(instance_id.to_string(), LogBuffer::new(LOG_BUFFER_CAPACITY));
+        }
+
+        let path = instance_dir.join("logs").join(format!(
+            "session-{}.log",
+            chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")
+        ));
+        let file = fs::create_dir_all(path.parent().unwrap_or(instance_dir))
+            .and_then(|_| File::create(&path))
+            .map(BufWriter::new)
+            .map_err(|e| log::warn!("Failed to create session log {}: {}", path.display(), e))
+            .ok();
+
+        Self {
+            instance_id: instance_id.to_string(),
+            stdout: Log4jParser::new(LogStream::Stdout),
+            stderr: Log4jParser::new(LogStream::Stderr),
+            file,
+            path,
+            output_tail: VecDeque::new(),
+            error_tail: VecDeque::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Handles one line of output, returning the record it completed, if any
+    pub fn push_line(&mut self, stream: LogStream, line: &str) -> Option<LogRecord> {
+        let record = match stream {
+            LogStream::Stdout => self.stdout.push_line(line),
+            LogStream::Stderr => self.stderr.push_line(line),
+        }?;
+        self.record(&record);
+        Some(record)
+    }
+
+    /// Flushes events left incomplete when the game exited
+    pub fn finish(&mut self) -> Vec<LogRecord> {
+        let records: Vec<LogRecord> = [self.stdout.finish(), self.stderr.finish()]
+            .into_iter()
+            .flatten()
+            .collect();
+        for record in &records {
+            self.record(record);
+        }
+        if let Some(file) = self.file.as_mut() {
+            if let Err(e) = file.flush() {
+                log::warn!("Failed to write session log {}: {}", self.path.display(), e);
+            }
+        }
+        records
+    }
+
+    /// Last lines of output, for the exit event
+    pub fn output_tail(&self) -> String {
+        Vec::from(self.output_tail.clone()).join("\n")
+    }
+
+    /// Last errors (stderr and ERROR/FATAL events), for the exit event and crash detection
+    pub fn error_tail(&self) -> String {
+        Vec::from(self.error_tail.clone()).join("\n")
+    }
+
+    fn record(&mut self, record: &LogRecord) {
+        let text = record.render();
+
+        if let Some(file) = self.file.as_mut() {
+            if writeln!(file, "{}", text).is_err() {
+                log::warn!("Failed to write session log {}", self.path.display());
+                self.file = None;
+            }
+        }
+
+        push_bounded(&mut self.output_tail, text.clone());
+        if record.is_error() {
+            push_bounded(&mut self.error_tail, text);
+        }
+
+        if let Ok(mut buffers) = LOG_BUFFERS.lock() {
+            buffers
+                .entry(self.instance_id.clone())
+                .or_insert_with(|| LogBuffer::new(LOG_BUFFER_CAPACITY))
+                .push(record.clone());
+        }
+    }
+}
+
+fn push_bounded(lines: &mut VecDeque<String>, line: String) {
+    if lines.len() == EXIT_TAIL_LINES {
+        lines.pop_front();
+    }
+    lines.push_back(line);
+}
+
+/// Sends every line of `pipe` to `sender` from a background thread. Lines are decoded
+/// lossily: the game may print text that is not valid UTF-8.
+pub fn forward_lines(
+    pipe: Option<impl Read + Send + 'static>,
+    stream: LogStream,
+    sender: Sender<(LogStream, String)>,
+) -> Option<thread::JoinHandle<()>> {
+    let pipe = pipe?;
+    Some(thread::spawn(move || {
+        let mut reader = BufReader::new(pipe);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let text = String::from_utf8_lossy(&line).to_string();
+                    if sender.send((stream, text)).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    }))
+}
+
+/// Buffered output of the instance's current or last session
+#[tauri::command]
+pub fn get_instance_log(instance_id: String) -> Result<Vec<LogRecord>, String> {
+    let buffers = LOG_BUFFERS
+        .lock()
+        .map_err(|_| "Failed to lock log buffers".to_string())?;
+    Ok(buffers
+        .get(&instance_id)
+        .map(LogBuffer::records)
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_xml_events() {
+        let mut parser = Log4jParser::new(LogStream::Stdout);
+        let lines = [
+            r#"<log4j:Event logger="net.minecraft.client.Minecraft" timestamp="1700000000000" level="INFO" thread="Render thread">"#,
+            "  <log4j:Message><![CDATA[Setting user: Steve]]></log4j:Message>",
+            "</log4j:Event>",
+            "",
+            r#"<log4j:Event logger="fml" timestamp="1700000000500" level="ERROR" thread="main">"#,
+            "  <log4j:Message><![CDATA[Bad <tag> ]]]]><![CDATA[> here]]></log4j:Message>",
+            "  <log4j:Throwable><![CDATA[java.lang.IllegalStateException: boom",
+            "\tat Foo.bar(Foo.java:1)",
+            "]]></log4j:Throwable>",
+            "</log4j:Event>",
+        ];
+        let records: Vec<LogRecord> = lines
+            .iter()
+            .filter_map(|line| parser.push_line(line))
+            .collect();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].timestamp, 1700000000000);
+        assert_eq!(records[0].level, "INFO");
+        assert_eq!(records[0].thread.as_deref(), Some("Render thread"));
+        assert_eq!(
+            records[0].logger.as_deref(),
+            Some("net.minecraft.client.Minecraft")
+        );
+        assert_eq!(records[0].message, "Setting user: Steve");
+        assert_eq!(records[0].throwable, None);
+
+        assert_eq!(records[1].message, "Bad <tag> ]]> here");
+        assert_eq!(
+            records[1].throwable.as_deref(),
+            Some("java.lang.IllegalStateException: boom\n\tat Foo.bar(Foo.java:1)\n")
+        );
+        assert!(records[1].is_error());
+        assert!(parser.finish().is_none());
+    }
+
+    #[test]
+    fn test_parse_plain_lines() {
+        let vanilla = parse_pattern_line(
+            "[12:34:56] [Render thread/WARN]: Missing sound",
+            LogStream::Stdout,
+        );
+        assert_eq!(vanilla.level, "WARN");
+        assert_eq!(vanilla.thread.as_deref(), Some("Render thread"));
+        assert_eq!(vanilla.logger, None);
+        assert_eq!(vanilla.message, "Missing sound");
+
+        let forge = parse_pattern_line(
+            "[12:34:56] [main/INFO] [cpw.mods.modlauncher.Launcher/MODLAUNCHER]: Launching",
+            LogStream::Stdout,
+        );
+        assert_eq!(
+            forge.logger.as_deref(),
+            Some("cpw.mods.modlauncher.Launcher/MODLAUNCHER")
+        );
+
+        let jvm = parse_pattern_line(
+            "Error: Could not create the Java Virtual Machine.",
+            LogStream::Stderr,
+        );
+        assert_eq!(jvm.level, "ERROR");
+        assert_eq!(jvm.thread, None);
+        assert_eq!(
+            jvm.render(),
+            "Error: Could not create the Java Virtual Machine."
+        );
+    }
+
+    #[test]
+    fn test_buffer_is_bounded() {
+        let mut buffer = LogBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(LogRecord::plain(LogStream::Stdout, &i.to_string()));
+        }
+        let messages: Vec<String> = buffer.records().into_iter().map(|r| r.message).collect();
+        assert_eq!(messages, vec!["2", "3", "4"]);
+    }
+
+    #[test]
+    fn test_session_writes_file_and_buffer() {
+        let dir = tempdir().unwrap();
+        let mut session = GameLogSession::start("session-test", dir.path());
+
+        assert!(session
+            .push_line(LogStream::Stdout, "[10:00:00] [main/INFO]: Loading\n")
+            .is_some());
+        assert!(session
+            .push_line(LogStream::Stdout, "<log4j:Event level=\"INFO\">\n")
+            .is_none());
+        assert!(session
+            .push_line(LogStream::Stderr, "Exception in thread \"main\"\n")
+            .is_some());
+        assert_eq!(session.finish().len(), 1);
+
+        let contents = fs::read_to_string(session.path()).unwrap();
+        assert!(contents.contains("[main/INFO]: Loading"));
+        assert!(contents.contains("Exception in thread \"main\""));
+        assert_eq!(session.error_tail(), "Exception in thread \"main\"");
+
+        let buffered = get_instance_log("session-test".into()).unwrap();
+        assert_eq!(buffered.len(), 3);
+        assert_eq!(buffered[1].stream, LogStream::Stderr);
+    }
+}
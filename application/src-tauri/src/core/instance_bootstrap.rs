@@ -1,12 +1,23 @@
 // src-tauri/src/instance_bootstrap.rs
 use crate::config::get_config_manager;
 use crate::core::bootstrap::{
-    download::{download_file, download_forge_libraries, download_libraries},
+    download::{
+        download_file_via_store, download_forge_libraries, download_libraries,
+        download_libraries_enhanced,
+    },
+    fabric::{fabric_version_id, fetch_fabric_profile, DEFAULT_FABRIC_META_URL},
     filesystem::{create_launcher_profiles, create_minecraft_directories, extract_natives},
+    forge::{forge_version_id, run_processors, ForgeInstallContext, ForgeInstaller},
+    integrity::{declared_files, verify_and_repair},
     manifest::{
-        build_forge_installer_url, get_java_version_requirement, get_version_details,
-        get_version_manifest,
+        forge_installer_urls, get_asset_index_info, get_java_runtime_component,
+        get_java_version_requirement, get_version_details, get_version_manifest,
+        install_version_json, merge_with_vanilla, version_json_url,
     },
+    meta_cache::{MetadataCache, DEFAULT_METADATA_TTL},
+    neoforge::{build_neoforge_installer_url, find_neoforge_version_json, neoforge_version_ids},
+    quilt::{fetch_quilt_profile, quilt_version_id, DEFAULT_QUILT_META_URL},
+    store::SharedStore,
     tasks::{
         emit_bootstrap_complete, emit_bootstrap_error, emit_bootstrap_start, emit_status,
         emit_status_with_stage, Stage,
@@ -16,12 +27,12 @@ use crate::core::bootstrap::{
 use crate::core::bootstrap_error::{BootstrapError, BootstrapStep, ErrorCategory};
 use crate::core::instance_manager::get_instance_by_id;
 use crate::core::java_manager::JavaManager;
-use crate::core::minecraft_instance::MinecraftInstance;
+use crate::core::minecraft_instance::{MinecraftInstance, ModLoader};
 use crate::core::tasks_manager::{
     add_task, remove_task, update_task, update_task_with_bootstrap_error, TaskStatus,
 };
 use crate::GLOBAL_APP_HANDLE;
-use serde_json::{json, Value};
+use serde_json::Value;
 use std::fs;
 use std::io::{self, Result as IoResult};
 use std::path::{Path, PathBuf};
@@ -32,18 +43,35 @@ use tauri_plugin_http::reqwest;
 
 pub struct InstanceBootstrap {
     client: reqwest::blocking::Client,
-    // Cache para metadatos de versiones
-    version_manifest_cache: Option<(Value, u64)>, // (datos, timestamp)
+    // Caché en disco para metadatos de versiones e instaladores
+    metadata: MetadataCache,
 }
 
 impl InstanceBootstrap {
     pub fn new() -> Self {
+        let metadata = MetadataCache::open().unwrap_or_else(|e| {
+            log::warn!("Metadata cache unavailable, using a temporary one: {}", e);
+            MetadataCache::at(
+                std::env::temp_dir().join("modpackstore-meta"),
+                DEFAULT_METADATA_TTL,
+            )
+            .expect("temporary metadata cache")
+        });
+
         Self {
             client: reqwest::blocking::Client::new(),
-            version_manifest_cache: None,
+            metadata,
         }
     }
 
+    /// Bootstrap that only reads metadata from the on-disk cache, for launching
+    /// without network
+    pub fn offline() -> Self {
+        let mut bootstrap = Self::new();
+        bootstrap.metadata = bootstrap.metadata.offline();
+        bootstrap
+    }
+
     // --- Error handling helpers ---
 
     fn handle_network_error(
@@ -76,27 +104,145 @@ impl InstanceBootstrap {
             })?;
 
         // Use the modular revalidate_assets function
-        revalidate_assets(&self.client, instance, &version_details)
+        revalidate_assets(&self.client, &self.metadata, instance, &version_details)?;
+
+        self.verify_game_files(instance, &version_details);
+        Ok(())
+    }
+
+    /// Checks, without network, that everything needed to launch is on disk. Returns the
+    /// names of the missing files; errors when the version metadata was never cached.
+    pub fn missing_offline_files(
+        &mut self,
+        instance: &MinecraftInstance,
+    ) -> Result<Vec<String>, String> {
+        let version_details = self.get_version_details(&instance.minecraftVersion)?;
+        let (assets_index_id, _) = get_asset_index_info(&version_details)?;
+
+        let minecraft_dir =
+            Path::new(instance.instanceDirectory.as_deref().unwrap_or("")).join("minecraft");
+        let client_jar = minecraft_dir
+            .join("versions")
+            .join(&instance.minecraftVersion)
+            .join(format!("{}.jar", instance.minecraftVersion));
+
+        let mut missing: Vec<String> = declared_files(
+            &version_details,
+            &client_jar,
+            &minecraft_dir.join("libraries"),
+        )
+        .into_iter()
+        .filter(|file| !file.path.is_file())
+        .map(|file| file.name)
+        .collect();
+
+        let assets_index = minecraft_dir
+            .join("assets")
+            .join("indexes")
+            .join(format!("{}.json", assets_index_id));
+        if !assets_index.is_file() {
+            missing.push(format!("assets/indexes/{}.json", assets_index_id));
+        }
+
+        Ok(missing)
+    }
+
+    /// Checks the client jar, libraries and natives against the hashes declared in the
+    /// version JSON, re-downloading the corrupted ones. Only surfaces a task when
+    /// something had to be repaired.
+    fn verify_game_files(&self, instance: &MinecraftInstance, version_details: &Value) {
+        let minecraft_dir =
+            Path::new(instance.instanceDirectory.as_deref().unwrap_or("")).join("minecraft");
+        let client_jar = minecraft_dir
+            .join("versions")
+            .join(&instance.minecraftVersion)
+            .join(format!("{}.jar", instance.minecraftVersion));
+        let files = declared_files(
+            version_details,
+            &client_jar,
+            &minecraft_dir.join("libraries"),
+        );
+
+        let summary = verify_and_repair(&self.client, &files, instance);
+        log::info!(
+            "[Instance: {}] Verified {} game files ({} repaired, {} failed)",
+            instance.instanceId,
+            summary.checked,
+            summary.repaired.len(),
+            summary.failed.len()
+        );
+        if summary.is_clean() {
+            return;
+        }
+
+        let task_id = add_task(
+            &format!("Reparando archivos de {}", instance.instanceName),
+            Some(serde_json::json!({
+                "instanceName": instance.instanceName.clone(),
+                "instanceId": instance.instanceId.clone()
+            })),
+        );
+        update_task(
+            &task_id,
+            TaskStatus::Running,
+            50.0,
+            "Verificando archivos del juego",
+            None,
+        );
+
+        let data = Some(serde_json::json!({
+            "instanceName": instance.instanceName.clone(),
+            "instanceId": instance.instanceId.clone(),
+            "summary": summary
+        }));
+        if summary.failed.is_empty() {
+            update_task(
+                &task_id,
+                TaskStatus::Completed,
+                100.0,
+                &format!("{} archivos reparados", summary.repaired.len()),
+                data,
+            );
+        } else {
+            update_task(
+                &task_id,
+                TaskStatus::Failed,
+                100.0,
+                &format!("No se pudieron reparar {} archivos", summary.failed.len()),
+                data,
+            );
+        }
     }
 
     // Método para obtener detalles de la versión
     fn get_version_details(&mut self, version: &str) -> Result<Value, String> {
-        get_version_details(&self.client, &mut self.version_manifest_cache, version)
+        get_version_details(&self.client, &self.metadata, version)
     }
 
-    // Método para descargar archivos
+    // Descarga a través de la caché de metadatos
     fn download_file(&self, url: &str, destination: &Path) -> Result<(), String> {
-        download_file(&self.client, url, destination)
+        self.metadata.fetch_to_file(&self.client, url, destination)
     }
 
     // Implementaciones auxiliares
-    fn get_version_manifest(&mut self) -> Result<Value, reqwest::Error> {
-        get_version_manifest(&self.client, &mut self.version_manifest_cache)
+    fn get_version_manifest(&mut self) -> Result<Value, String> {
+        get_version_manifest(&self.client, &self.metadata)
     }
 
-    // Aquí irían más métodos para bootstrapping de instancias Vanilla y Forge
-    // como bootstrap_vanilla_instance y bootstrap_forge_instance,
-    // pero son bastante extensos para este contexto
+    /// Bootstraps an instance with the mod loader it is configured for
+    pub fn bootstrap_instance(
+        &mut self,
+        instance: &MinecraftInstance,
+        task_id: Option<String>,
+    ) -> Result<(), String> {
+        match instance.mod_loader() {
+            ModLoader::Vanilla => self.bootstrap_vanilla_instance(instance, task_id),
+            ModLoader::Forge => self.bootstrap_forge_instance(instance, task_id),
+            ModLoader::NeoForge => self.bootstrap_neoforge_instance(instance, task_id),
+            ModLoader::Fabric => self.bootstrap_fabric_instance(instance, task_id),
+            ModLoader::Quilt => self.bootstrap_quilt_instance(instance, task_id),
+        }
+    }
 
     pub fn bootstrap_vanilla_instance(
         &mut self,
@@ -219,23 +365,7 @@ impl InstanceBootstrap {
                 .get_version_manifest()
                 .map_err(|e| format!("Error fetching version manifest: {}", e))?;
 
-            let versions = version_manifest["versions"]
-                .as_array()
-                .ok_or_else(|| "Invalid version manifest format".to_string())?;
-
-            let version_info = versions
-                .iter()
-                .find(|v| v["id"].as_str() == Some(&instance.minecraftVersion))
-                .ok_or_else(|| {
-                    format!(
-                        "Version {} not found in manifest",
-                        instance.minecraftVersion
-                    )
-                })?;
-
-            let version_url = version_info["url"]
-                .as_str()
-                .ok_or_else(|| "Invalid version info format".to_string())?;
+            let version_url = version_json_url(&version_manifest, &instance.minecraftVersion)?;
 
             // Update task status - 25%
             if let Some(task_id) = &task_id {
@@ -265,7 +395,7 @@ impl InstanceBootstrap {
                 ),
             );
 
-            self.download_file(version_url, &version_json_path)
+            self.download_file(&version_url, &version_json_path)
                 .map_err(|e| {
                     self.handle_network_error(
                         BootstrapStep::DownloadingVersionJson,
@@ -329,13 +459,25 @@ impl InstanceBootstrap {
                 ),
             );
 
-            self.download_file(client_url, &client_jar_path)
-                .map_err(|e| {
-                    self.handle_network_error(
-                        BootstrapStep::DownloadingClientJar,
-                        format!("Error downloading client jar: {}", e),
-                    )
-                })?;
+            // The client jar is identical for every instance of a version, share it
+            let store = SharedStore::open()
+                .map_err(|e| log::warn!("Shared store unavailable: {}", e))
+                .ok();
+
+            download_file_via_store(
+                &self.client,
+                store.as_ref(),
+                client_url,
+                version_details["downloads"]["client"]["sha1"].as_str(),
+                &client_jar_path,
+                instance,
+            )
+            .map_err(|e| {
+                self.handle_network_error(
+                    BootstrapStep::DownloadingClientJar,
+                    format!("Error downloading client jar: {}", e),
+                )
+            })?;
         } else {
             // Update task status if file already exists
             if let Some(task_id) = &task_id {
@@ -379,10 +521,15 @@ impl InstanceBootstrap {
 
         println!("Java Major Version: {}", java_major_version);
 
+        let java_component = get_java_runtime_component(&version_details);
         let java_manager =
             JavaManager::new().map_err(|e| format!("Failed to create JavaManager: {}", e))?; // Convert error to String
 
-        let is_version_installed = java_manager.is_version_installed(&java_major_version);
+        // The runtime is only made available here; the launcher picks it for the instance
+        // at launch unless the user pinned a Java installation
+        let is_version_installed = java_manager
+            .installed_runtime(&java_major_version, java_component.as_deref())
+            .is_some();
 
         if !is_version_installed {
             // Update task status - 40%
@@ -405,18 +552,17 @@ impl InstanceBootstrap {
             }
 
             // Create Tokio runtime for async task execution
-            let java_path = tokio::runtime::Runtime::new()
+            tokio::runtime::Runtime::new()
                 .expect("Failed to create Tokio runtime")
-                .block_on(java_manager.get_java_path(&java_major_version))
+                .block_on(
+                    java_manager.get_java_runtime(&java_major_version, java_component.as_deref()),
+                )
                 .map_err(|e| {
                     format!(
                         "Error obtaining Java path for version {}: {}",
                         java_major_version, e
                     )
                 })?;
-
-            let mut instance_to_modify = instance.clone();
-            instance_to_modify.set_java_path(java_path);
         } else {
             // Update task status if Java is already installed
             if let Some(task_id) = &task_id {
@@ -523,186 +669,109 @@ impl InstanceBootstrap {
         Ok(())
     }
 
-    fn run_forge_installer(
+    /// Installs Forge from its installer jar: writes the version JSON, fetches the libraries
+    /// and runs the client processors natively.
+    fn install_forge(
         &self,
         installer_path: &Path,
         minecraft_dir: &Path,
-        minecraft_version: &str,
-        forge_version: &str,
         instance: &MinecraftInstance,
+        task_id: Option<&str>,
     ) -> Result<(), BootstrapError> {
-        log::info!(
-            "[Instance: {}] Starting Forge installer - Minecraft: {}, Forge: {}",
-            instance.instanceId,
-            minecraft_version,
-            forge_version
-        );
+        let libraries_dir = minecraft_dir.join("libraries");
+        let versions_dir = minecraft_dir.join("versions");
 
-        // Determinar la ruta de Java
-        let java_path = self.find_java_path()?;
-        log::debug!(
-            "[Instance: {}] Using Java path: {}",
+        let installer =
+            ForgeInstaller::open(installer_path).map_err(BootstrapError::forge_error)?;
+        log::info!(
+            "[Instance: {}] Installing Forge version {}",
             instance.instanceId,
-            java_path
+            installer.version_id()
         );
 
-        // Crear archivo temporal para parámetros de instalación
-        let install_profile = minecraft_dir.join("forge-install-profile.json");
-        let install_profile_content = json!({
-            "profile": format!("forge-{}-{}", minecraft_version, forge_version),
-            "version": format!("{}-forge-{}", minecraft_version, forge_version),
-            "installDir": minecraft_dir.to_string_lossy(),
-            "minecraft": minecraft_version,
-            "forge": forge_version
-        });
+        // Installed under the id the launcher looks up, whatever the installer calls it
+        let forge_version = instance.forgeVersion.as_deref().unwrap_or_default();
+        let version_id = forge_version_id(&instance.minecraftVersion, forge_version);
+        let mut version_json = installer.version_json.clone();
+        version_json["id"] = Value::String(version_id.clone());
 
+        let embedded = installer
+            .extract_embedded_libraries(&libraries_dir)
+            .map_err(BootstrapError::forge_error)?;
         log::debug!(
-            "[Instance: {}] Creating Forge install profile at: {}",
+            "[Instance: {}] Extracted {} libraries bundled in the Forge installer",
             instance.instanceId,
-            install_profile.display()
+            embedded
         );
 
-        fs::write(&install_profile, install_profile_content.to_string()).map_err(|e| {
-            log::error!(
-                "[Instance: {}] Failed to create install profile: {}",
-                instance.instanceId,
-                e
-            );
-            BootstrapError::filesystem_error(
-                BootstrapStep::RunningForgeInstaller,
-                format!("Error al crear archivo de perfil de instalación: {}", e),
-            )
-        })?;
-
-        // Lista de opciones de instalación para probar secuencialmente
-        let install_options = ["--installClient", "--installDir", "--installServer"];
-
-        let mut success = false;
-        let mut last_error = String::new();
-        let mut attempted_options = Vec::new();
-
-        log::info!(
-            "[Instance: {}] Attempting Forge installation with {} options",
-            instance.instanceId,
-            install_options.len()
-        );
-
-        // Intentar cada opción de instalación hasta que una tenga éxito
-        for &option in &install_options {
-            attempted_options.push(option);
-
-            // Preparar comando para ejecutar el instalador con la opción actual
-            let mut install_cmd = Command::new(&java_path);
-            install_cmd
-                .arg("-jar")
-                .arg(installer_path)
-                .arg(option)
-                .current_dir(minecraft_dir);
-
-            // En Windows, usar CREATE_NO_WINDOW para evitar que aparezca una ventana de CMD
-            #[cfg(target_os = "windows")]
-            {
-                use std::os::windows::process::CommandExt;
-                install_cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
-            }
-
-            // Ejecutar instalador con la opción actual
-            log::info!(
-                "[Instance: {}] Executing Forge installer with option '{}': {:?}",
-                instance.instanceId,
-                option,
-                install_cmd
-            );
+        install_version_json(&versions_dir, &version_id, &version_json)
+            .map_err(|e| self.handle_filesystem_error(BootstrapStep::RunningForgeInstaller, e))?;
+        let merged = merge_with_vanilla(&versions_dir, &version_json)
+            .map_err(|e| self.handle_filesystem_error(BootstrapStep::RunningForgeInstaller, e))?;
+        self.download_merged_libraries(instance, &merged, &libraries_dir)?;
+        if installer.is_legacy() {
+            return Ok(());
+        }
+        self.download_merged_libraries(instance, &installer.install_profile, &libraries_dir)?;
 
-            match install_cmd.output() {
-                Ok(output) => {
-                    if output.status.success() {
-                        success = true;
-                        log::info!(
-                            "[Instance: {}] Forge installation completed successfully using option '{}'",
-                            instance.instanceId,
-                            option
-                        );
-                        break;
-                    } else {
-                        let error_msg = String::from_utf8_lossy(&output.stderr);
-                        let stdout_msg = String::from_utf8_lossy(&output.stdout);
-
-                        log::warn!(
-                            "[Instance: {}] Forge installation failed with option '{}' - Exit code: {:?}",
-                            instance.instanceId,
-                            option,
-                            output.status.code()
-                        );
-                        log::debug!(
-                            "[Instance: {}] Forge installer stderr: {}",
-                            instance.instanceId,
-                            error_msg
-                        );
-                        log::debug!(
-                            "[Instance: {}] Forge installer stdout: {}",
-                            instance.instanceId,
-                            stdout_msg
-                        );
-
-                        last_error = format!(
-                            "Error en instalación de Forge con {}: {}",
-                            option, error_msg
-                        );
-                    }
-                }
-                Err(e) => {
-                    log::error!(
-                        "[Instance: {}] Failed to execute Forge installer with option '{}': {}",
-                        instance.instanceId,
-                        option,
-                        e
-                    );
-                    last_error = format!(
-                        "Error al ejecutar instalador de Forge con {}: {}",
-                        option, e
-                    );
-                }
-            }
+        let processors = installer
+            .client_processors()
+            .map_err(BootstrapError::forge_error)?;
+        if processors.is_empty() {
+            return Ok(());
         }
 
-        // Limpiar archivo temporal de instalación
-        if install_profile.exists() {
-            if let Err(e) = fs::remove_file(&install_profile) {
+        let java_path = self.find_java_path()?;
+        let minecraft_jar = versions_dir
+            .join(&instance.minecraftVersion)
+            .join(format!("{}.jar", instance.minecraftVersion));
+        let context = ForgeInstallContext {
+            minecraft_dir,
+            libraries_dir: &libraries_dir,
+            minecraft_version: &instance.minecraftVersion,
+            minecraft_jar: &minecraft_jar,
+        };
+        let work_dir = minecraft_dir.join(".forge-installer");
+
+        let result = installer
+            .resolve_data(&context, &work_dir)
+            .and_then(|data| {
+                run_processors(
+                    &java_path,
+                    &processors,
+                    &data,
+                    &libraries_dir,
+                    |current, total, jar| {
+                        if let Some(task_id) = task_id {
+                            update_task(
+                                task_id,
+                                TaskStatus::Running,
+                                85.0 + 10.0 * (current - 1) as f32 / total as f32,
+                                &format!("Ejecutando procesador de Forge {}/{}", current, total),
+                                Some(serde_json::json!({
+                                    "instanceName": instance.instanceName.clone(),
+                                    "instanceId": instance.instanceId.clone(),
+                                    "processor": jar
+                                })),
+                            );
+                        }
+                        let stage = Stage::RunningForgeProcessors { current, total };
+                        emit_status_with_stage(instance, "instance-installing-forge", &stage);
+                    },
+                )
+            });
+
+        if work_dir.exists() {
+            if let Err(e) = fs::remove_dir_all(&work_dir) {
                 log::warn!(
-                    "[Instance: {}] Failed to remove install profile: {}",
+                    "[Instance: {}] Failed to remove Forge installer work directory: {}",
                     instance.instanceId,
                     e
                 );
-            } else {
-                log::debug!(
-                    "[Instance: {}] Cleaned up install profile",
-                    instance.instanceId
-                );
             }
         }
 
-        // Verificar resultado final
-        if success {
-            log::info!(
-                "[Instance: {}] Forge installation completed successfully",
-                instance.instanceId
-            );
-            Ok(())
-        } else {
-            log::error!(
-                "[Instance: {}] All Forge installation methods failed. Attempted options: {:?}. Last error: {}",
-                instance.instanceId,
-                attempted_options,
-                last_error
-            );
-            Err(
-                BootstrapError::forge_error(last_error).with_technical_details(format!(
-                    "Tried installation options: {:?}. All failed.",
-                    attempted_options
-                )),
-            )
-        }
+        result.map_err(BootstrapError::forge_error)
     }
 
     fn find_java_path(&self) -> Result<String, BootstrapError> {
@@ -833,37 +902,10 @@ impl InstanceBootstrap {
         // Get minecraft directory (reuse from vanilla bootstrap)
         let instance_dir = Path::new(instance.instanceDirectory.as_deref().unwrap_or(""));
         let minecraft_dir = instance_dir.join("minecraft");
-        let versions_dir = minecraft_dir.join("versions");
 
-        // Setup Forge-specific files
         let forge_version = instance.forgeVersion.as_ref().unwrap();
-        let forge_version_name = format!("{}-forge-{}", instance.minecraftVersion, forge_version);
-        let forge_version_dir = versions_dir.join(&forge_version_name);
-
-        if !forge_version_dir.exists() {
-            // Update task status - 75%
-            if let Some(task_id) = &task_id {
-                update_task(
-                    task_id,
-                    TaskStatus::Running,
-                    75.0,
-                    &format!("Creando directorio para Forge {}", forge_version),
-                    Some(serde_json::json!({
-                        "instanceName": instance.instanceName.clone(),
-                        "instanceId": instance.instanceId.clone(),
-                        "forgeVersion": forge_version
-                    })),
-                );
-            }
-
-            fs::create_dir_all(&forge_version_dir)
-                .map_err(|e| format!("Error creating Forge version directory: {}", e))?;
-        }
 
         // Download Forge installer
-        let forge_installer_url =
-            build_forge_installer_url(&instance.minecraftVersion, forge_version);
-
         let forge_installer_path = minecraft_dir.join("forge-installer.jar");
 
         // Update task status - 80%
@@ -888,8 +930,23 @@ impl InstanceBootstrap {
             "Descargando instalador de Forge",
         );
 
-        self.download_file(&forge_installer_url, &forge_installer_path)
-            .map_err(|e| format!("Error downloading Forge installer: {}", e))?;
+        let mut download_error = String::new();
+        let downloaded = forge_installer_urls(&instance.minecraftVersion, forge_version)
+            .iter()
+            .any(|url| match self.download_file(url, &forge_installer_path) {
+                Ok(()) => true,
+                Err(e) => {
+                    log::warn!("Forge installer not available at {}: {}", url, e);
+                    download_error = e;
+                    false
+                }
+            });
+        if !downloaded {
+            return Err(format!(
+                "Error downloading Forge installer: {}",
+                download_error
+            ));
+        }
 
         // Update task status - 85%
         if let Some(task_id) = &task_id {
@@ -910,14 +967,21 @@ impl InstanceBootstrap {
         let stage = Stage::InstallingForge;
         emit_status_with_stage(instance, "instance-installing-forge", &stage);
 
-        // Ejecutar el instalador de Forge
-        match self.run_forge_installer(
+        let result = self.install_forge(
             &forge_installer_path,
             &minecraft_dir,
-            &instance.minecraftVersion,
-            forge_version,
             instance,
-        ) {
+            task_id.as_deref(),
+        );
+        if let Err(e) = fs::remove_file(&forge_installer_path) {
+            log::warn!(
+                "[Instance: {}] Failed to remove Forge installer: {}",
+                instance.instanceId,
+                e
+            );
+        }
+
+        match result {
             Ok(_) => {
                 // Update task status - 95%
                 if let Some(task_id) = &task_id {
@@ -951,4 +1015,415 @@ impl InstanceBootstrap {
 
         Ok(())
     }
+
+    pub fn bootstrap_fabric_instance(
+        &mut self,
+        instance: &MinecraftInstance,
+        task_id: Option<String>,
+    ) -> Result<(), String> {
+        self.bootstrap_profile_loader_instance(instance, task_id, ModLoader::Fabric)
+    }
+
+    pub fn bootstrap_quilt_instance(
+        &mut self,
+        instance: &MinecraftInstance,
+        task_id: Option<String>,
+    ) -> Result<(), String> {
+        self.bootstrap_profile_loader_instance(instance, task_id, ModLoader::Quilt)
+    }
+
+    /// Shared bootstrap of loaders installed from a meta server profile (Fabric, Quilt)
+    fn bootstrap_profile_loader_instance(
+        &mut self,
+        instance: &MinecraftInstance,
+        task_id: Option<String>,
+        loader: ModLoader,
+    ) -> Result<(), String> {
+        let loader_name = loader.display_name();
+        let loader_version = instance
+            .loader_version()
+            .filter(|version| !version.is_empty())
+            .ok_or_else(|| format!("No se especificó versión de {}", loader_name))?
+            .to_string();
+
+        emit_bootstrap_start(instance, loader_name);
+
+        if let Some(task_id) = &task_id {
+            update_task(
+                task_id,
+                TaskStatus::Running,
+                5.0,
+                "Iniciando configuración base de Vanilla",
+                Some(serde_json::json!({
+                    "instanceName": instance.instanceName.clone(),
+                    "instanceId": instance.instanceId.clone()
+                })),
+            );
+        }
+
+        // These loaders only add libraries on top of the vanilla client
+        self.bootstrap_vanilla_instance(instance, task_id.clone())
+            .map_err(|e| format!("Error configurando base Vanilla: {}", e))?;
+
+        let message = format!("Instalando {} {}", loader_name, loader_version);
+        if let Some(task_id) = &task_id {
+            update_task(
+                task_id,
+                TaskStatus::Running,
+                92.0,
+                &message,
+                Some(serde_json::json!({
+                    "instanceName": instance.instanceName.clone(),
+                    "instanceId": instance.instanceId.clone(),
+                    "loader": loader,
+                    "loaderVersion": loader_version
+                })),
+            );
+        }
+
+        emit_status(instance, "instance-installing-loader", &message);
+
+        if let Err(bootstrap_error) = self.install_loader_profile(instance, loader, &loader_version)
+        {
+            emit_bootstrap_error(instance, &bootstrap_error);
+            if let Some(task_id) = &task_id {
+                update_task_with_bootstrap_error(task_id, &bootstrap_error);
+            }
+            return Err(bootstrap_error.into());
+        }
+
+        if let Some(task_id) = &task_id {
+            update_task(
+                task_id,
+                TaskStatus::Running,
+                95.0,
+                &format!("{} instalado correctamente", loader_name),
+                Some(serde_json::json!({
+                    "instanceName": instance.instanceName.clone(),
+                    "instanceId": instance.instanceId.clone(),
+                    "loaderVersion": loader_version
+                })),
+            );
+        }
+
+        emit_bootstrap_complete(instance, loader_name);
+
+        Ok(())
+    }
+
+    /// Installs the loader profile next to the vanilla version and downloads its libraries
+    fn install_loader_profile(
+        &self,
+        instance: &MinecraftInstance,
+        loader: ModLoader,
+        loader_version: &str,
+    ) -> Result<(), BootstrapError> {
+        let minecraft_dir =
+            Path::new(instance.instanceDirectory.as_deref().unwrap_or("")).join("minecraft");
+        let versions_dir = minecraft_dir.join("versions");
+        let libraries_dir = minecraft_dir.join("libraries");
+        let minecraft_version = &instance.minecraftVersion;
+
+        let config = get_config_manager().lock().ok();
+        let config = config.as_ref().and_then(|config| config.as_ref().ok());
+
+        let (version_id, profile) = match loader {
+            ModLoader::Fabric => {
+                let meta_url = config
+                    .map(|c| c.get_fabric_meta_url())
+                    .unwrap_or_else(|| DEFAULT_FABRIC_META_URL.to_string());
+                (
+                    fabric_version_id(minecraft_version, loader_version),
+                    fetch_fabric_profile(
+                        &self.client,
+                        &meta_url,
+                        minecraft_version,
+                        loader_version,
+                    ),
+                )
+            }
+            ModLoader::Quilt => {
+                let meta_url = config
+                    .map(|c| c.get_quilt_meta_url())
+                    .unwrap_or_else(|| DEFAULT_QUILT_META_URL.to_string());
+                (
+                    quilt_version_id(minecraft_version, loader_version),
+                    fetch_quilt_profile(&self.client, &meta_url, minecraft_version, loader_version),
+                )
+            }
+            other => {
+                return Err(BootstrapError::new(
+                    BootstrapStep::InstallingModLoader,
+                    ErrorCategory::Configuration,
+                    format!("{} no se instala desde un perfil", other.display_name()),
+                ))
+            }
+        };
+        let profile = profile
+            .map_err(|e| self.handle_network_error(BootstrapStep::InstallingModLoader, e))?;
+
+        install_version_json(&versions_dir, &version_id, &profile)
+            .map_err(|e| self.handle_filesystem_error(BootstrapStep::InstallingModLoader, e))?;
+
+        // Same merge the launcher does, so every library in the final classpath gets downloaded
+        let merged = merge_with_vanilla(&versions_dir, &profile)
+            .map_err(|e| self.handle_filesystem_error(BootstrapStep::InstallingModLoader, e))?;
+
+        self.download_merged_libraries(instance, &merged, &libraries_dir)?;
+
+        log::info!(
+            "[Instance: {}] {} {} installed for Minecraft {}",
+            instance.instanceId,
+            loader.display_name(),
+            loader_version,
+            minecraft_version
+        );
+        Ok(())
+    }
+
+    /// Downloads every library of a merged loader manifest that is not on disk yet
+    fn download_merged_libraries(
+        &self,
+        instance: &MinecraftInstance,
+        merged: &Value,
+        libraries_dir: &Path,
+    ) -> Result<(), BootstrapError> {
+        let runtime = tokio::runtime::Runtime::new().map_err(|e| {
+            self.handle_filesystem_error(
+                BootstrapStep::DownloadingLibraries,
+                format!("Failed to create Tokio runtime: {}", e),
+            )
+        })?;
+        runtime
+            .block_on(download_libraries_enhanced(instance, merged, libraries_dir))
+            .map_err(|e| self.handle_network_error(BootstrapStep::DownloadingLibraries, e))
+    }
+
+    pub fn bootstrap_neoforge_instance(
+        &mut self,
+        instance: &MinecraftInstance,
+        task_id: Option<String>,
+    ) -> Result<(), String> {
+        let neoforge_version = instance
+            .loader_version()
+            .filter(|version| !version.is_empty())
+            .ok_or_else(|| "No se especificó versión de NeoForge".to_string())?
+            .to_string();
+
+        emit_bootstrap_start(instance, "NeoForge");
+
+        if let Some(task_id) = &task_id {
+            update_task(
+                task_id,
+                TaskStatus::Running,
+                5.0,
+                "Iniciando configuración base de Vanilla",
+                Some(serde_json::json!({
+                    "instanceName": instance.instanceName.clone(),
+                    "instanceId": instance.instanceId.clone()
+                })),
+            );
+        }
+
+        self.bootstrap_vanilla_instance(instance, task_id.clone())
+            .map_err(|e| format!("Error configurando base Vanilla: {}", e))?;
+
+        let installer_url =
+            build_neoforge_installer_url(&instance.minecraftVersion, &neoforge_version)?;
+        let minecraft_dir =
+            Path::new(instance.instanceDirectory.as_deref().unwrap_or("")).join("minecraft");
+        let installer_path = minecraft_dir.join("neoforge-installer.jar");
+
+        if let Some(task_id) = &task_id {
+            update_task(
+                task_id,
+                TaskStatus::Running,
+                80.0,
+                "Descargando instalador de NeoForge",
+                Some(serde_json::json!({
+                    "instanceName": instance.instanceName.clone(),
+                    "instanceId": instance.instanceId.clone(),
+                    "fileName": "neoforge-installer.jar",
+                    "fileType": "neoforge_installer"
+                })),
+            );
+        }
+
+        emit_status(
+            instance,
+            "instance-downloading-neoforge-installer",
+            "Descargando instalador de NeoForge",
+        );
+
+        self.download_file(&installer_url, &installer_path)
+            .map_err(|e| format!("Error downloading NeoForge installer: {}", e))?;
+
+        if let Some(task_id) = &task_id {
+            update_task(
+                task_id,
+                TaskStatus::Running,
+                85.0,
+                "Instalando NeoForge",
+                Some(serde_json::json!({
+                    "instanceName": instance.instanceName.clone(),
+                    "instanceId": instance.instanceId.clone(),
+                    "loader": "neoforge",
+                    "loaderVersion": neoforge_version
+                })),
+            );
+        }
+
+        emit_status(
+            instance,
+            "instance-installing-neoforge",
+            "Instalando NeoForge",
+        );
+
+        let result = self.install_neoforge(instance, &installer_path, &neoforge_version);
+        if let Err(e) = fs::remove_file(&installer_path) {
+            log::warn!(
+                "[Instance: {}] Failed to remove NeoForge installer: {}",
+                instance.instanceId,
+                e
+            );
+        }
+
+        if let Err(bootstrap_error) = result {
+            emit_bootstrap_error(instance, &bootstrap_error);
+            if let Some(task_id) = &task_id {
+                update_task_with_bootstrap_error(task_id, &bootstrap_error);
+            }
+            return Err(bootstrap_error.into());
+        }
+
+        if let Some(task_id) = &task_id {
+            update_task(
+                task_id,
+                TaskStatus::Running,
+                95.0,
+                "NeoForge instalado correctamente",
+                Some(serde_json::json!({
+                    "instanceName": instance.instanceName.clone(),
+                    "instanceId": instance.instanceId.clone(),
+                    "loaderVersion": neoforge_version
+                })),
+            );
+        }
+
+        emit_bootstrap_complete(instance, "NeoForge");
+
+        Ok(())
+    }
+
+    /// Runs the NeoForge installer and makes sure every library of the result is present
+    fn install_neoforge(
+        &self,
+        instance: &MinecraftInstance,
+        installer_path: &Path,
+        neoforge_version: &str,
+    ) -> Result<(), BootstrapError> {
+        let minecraft_dir =
+            Path::new(instance.instanceDirectory.as_deref().unwrap_or("")).join("minecraft");
+        let versions_dir = minecraft_dir.join("versions");
+
+        self.run_neoforge_installer(installer_path, &minecraft_dir, instance)?;
+
+        let version_json =
+            find_neoforge_version_json(&versions_dir, &instance.minecraftVersion, neoforge_version)
+                .ok_or_else(|| {
+                    BootstrapError::new(
+                        BootstrapStep::InstallingModLoader,
+                        ErrorCategory::Forge,
+                        "El instalador de NeoForge no generó el archivo de versión",
+                    )
+                    .with_technical_details(format!(
+                        "Expected one of {:?} in {}",
+                        neoforge_version_ids(&instance.minecraftVersion, neoforge_version),
+                        versions_dir.display()
+                    ))
+                })?;
+
+        let profile: Value = fs::read_to_string(&version_json)
+            .map_err(|e| e.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+            .map_err(|e| {
+                self.handle_filesystem_error(
+                    BootstrapStep::InstallingModLoader,
+                    format!("Error leyendo {}: {}", version_json.display(), e),
+                )
+            })?;
+
+        let merged = merge_with_vanilla(&versions_dir, &profile)
+            .map_err(|e| self.handle_filesystem_error(BootstrapStep::InstallingModLoader, e))?;
+        self.download_merged_libraries(instance, &merged, &minecraft_dir.join("libraries"))
+    }
+
+    /// Runs the NeoForge installer headless against the instance's minecraft directory
+    fn run_neoforge_installer(
+        &self,
+        installer_path: &Path,
+        minecraft_dir: &Path,
+        instance: &MinecraftInstance,
+    ) -> Result<(), BootstrapError> {
+        let java_path = self.find_java_path()?;
+
+        let mut install_cmd = Command::new(&java_path);
+        install_cmd
+            .arg("-Djava.awt.headless=true")
+            .arg("-jar")
+            .arg(installer_path)
+            .arg("--installClient")
+            .arg(minecraft_dir)
+            .current_dir(minecraft_dir);
+
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            install_cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        }
+
+        log::info!(
+            "[Instance: {}] Executing NeoForge installer: {:?}",
+            instance.instanceId,
+            install_cmd
+        );
+
+        let output = install_cmd.output().map_err(|e| {
+            BootstrapError::java_error(
+                BootstrapStep::InstallingModLoader,
+                format!("Error al ejecutar instalador de NeoForge: {}", e),
+            )
+        })?;
+
+        if output.status.success() {
+            log::info!(
+                "[Instance: {}] NeoForge installer finished",
+                instance.instanceId
+            );
+            return Ok(());
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        log::error!(
+            "[Instance: {}] NeoForge installer failed - Exit code: {:?}\n{}\n{}",
+            instance.instanceId,
+            output.status.code(),
+            stdout,
+            stderr
+        );
+
+        Err(BootstrapError::new(
+            BootstrapStep::InstallingModLoader,
+            ErrorCategory::Forge,
+            format!(
+                "El instalador de NeoForge falló (código {:?})",
+                output.status.code()
+            ),
+        )
+        .with_suggestion(
+            "Verifica que la versión de NeoForge sea compatible con la versión de Minecraft",
+        )
+        .with_technical_details(stderr.trim().to_string()))
+    }
 }
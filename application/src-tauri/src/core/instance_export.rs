@@ -0,0 +1,420 @@
+// src-tauri/src/core/instance_export.rs
+// Export of an instance as a portable archive (.zip or .mrpack)
+
+use crate::core::instance_manager::get_instance_by_id;
+use crate::core::minecraft_instance::{MinecraftInstance, ModLoader};
+use crate::core::modpack_file_manager::get_controlled_directories;
+use crate::core::tasks_manager::{add_task, update_task, TaskStatus};
+use serde::Deserialize;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tokio::task;
+
+/// Instance configuration at the root of a `.zip` export
+pub const EXPORT_INSTANCE_FILE: &str = "instance.json";
+/// Cached manifest of store instances, kept so updates can still clean up obsolete files
+pub const EXPORT_MANIFEST_FILE: &str = "previous_manifest.json";
+/// Folder holding the contents of the minecraft directory in a `.zip` export
+pub const EXPORT_CONTENT_PREFIX: &str = "minecraft/";
+
+/// Content that the launcher downloads again on install, never exported
+const EXCLUDED_DIRECTORIES: [&str; 3] = ["libraries", "assets", "versions"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Zip,
+    Mrpack,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    #[serde(default)]
+    pub include_saves: bool,
+    #[serde(default)]
+    pub include_resourcepacks: bool,
+    #[serde(default)]
+    pub include_shaderpacks: bool,
+    #[serde(default)]
+    pub include_options: bool,
+}
+
+/// Relative paths (with `/` separators) of the minecraft directory files to export
+pub fn collect_export_files(
+    minecraft_dir: &Path,
+    options: &ExportOptions,
+) -> Result<Vec<String>, String> {
+    let mut directories: Vec<&str> = get_controlled_directories();
+    if options.include_saves {
+        directories.push("saves");
+    }
+    if options.include_resourcepacks {
+        directories.push("resourcepacks");
+    }
+    if options.include_shaderpacks {
+        directories.push("shaderpacks");
+    }
+    directories.retain(|dir| !EXCLUDED_DIRECTORIES.contains(dir));
+
+    let mut files = Vec::new();
+    for dir in directories {
+        collect_directory(minecraft_dir, &minecraft_dir.join(dir), &mut files)?;
+    }
+
+    if options.include_options && minecraft_dir.join("options.txt").is_file() {
+        files.push("options.txt".to_string());
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+fn collect_directory(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<(), String> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(format!("Error leyendo {}: {}", dir.display(), e)),
+    };
+
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Error leyendo {}: {}", dir.display(), e))?;
+        let file_type = entry
+            .file_type()
+            .map_err(|e| format!("Error leyendo {}: {}", entry.path().display(), e))?;
+
+        // Symlinks could point anywhere on the machine, they are not part of the instance
+        if file_type.is_symlink() {
+            log::warn!("[Export] Skipping symlink {}", entry.path().display());
+        } else if file_type.is_dir() {
+            collect_directory(root, &entry.path(), files)?;
+        } else if file_type.is_file() {
+            let path = entry.path();
+            let relative = path
+                .strip_prefix(root)
+                .map_err(|e| format!("Ruta fuera de la instancia: {}", e))?;
+            files.push(
+                relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().to_string())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the archive to `output_path`, calling `on_progress(done, total)` per file
+pub fn write_export_archive(
+    instance: &MinecraftInstance,
+    output_path: &Path,
+    options: &ExportOptions,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Result<usize, String> {
+    let minecraft_dir = PathBuf::from(&instance.minecraftPath);
+    let files = collect_export_files(&minecraft_dir, options)?;
+
+    // Write next to the destination and rename so a failed export leaves nothing behind
+    let mut partial_name = output_path.file_name().unwrap_or_default().to_os_string();
+    partial_name.push(".part");
+    let partial_path = output_path.with_file_name(partial_name);
+    let result = write_archive_contents(
+        instance,
+        &minecraft_dir,
+        &files,
+        &partial_path,
+        options,
+        &mut on_progress,
+    )
+    .and_then(|_| {
+        fs::rename(&partial_path, output_path)
+            .map_err(|e| format!("Error guardando {}: {}", output_path.display(), e))
+    });
+
+    if result.is_err() {
+        let _ = fs::remove_file(&partial_path);
+    }
+    result.map(|_| files.len())
+}
+
+fn write_archive_contents(
+    instance: &MinecraftInstance,
+    minecraft_dir: &Path,
+    files: &[String],
+    archive_path: &Path,
+    options: &ExportOptions,
+    on_progress: &mut impl FnMut(usize, usize),
+) -> Result<(), String> {
+    let output = fs::File::create(archive_path)
+        .map_err(|e| format!("Error creando {}: {}", archive_path.display(), e))?;
+    let mut writer = zip::ZipWriter::new(io::BufWriter::new(output));
+    let deflated = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    let content_prefix = match options.format {
+        ExportFormat::Zip => {
+            let instance_json = serde_json::to_vec_pretty(instance)
+                .map_err(|e| format!("Error serializando la instancia: {}", e))?;
+            write_entry(&mut writer, EXPORT_INSTANCE_FILE, deflated, &instance_json)?;
+
+            if let Some(instance_dir) = &instance.instanceDirectory {
+                let cached = Path::new(instance_dir)
+                    .join(".modpack_cache")
+                    .join(EXPORT_MANIFEST_FILE);
+                if let Ok(content) = fs::read(&cached) {
+                    write_entry(&mut writer, EXPORT_MANIFEST_FILE, deflated, &content)?;
+                }
+            }
+            EXPORT_CONTENT_PREFIX
+        }
+        ExportFormat::Mrpack => {
+            let index = serde_json::to_vec_pretty(&mrpack_index(instance))
+                .map_err(|e| format!("Error serializando modrinth.index.json: {}", e))?;
+            write_entry(&mut writer, "modrinth.index.json", deflated, &index)?;
+            "overrides/"
+        }
+    };
+
+    for (done, relative) in files.iter().enumerate() {
+        let source = minecraft_dir.join(relative);
+        let mut input = fs::File::open(&source)
+            .map_err(|e| format!("Error abriendo {}: {}", source.display(), e))?;
+        let size = input
+            .metadata()
+            .map_err(|e| format!("Error leyendo {}: {}", source.display(), e))?
+            .len();
+
+        // Jars and zips are already compressed, deflating them again only costs time
+        let entry_options = if relative.ends_with(".jar") || relative.ends_with(".zip") {
+            deflated.compression_method(zip::CompressionMethod::Stored)
+        } else {
+            deflated
+        }
+        .large_file(size >= u32::MAX as u64);
+
+        writer
+            .start_file(format!("{}{}", content_prefix, relative), entry_options)
+            .map_err(|e| format!("Error escribiendo {}: {}", relative, e))?;
+        io::copy(&mut input, &mut writer)
+            .map_err(|e| format!("Error escribiendo {}: {}", relative, e))?;
+
+        on_progress(done + 1, files.len());
+    }
+
+    writer
+        .finish()
+        .map_err(|e| format!("Error finalizando el archivo: {}", e))?
+        .flush()
+        .map_err(|e| format!("Error finalizando el archivo: {}", e))?;
+    Ok(())
+}
+
+fn write_entry<W: Write + io::Seek>(
+    writer: &mut zip::ZipWriter<W>,
+    name: &str,
+    options: zip::write::SimpleFileOptions,
+    content: &[u8],
+) -> Result<(), String> {
+    writer
+        .start_file(name, options)
+        .map_err(|e| format!("Error escribiendo {}: {}", name, e))?;
+    writer
+        .write_all(content)
+        .map_err(|e| format!("Error escribiendo {}: {}", name, e))
+}
+
+/// `modrinth.index.json` for an export. Every file ships inside `overrides/`: the
+/// launcher only keeps SHA-1 hashes while the format requires SHA-512 for `files[]`.
+fn mrpack_index(instance: &MinecraftInstance) -> serde_json::Value {
+    let mut dependencies = serde_json::Map::new();
+    dependencies.insert(
+        "minecraft".to_string(),
+        serde_json::Value::String(instance.minecraftVersion.clone()),
+    );
+    let loader_key = match instance.mod_loader() {
+        ModLoader::Vanilla => None,
+        ModLoader::Forge => Some("forge"),
+        ModLoader::NeoForge => Some("neoforge"),
+        ModLoader::Fabric => Some("fabric-loader"),
+        ModLoader::Quilt => Some("quilt-loader"),
+    };
+    if let (Some(key), Some(version)) = (loader_key, instance.loader_version()) {
+        dependencies.insert(
+            key.to_string(),
+            serde_json::Value::String(version.to_string()),
+        );
+    }
+
+    serde_json::json!({
+        "formatVersion": 1,
+        "game": "minecraft",
+        "versionId": instance.modpackVersionId.clone().unwrap_or_else(|| "1.0.0".to_string()),
+        "name": instance.instanceName,
+        "files": [],
+        "dependencies": dependencies,
+    })
+}
+
+/// Exports an instance to `output_path`, returning the number of exported files
+#[tauri::command]
+pub async fn export_instance(
+    instance_id: String,
+    output_path: String,
+    options: ExportOptions,
+) -> Result<usize, String> {
+    let instance = get_instance_by_id(instance_id.clone())?
+        .ok_or_else(|| format!("Instancia no encontrada: {}", instance_id))?;
+
+    let task_id = add_task(
+        &format!("Exportando instancia {}", instance.instanceName),
+        Some(serde_json::json!({
+            "type": "instance_export",
+            "instanceName": instance.instanceName,
+            "instanceId": instance.instanceId
+        })),
+    );
+
+    let task_for_progress = task_id.clone();
+    let result = task::spawn_blocking(move || {
+        write_export_archive(
+            &instance,
+            Path::new(&output_path),
+            &options,
+            |done, total| {
+                // Avoid flooding the frontend on instances with thousands of files
+                if done % 50 == 0 || done == total {
+                    update_task(
+                        &task_for_progress,
+                        TaskStatus::Running,
+                        (done as f32 / total as f32) * 100.0,
+                        &format!("Exportando archivo {} de {}", done, total),
+                        None,
+                    );
+                }
+            },
+        )
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?;
+
+    match &result {
+        Ok(count) => update_task(
+            &task_id,
+            TaskStatus::Completed,
+            100.0,
+            &format!("Instancia exportada ({} archivos)", count),
+            None,
+        ),
+        Err(e) => update_task(
+            &task_id,
+            TaskStatus::Failed,
+            0.0,
+            &format!("Error exportando la instancia: {}", e),
+            None,
+        ),
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn options(format: ExportFormat) -> ExportOptions {
+        ExportOptions {
+            format,
+            include_saves: true,
+            include_resourcepacks: false,
+            include_shaderpacks: false,
+            include_options: true,
+        }
+    }
+
+    fn sample_instance(root: &Path) -> MinecraftInstance {
+        let minecraft_dir = root.join("minecraft");
+        for (path, content) in [
+            ("mods/jei.jar", "jar"),
+            ("config/jei/settings.toml", "config"),
+            ("saves/World/level.dat", "level"),
+            ("resourcepacks/pack.zip", "pack"),
+            ("options.txt", "options"),
+            ("libraries/net/lib.jar", "library"),
+            ("versions/1.20.1/1.20.1.jar", "client"),
+        ] {
+            let file = minecraft_dir.join(path);
+            fs::create_dir_all(file.parent().unwrap()).unwrap();
+            fs::write(file, content).unwrap();
+        }
+
+        let mut instance = MinecraftInstance::new();
+        instance.instanceId = "source".to_string();
+        instance.instanceName = "Exported".to_string();
+        instance.minecraftVersion = "1.20.1".to_string();
+        instance.forgeVersion = Some("47.2.0".to_string());
+        instance.instanceDirectory = Some(root.to_string_lossy().to_string());
+        instance.minecraftPath = minecraft_dir.to_string_lossy().to_string();
+        instance
+    }
+
+    fn archive_names(path: &Path) -> Vec<String> {
+        let archive = zip::ZipArchive::new(fs::File::open(path).unwrap()).unwrap();
+        let mut names: Vec<String> = archive.file_names().map(str::to_string).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_zip_export_skips_redownloadable_content() {
+        let dir = tempdir().unwrap();
+        let instance = sample_instance(dir.path());
+        let output = dir.path().join("export.zip");
+
+        let count =
+            write_export_archive(&instance, &output, &options(ExportFormat::Zip), |_, _| {})
+                .unwrap();
+
+        assert_eq!(count, 4);
+        assert_eq!(
+            archive_names(&output),
+            vec![
+                "instance.json",
+                "minecraft/config/jei/settings.toml",
+                "minecraft/mods/jei.jar",
+                "minecraft/options.txt",
+                "minecraft/saves/World/level.dat",
+            ]
+        );
+        assert!(!dir.path().join("export.zip.part").exists());
+    }
+
+    #[test]
+    fn test_mrpack_export_ships_files_as_overrides() {
+        let dir = tempdir().unwrap();
+        let instance = sample_instance(dir.path());
+        let output = dir.path().join("export.mrpack");
+
+        let mut export_options = options(ExportFormat::Mrpack);
+        export_options.include_saves = false;
+        write_export_archive(&instance, &output, &export_options, |_, _| {}).unwrap();
+
+        assert_eq!(
+            archive_names(&output),
+            vec![
+                "modrinth.index.json",
+                "overrides/config/jei/settings.toml",
+                "overrides/mods/jei.jar",
+                "overrides/options.txt",
+            ]
+        );
+
+        let index = mrpack_index(&instance);
+        assert_eq!(index["dependencies"]["forge"], "47.2.0");
+        assert_eq!(index["dependencies"]["minecraft"], "1.20.1");
+    }
+}
@@ -4,15 +4,19 @@
 use std::fs;
 use std::io::{Error as IoError, ErrorKind as IoErrorKind};
 use std::path::PathBuf;
-use std::process::{Child, Command};
-use std::sync::Arc;
+use std::process::{Child, Command, ExitStatus};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 
 // --- Crate Imports ---
+use crate::core::game_log::{forward_lines, GameLogSession, LogRecord, LogStream};
 use crate::core::instance_bootstrap::InstanceBootstrap;
+use crate::core::minecraft::hooks::{run_hook, HookContext};
+use crate::core::minecraft::wrappers::{effective_wrappers, resolve_wrapper_chain};
 use crate::core::minecraft::MinecraftLauncher as CoreMinecraftLauncher;
 use crate::core::minecraft_instance::MinecraftInstance;
 use crate::core::network_utilities;
+use crate::core::running_instances;
 use crate::interfaces::game_launcher::GameLauncher;
 use crate::utils::config_manager::get_config_manager;
 use crate::GLOBAL_APP_HANDLE;
@@ -33,6 +37,7 @@ const EVENT_DOWNLOADING_ASSETS: &str = "instance-downloading-assets";
 const EVENT_LAUNCHED: &str = "instance-launched";
 const EVENT_EXITED: &str = "instance-exited";
 const EVENT_ERROR: &str = "instance-error";
+const EVENT_LOG: &str = "instance-log";
 
 //-----------------------------------------------------------------------------
 // Error Handling
@@ -50,6 +55,12 @@ pub enum LaunchError {
 
     #[error("The Minecraft launcher failed to start the process.")]
     ProcessStartFailed,
+
+    #[error("{0}")]
+    LaunchWrapperMissing(String),
+
+    #[error("Pre-launch hook failed: {0}")]
+    PreLaunchHookFailed(String),
 }
 
 impl From<String> for LaunchError {
@@ -162,6 +173,25 @@ impl InstanceLauncher {
         self.emit_status(EVENT_ERROR, error_message, data);
     }
 
+    /// Streams one record of game output. Not mirrored to the launcher log: the game's output
+    /// already goes to the session log file.
+    fn emit_log(&self, record: &LogRecord) {
+        if let Ok(guard) = GLOBAL_APP_HANDLE.lock() {
+            if let Some(app_handle) = guard.as_ref() {
+                let payload = json!({
+                    "id": self.instance.instanceId,
+                    "record": record,
+                });
+                if let Err(e) = app_handle.emit(EVENT_LOG, payload) {
+                    error!(
+                        "[Instance: {}] Failed to emit event '{}': {}",
+                        self.instance.instanceId, EVENT_LOG, e
+                    );
+                }
+            }
+        }
+    }
+
     // --- Process Monitoring ---
 
     /// Monitors the launched Minecraft process in a separate thread.
@@ -177,23 +207,53 @@ impl InstanceLauncher {
                 instance.instanceId
             );
 
-            match child.wait_with_output() {
-                Ok(output) => {
-                    let exit_code = output.status.code().unwrap_or(-1);
-                    let stdout = String::from_utf8_lossy(&output.stdout);
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-                    let official_exit_code: OfficialExitCode = exit_code.into();
+            // Forward both pipes line by line; the channel closes when the game closes them
+            let instance_dir = PathBuf::from(
+                instance
+                    .instanceDirectory
+                    .as_deref()
+                    .unwrap_or(&instance.minecraftPath),
+            );
+            let mut session = GameLogSession::start(&instance.instanceId, &instance_dir);
+            info!(
+                "[Monitor: {}] Writing session log to {}",
+                instance.instanceId,
+                session.path().display()
+            );
+            let (sender, receiver) = mpsc::channel();
+            let readers = [
+                forward_lines(child.stdout.take(), LogStream::Stdout, sender.clone()),
+                forward_lines(child.stderr.take(), LogStream::Stderr, sender),
+            ];
+
+            // Shared with the registry so `kill_instance` can stop the game
+            let child = Arc::new(Mutex::new(child));
+            running_instances::attach(&instance, Arc::clone(&child));
+            for (stream, line) in receiver {
+                if let Some(record) = session.push_line(stream, &line) {
+                    emitter_launcher.emit_log(&record);
+                }
+            }
+            for reader in readers.into_iter().flatten() {
+                let _ = reader.join();
+            }
+            for record in session.finish() {
+                emitter_launcher.emit_log(&record);
+            }
 
-                    info!("[Minecraft:{} stdout]\n{}", instance.instanceId, stdout);
-                    if !stderr.is_empty() {
-                        error!("[Minecraft:{} stderr]\n{}", instance.instanceId, stderr);
-                    }
+            match Self::wait_for_exit(&child) {
+                Ok(status) => {
+                    let exit_code = status.code().unwrap_or(-1);
+                    let stdout = session.output_tail();
+                    let stderr = session.error_tail();
+                    let official_exit_code: OfficialExitCode = exit_code.into();
+                    let killed = running_instances::is_stopping(&instance.instanceId);
 
                     let mut crash_report_content: Option<String> = None;
                     let mut detected_error_details = json!({ "code": "UNKNOWN_ERROR" });
 
                     // Only search for crash reports and analyze stderr if the game exited with an error
-                    if exit_code != 0 {
+                    if exit_code != 0 && !killed {
                         // --- 1. The Holy Grail: Search for a crash report file ---
                         let crash_report_dir =
                             PathBuf::from(&instance.minecraftPath).join("crash-reports");
@@ -243,6 +303,7 @@ impl InstanceLauncher {
                         &message,
                         Some(json!({
                             "exitCode": exit_code,
+                            "killed": killed, // Stopped with `kill_instance`
                             "officialExitCode": format!("{:?}", official_exit_code),
                             "detectedError": detected_error_details, // Detailed error object
                             "crashReport": crash_report_content, // Full crash report text
@@ -250,6 +311,8 @@ impl InstanceLauncher {
                             "stderr": stderr.trim_end(),
                         })),
                     );
+
+                    Self::run_post_exit_hook(&instance, exit_code);
                 }
                 Err(err) => {
                     let error_msg = format!("Failed to wait for process: {}", err);
@@ -258,10 +321,26 @@ impl InstanceLauncher {
                 }
             }
 
+            running_instances::release(&instance.instanceId);
             info!("[Monitor: {}] Finished monitoring.", instance.instanceId);
         });
     }
 
+    /// Waits for the game to exit, polling so the lock `kill_instance` needs is only held
+    /// for each check. Its pipes can close before the process is gone.
+    fn wait_for_exit(child: &Mutex<Child>) -> std::io::Result<ExitStatus> {
+        loop {
+            let status = child
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .try_wait()?;
+            if let Some(status) = status {
+                return Ok(status);
+            }
+            thread::sleep(std::time::Duration::from_millis(200));
+        }
+    }
+
     /// Revalidates or downloads necessary game assets, libraries, etc.
     fn revalidate_assets(&self) -> Result<(), LaunchError> {
         info!(
@@ -280,11 +359,7 @@ impl InstanceLauncher {
         }
 
         if !network_utilities::check_real_connection() {
-            warn!(
-                "[Instance: {}] No internet connection. Skipping asset revalidation.",
-                self.instance.instanceId
-            );
-            return Ok(());
+            return self.check_offline_launch();
         }
 
         // This is tricky because revalidate_assets needs a mutable instance.
@@ -305,6 +380,48 @@ impl InstanceLauncher {
         Ok(())
     }
 
+    /// Offline launch: nothing can be downloaded, so only make sure the files the cached
+    /// version metadata declares are present
+    fn check_offline_launch(&self) -> Result<(), LaunchError> {
+        let mut instance_bootstrap = InstanceBootstrap::offline();
+
+        match instance_bootstrap.missing_offline_files(&self.instance) {
+            Ok(missing) if missing.is_empty() => {
+                info!(
+                    "[Instance: {}] No internet connection, launching from cached metadata.",
+                    self.instance.instanceId
+                );
+                Ok(())
+            }
+            Ok(missing) => {
+                let message = format!(
+                    "Sin conexión a internet y faltan {} archivos para iniciar la instancia (por ejemplo {})",
+                    missing.len(),
+                    missing[0]
+                );
+                Err(LaunchError::AssetRevalidationError(message))
+            }
+            Err(e) => {
+                // Instances installed before the metadata cache existed have nothing cached yet
+                warn!(
+                    "[Instance: {}] No internet connection and no cached metadata ({}). Skipping asset revalidation.",
+                    self.instance.instanceId, e
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Java used when no runtime was selected for the version: the one pinned for the
+    /// instance or, failing that, the global `javaDir`
+    fn configured_java_home(&self) -> Option<PathBuf> {
+        if let Some(java_home) = self.instance.java_override() {
+            return Some(PathBuf::from(java_home));
+        }
+        let config_manager = crate::config::get_config_manager().lock().ok()?;
+        config_manager.as_ref().ok()?.get_java_dir()
+    }
+
     // --- Internal Synchronous Launch Logic ---
 
     /// Contains the core, sequential steps for launching the instance.
@@ -324,8 +441,27 @@ impl InstanceLauncher {
                 self.instance.instanceId
             );
 
-            // 2. Launch Minecraft
-            let minecraft_launcher = CoreMinecraftLauncher::new((*self.instance).clone());
+            // 2. Check the wrapper commands before spawning anything
+            self.check_launch_wrappers()?;
+
+            // 3. Run the instance's pre-launch hook; a failure aborts the launch
+            self.run_pre_launch_hook()?;
+
+            // 4. Pick the Java the version needs, downloading it if missing
+            let mut minecraft_launcher = CoreMinecraftLauncher::new((*self.instance).clone());
+            let java_home = match minecraft_launcher.select_java_home() {
+                Some(java_home) => {
+                    minecraft_launcher = minecraft_launcher.with_java_home(java_home.clone());
+                    Some(java_home)
+                }
+                None => self.configured_java_home(),
+            };
+            // Lets the Java manager refuse to remove a runtime the game is using
+            if let Some(java_home) = java_home {
+                running_instances::set_java_home(&self.instance.instanceId, java_home);
+            }
+
+            // 5. Launch Minecraft
 
             minecraft_launcher
                 .launch()
@@ -346,6 +482,7 @@ impl InstanceLauncher {
                 self.handle_close_on_launch();
             }
             Err(e) => {
+                running_instances::release(&self.instance.instanceId);
                 let err_msg = e.to_string();
                 error!(
                     "[Launch Thread: {}] Launch sequence failed: {}",
@@ -360,6 +497,64 @@ impl InstanceLauncher {
         );
     }
 
+    /// Fails with the name of the first configured wrapper that is not on `PATH`.
+    fn check_launch_wrappers(&self) -> Result<(), LaunchError> {
+        // Released before launching: the launcher locks the config for the whole launch
+        let wrappers = match crate::config::get_config_manager().lock() {
+            Ok(guard) => match guard.as_ref() {
+                Ok(config) => effective_wrappers(&self.instance, config),
+                Err(_) => return Ok(()),
+            },
+            Err(_) => return Ok(()),
+        };
+
+        resolve_wrapper_chain(&wrappers)
+            .map(|_| ())
+            .map_err(LaunchError::LaunchWrapperMissing)
+    }
+
+    fn run_pre_launch_hook(&self) -> Result<(), LaunchError> {
+        let hooks = &self.instance.launchOptions.hooks;
+        let Some(script) = hooks.pre_launch() else {
+            return Ok(());
+        };
+
+        self.emit_status(
+            EVENT_LAUNCH_START,
+            "Ejecutando script previo al lanzamiento...",
+            None,
+        );
+        let output = run_hook(script, &HookContext::new(&self.instance), hooks.timeout())
+            .map_err(LaunchError::PreLaunchHookFailed)?;
+        info!(
+            "[Launch Thread: {}] Pre-launch hook finished.\n{}",
+            self.instance.instanceId,
+            output.stdout.trim_end()
+        );
+        Ok(())
+    }
+
+    /// Runs the instance's post-exit hook; the game is already gone, so failures are logged only.
+    fn run_post_exit_hook(instance: &MinecraftInstance, exit_code: i32) {
+        let hooks = &instance.launchOptions.hooks;
+        let Some(script) = hooks.post_exit() else {
+            return;
+        };
+
+        let context = HookContext::new(instance).with_exit_code(exit_code);
+        match run_hook(script, &context, hooks.timeout()) {
+            Ok(output) => info!(
+                "[Monitor: {}] Post-exit hook finished.\n{}",
+                instance.instanceId,
+                output.stdout.trim_end()
+            ),
+            Err(e) => warn!(
+                "[Monitor: {}] Post-exit hook failed: {}",
+                instance.instanceId, e
+            ),
+        }
+    }
+
     fn handle_close_on_launch(&self) {
         let close_on_launch = get_config_manager()
             .lock()
@@ -1,10 +1,16 @@
 // src-tauri/src/core/instance_manager.rs
 
 use crate::config::get_config_manager;
+use crate::core::bootstrap::store::SharedStore;
 use crate::core::bootstrap_error::BootstrapError;
 use crate::core::instance_bootstrap::InstanceBootstrap;
-use crate::core::minecraft_instance::{self, MinecraftInstance};
+use crate::core::manifest_signature::{
+    signature_error_to_string, verify_manifest_signature, ManifestSignature,
+};
+use crate::core::minecraft::LaunchOptions;
+use crate::core::minecraft_instance::{self, MinecraftInstance, ModLoader};
 use crate::core::modpack_file_manager::ModpackManifest;
+use crate::core::running_instances;
 use crate::core::tasks_manager::{
     add_task, add_task_with_auto_start, remove_task, update_task, update_task_with_bootstrap_error,
     TaskStatus,
@@ -20,18 +26,18 @@ use tauri::Emitter;
 use tokio::task;
 
 // Constants
-const DEFAULT_VANILLA_ICON: &str = "/images/default_instances/default_vanilla.webp";
-const DEFAULT_FORGE_ICON: &str = "/images/default_instances/default_forge.webp";
+pub(crate) const DEFAULT_VANILLA_ICON: &str = "/images/default_instances/default_vanilla.webp";
+pub(crate) const DEFAULT_FORGE_ICON: &str = "/images/default_instances/default_forge.webp";
 const MAX_SEARCH_RESULTS: usize = 20;
 const TASK_CLEANUP_DELAY: u64 = 60;
 
 // Función auxiliar para normalizar rutas
-fn normalize_path(path: &Path) -> String {
+pub(crate) fn normalize_path(path: &Path) -> String {
     path.to_string_lossy().to_string()
 }
 
 // Función auxiliar para obtener el directorio de instancias
-fn get_instances_dir() -> Result<PathBuf, String> {
+pub(crate) fn get_instances_dir() -> Result<PathBuf, String> {
     let config_manager = get_config_manager()
         .lock()
         .map_err(|_| "Failed to lock config manager mutex")?;
@@ -70,8 +76,7 @@ pub fn update_instance(instance: MinecraftInstance) -> Result<(), String> {
         let mut existing_instance: MinecraftInstance =
             from_str(&contents).map_err(|e| format!("Error parsing JSON: {}", e))?;
 
-        existing_instance.instanceName = instance.instanceName;
-        existing_instance.accountUuid = instance.accountUuid;
+        apply_instance_edits(&mut existing_instance, instance)?;
 
         existing_instance
             .save()
@@ -81,6 +86,28 @@ pub fn update_instance(instance: MinecraftInstance) -> Result<(), String> {
     Ok(())
 }
 
+/// Copies the settings the user can edit onto the stored instance
+fn apply_instance_edits(
+    existing: &mut MinecraftInstance,
+    edited: MinecraftInstance,
+) -> Result<(), String> {
+    edited.launchOptions.validate()?;
+
+    // A pinned Java home replaces the runtime picked from the version's javaVersion
+    let java_override = edited.java_override().map(str::to_string);
+    if let Some(java_home) = &java_override {
+        if !Path::new(java_home).is_dir() {
+            return Err(format!("La ruta de Java {} no existe", java_home));
+        }
+    }
+
+    existing.instanceName = edited.instanceName;
+    existing.accountUuid = edited.accountUuid;
+    existing.launchOptions = edited.launchOptions;
+    existing.set_java_override(java_override);
+    Ok(())
+}
+
 #[tauri::command]
 pub fn get_instance_by_id(instance_id: String) -> Result<Option<MinecraftInstance>, String> {
     let instances_dir = get_instances_dir()?;
@@ -92,21 +119,68 @@ pub fn get_instance_by_id(instance_id: String) -> Result<Option<MinecraftInstanc
 pub fn delete_instance(instance_path: String) -> Result<(), String> {
     let path = Path::new(&instance_path);
     if path.exists() && path.is_dir() {
+        let instance_id = MinecraftInstance::from_directory(path).map(|i| i.instanceId);
+
         fs::remove_dir_all(path).map_err(|e| format!("Failed to delete instance: {}", e))?;
+
+        if let Some(instance_id) = instance_id {
+            release_shared_store_references(&instance_id);
+        }
     }
     Ok(())
 }
 
+/// Releases the shared store blobs referenced by a deleted instance and
+/// garbage-collects the ones no other instance uses
+fn release_shared_store_references(instance_id: &str) {
+    let store = match SharedStore::open() {
+        Ok(store) => store,
+        Err(e) => {
+            log::warn!("Shared store unavailable, skipping GC: {}", e);
+            return;
+        }
+    };
+
+    match store.release_instance(instance_id) {
+        Ok(released) => {
+            log::info!(
+                "Released {} shared store references for instance {}",
+                released,
+                instance_id
+            );
+            if let Err(e) = store.collect_garbage() {
+                log::warn!("Shared store GC failed: {}", e);
+            }
+        }
+        Err(e) => log::warn!(
+            "Failed to release shared store references for {}: {}",
+            instance_id,
+            e
+        ),
+    }
+}
+
 #[tauri::command]
 pub async fn launch_mc_instance(instance_id: String) -> Result<(), String> {
     let instances_dir = get_instances_dir()?;
     let instances = get_instances(instances_dir.to_str().unwrap_or_default())?;
 
-    let mut instance = instances
+    let instance = instances
         .into_iter()
         .find(|i| i.instanceId == instance_id)
         .ok_or_else(|| format!("Instance with ID {} not found", instance_id))?;
 
+    // Refuses a second launch; the launcher releases it when the game exits or fails to start
+    running_instances::reserve(&instance)?;
+    let result = prepare_and_launch(instance).await;
+    if result.is_err() {
+        running_instances::release(&instance_id);
+    }
+    result
+}
+
+/// Updates and validates modpack files if needed, then hands the instance to the launcher
+async fn prepare_and_launch(mut instance: MinecraftInstance) -> Result<(), String> {
     // Emit instance launching status
 
     if let Ok(guard) = crate::GLOBAL_APP_HANDLE.lock() {
@@ -487,6 +561,8 @@ pub async fn create_local_instance(
     instance_name: String,
     mc_version: String,
     forge_version: Option<String>,
+    loader: Option<ModLoader>,
+    loader_version: Option<String>,
 ) -> Result<String, String> {
     let instances_dir = get_instances_dir()?;
     let instance_id = uuid::Uuid::new_v4().to_string();
@@ -495,12 +571,15 @@ pub async fn create_local_instance(
     let mut instance = MinecraftInstance::new();
     instance.instanceName = instance_name.clone();
     instance.minecraftVersion = mc_version;
-    instance.forgeVersion = forge_version.clone();
     instance.instanceId = instance_id.clone();
+    match loader {
+        Some(loader) => instance.set_loader(loader, loader_version.or(forge_version)),
+        None => instance.forgeVersion = forge_version,
+    }
 
     // Configurar ícono por defecto
     instance.bannerUrl = Some(
-        if forge_version.is_some() {
+        if instance.is_forge_instance() {
             DEFAULT_FORGE_ICON
         } else {
             DEFAULT_VANILLA_ICON
@@ -610,13 +689,7 @@ pub async fn create_modpack_instance(
     let manifest = fetch_modpack_manifest(&modpack_id, &actual_version_id_for_manifest)
         .await
         .map_err(|e| {
-            update_task(
-                &task_id,
-                TaskStatus::Failed,
-                0.0,
-                &format!("Error descargando manifiesto: {}", e),
-                None,
-            );
+            report_manifest_error(&task_id, &e);
             e
         })?;
 
@@ -664,6 +737,10 @@ pub async fn remove_instance(instance_id: String) -> Result<bool, String> {
             .await
             .map_err(|e| format!("Task join error: {}", e))?
             .map_err(|e| format!("Failed to delete instance directory: {}", e))?;
+
+        task::spawn_blocking(move || release_shared_store_references(&instance_id))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?;
     }
 
     Ok(true)
@@ -814,18 +891,12 @@ pub async fn update_modpack_instance(
     let manifest = fetch_modpack_manifest(&modpack_id, &final_version_id)
         .await
         .map_err(|e| {
-            update_task(
-                &task_id,
-                TaskStatus::Failed,
-                0.0,
-                &format!("Error descargando manifiesto: {}", e),
-                None,
-            );
+            report_manifest_error(&task_id, &e);
             e
         })?;
 
     instance.minecraftVersion = manifest.mc_version.clone();
-    instance.forgeVersion = manifest.forge_version.clone();
+    apply_manifest_loader(&mut instance, &manifest);
 
     instance.save().map_err(|e| {
         update_task(
@@ -858,11 +929,7 @@ fn spawn_instance_creation_task(instance: MinecraftInstance, task_id: String) {
             None,
         );
 
-        let result = if instance.forgeVersion.is_some() {
-            bootstrap.bootstrap_forge_instance(&instance, Some(task_id.clone()))
-        } else {
-            bootstrap.bootstrap_vanilla_instance(&instance, Some(task_id.clone()))
-        };
+        let result = bootstrap.bootstrap_instance(&instance, Some(task_id.clone()));
 
         match result {
             Ok(_) => {
@@ -899,7 +966,7 @@ fn spawn_instance_creation_task(instance: MinecraftInstance, task_id: String) {
     });
 }
 
-fn spawn_modpack_creation_task(
+pub(crate) fn spawn_modpack_creation_task(
     instance: MinecraftInstance,
     manifest: ModpackManifest,
     task_id: String,
@@ -915,11 +982,7 @@ fn spawn_modpack_creation_task(
             None,
         );
 
-        let bootstrap_result = if instance.forgeVersion.is_some() {
-            bootstrap.bootstrap_forge_instance(&instance, Some(task_id.clone()))
-        } else {
-            bootstrap.bootstrap_vanilla_instance(&instance, Some(task_id.clone()))
-        };
+        let bootstrap_result = bootstrap.bootstrap_instance(&instance, Some(task_id.clone()));
 
         if let Err(e) = bootstrap_result {
             // Check if this is a bootstrap error
@@ -957,6 +1020,9 @@ fn spawn_modpack_creation_task(
             .await
         });
 
+        // Files imported from a local archive were staged only for this install
+        crate::core::modpack_import::discard_import_staging(&instance);
+
         let files_processed = match files_processed {
             Ok(count) => count,
             Err(e) => {
@@ -1166,6 +1232,40 @@ fn spawn_modpack_update_task(
 }
 
 // Funciones auxiliares para crear instancia de modpack
+
+/// Sets the loader a store manifest declares (`quiltVersion` or `forgeVersion`)
+fn apply_manifest_loader(instance: &mut MinecraftInstance, manifest: &ModpackManifest) {
+    if let Some(quilt_version) = &manifest.quilt_version {
+        instance.set_loader(ModLoader::Quilt, Some(quilt_version.clone()));
+    } else if let Some(forge_version) = &manifest.forge_version {
+        instance.set_loader(ModLoader::Forge, Some(forge_version.clone()));
+    } else {
+        instance.set_loader(ModLoader::Vanilla, None);
+    }
+}
+
+/// Starts a new instance from the launch options the modpack recommends, if they are valid
+fn apply_recommended_launch_options(instance: &mut MinecraftInstance, manifest: &ModpackManifest) {
+    let Some(options) = &manifest.launch_options else {
+        return;
+    };
+    match options.validate() {
+        Ok(()) => {
+            instance.launchOptions = LaunchOptions {
+                // Wrappers and hooks run host commands that depend on each machine
+                wrappers: None,
+                hooks: Default::default(),
+                ..options.clone()
+            }
+        }
+        Err(e) => log::warn!(
+            "Ignoring launch options recommended by modpack {}: {}",
+            manifest.id,
+            e
+        ),
+    }
+}
+
 async fn create_modpack_instance_struct(
     instance_name: String,
     modpack_id: String,
@@ -1181,8 +1281,9 @@ async fn create_modpack_instance_struct(
     instance.instanceId = instance_id.clone();
     instance.modpackId = Some(modpack_id.clone());
     instance.modpackVersionId = Some(final_version_id);
+    apply_manifest_loader(&mut instance, &manifest);
+    apply_recommended_launch_options(&mut instance, &manifest);
     instance.minecraftVersion = manifest.mc_version;
-    instance.forgeVersion = manifest.forge_version;
 
     println!("{}", modpack_info.to_string());
 
@@ -1279,6 +1380,21 @@ async fn fetch_modpack_info(modpack_id: &str) -> Result<serde_json::Value, Strin
         .map_err(|e| format!("Failed to parse JSON: {}", e))
 }
 
+/// Marks the task as failed, surfacing signature failures as bootstrap errors
+fn report_manifest_error(task_id: &str, error: &str) {
+    if let Ok(bootstrap_error) = serde_json::from_str::<BootstrapError>(error) {
+        update_task_with_bootstrap_error(task_id, &bootstrap_error);
+    } else {
+        update_task(
+            task_id,
+            TaskStatus::Failed,
+            0.0,
+            &format!("Error descargando manifiesto: {}", error),
+            None,
+        );
+    }
+}
+
 pub async fn fetch_modpack_manifest(
     modpack_id: &str,
     version_id: &str,
@@ -1304,6 +1420,29 @@ pub async fn fetch_modpack_manifest(
         .await
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
 
+    // Refuse anything not signed by a pinned publisher key before it can reach the disk
+    let signature = json
+        .get("signature")
+        .filter(|value| !value.is_null())
+        .map(|value| serde_json::from_value::<ManifestSignature>(value.clone()))
+        .transpose()
+        .map_err(|e| {
+            signature_error_to_string(
+                &BootstrapError::signature_error("La firma del manifiesto está mal formada")
+                    .with_technical_details(e.to_string()),
+            )
+        })?;
+
+    verify_manifest_signature(&json["manifest"], signature.as_ref()).map_err(|e| {
+        log::error!(
+            "Rejected manifest for modpack {} version {}: {}",
+            modpack_id,
+            version_id,
+            e
+        );
+        signature_error_to_string(&e)
+    })?;
+
     serde_json::from_value(json["manifest"].clone())
         .map_err(|e| format!("Failed to parse manifest: {}", e))
 }
@@ -1380,3 +1519,41 @@ pub async fn validate_modpack_password(
 
     Ok(json["valid"].as_bool().unwrap_or(false))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::minecraft::MinecraftLauncher;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_pinned_java_wins_over_automatic_selection() {
+        let dir = tempdir().unwrap();
+        let java_home = dir.path().to_string_lossy().to_string();
+        let mut existing = MinecraftInstance::new();
+
+        let mut edited = existing.clone();
+        edited.javaPath = Some(java_home.clone());
+        edited.javaPathPinned = true;
+        apply_instance_edits(&mut existing, edited).unwrap();
+        assert_eq!(existing.java_override(), Some(java_home.as_str()));
+        // The runtime matching the version is neither looked up nor downloaded
+        assert_eq!(
+            MinecraftLauncher::new(existing.clone()).select_java_home(),
+            None
+        );
+
+        // Unpinning goes back to the automatic selection
+        let mut edited = existing.clone();
+        edited.javaPathPinned = false;
+        apply_instance_edits(&mut existing, edited).unwrap();
+        assert_eq!(existing.java_override(), None);
+        assert_eq!(existing.javaPath, None);
+
+        let mut edited = existing.clone();
+        edited.javaPath = Some(dir.path().join("missing").to_string_lossy().to_string());
+        edited.javaPathPinned = true;
+        assert!(apply_instance_edits(&mut existing, edited).is_err());
+        assert_eq!(existing.java_override(), None);
+    }
+}
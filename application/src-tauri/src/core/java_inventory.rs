@@ -0,0 +1,351 @@
+// src/core/java_inventory.rs
+// Inventario de los runtimes de Java: los que instala el launcher en `_java_versions` y
+// los que ya hay en el sistema, con lo que la UI necesita para mostrarlos y gestionarlos.
+
+use crate::core::bootstrap::manifest::{get_java_runtime_component, get_java_version_requirement};
+use crate::core::instance_manager::get_all_instances;
+use crate::core::java_manager::JavaManager;
+use crate::core::minecraft_instance::MinecraftInstance;
+use crate::core::running_instances;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::task;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JavaSource {
+    /// Instalado por el launcher; se puede eliminar desde la UI
+    Managed,
+    /// Encontrado en el sistema (JAVA_HOME, PATH o ubicaciones comunes)
+    System,
+}
+
+/// Lo que reporta la propia JVM con `-XshowSettings:properties`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaProbe {
+    pub version: Option<String>,
+    pub major_version: Option<u32>,
+    pub vendor: Option<String>,
+    pub arch: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaInstanceUsage {
+    pub instance_id: String,
+    pub instance_name: String,
+    /// La instancia tiene esta instalación fijada en lugar de la elegida automáticamente
+    pub pinned: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaInstallation {
+    /// Directorio dentro de `_java_versions`, solo para runtimes gestionados
+    pub id: Option<String>,
+    pub source: JavaSource,
+    /// Home de Java (el directorio que contiene `bin`)
+    pub path: String,
+    #[serde(flatten)]
+    pub probe: JavaProbe,
+    /// `false` si `java -version` no se pudo ejecutar
+    pub valid: bool,
+    pub size_bytes: u64,
+    pub instances: Vec<JavaInstanceUsage>,
+}
+
+fn java_executable(java_home: &Path) -> PathBuf {
+    java_home.join("bin").join(if cfg!(target_os = "windows") {
+        "java.exe"
+    } else {
+        "java"
+    })
+}
+
+/// Lee las propiedades `clave = valor` que imprime `-XshowSettings:properties`
+pub fn parse_java_properties(output: &str) -> JavaProbe {
+    let property = |key: &str| {
+        output.lines().find_map(|line| {
+            let (name, value) = line.split_once(" = ")?;
+            (name.trim() == key).then(|| value.trim().to_string())
+        })
+    };
+
+    // "1.8" en Java 8 y anteriores, "17" a partir de Java 9
+    let major_version = property("java.specification.version").and_then(|spec| {
+        let spec = spec.strip_prefix("1.").unwrap_or(&spec);
+        spec.parse::<u32>().ok()
+    });
+
+    JavaProbe {
+        version: property("java.runtime.version").or_else(|| property("java.version")),
+        major_version,
+        vendor: property("java.vendor"),
+        arch: property("os.arch"),
+    }
+}
+
+/// Ejecuta la JVM para obtener su versión, fabricante y arquitectura
+pub fn probe_java(java_home: &Path) -> Result<JavaProbe, String> {
+    let java_exe = java_executable(java_home);
+    if !java_exe.is_file() {
+        return Err(format!("No se encontró {}", java_exe.display()));
+    }
+
+    let mut command = std::process::Command::new(&java_exe);
+    command.args(["-XshowSettings:properties", "-version"]);
+
+    // En Windows, usar CREATE_NO_WINDOW para evitar que aparezca una ventana de CMD
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        command.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| format!("Error al ejecutar {}: {}", java_exe.display(), e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} -version terminó con {}",
+            java_exe.display(),
+            output.status
+        ));
+    }
+
+    let probe = parse_java_properties(&String::from_utf8_lossy(&output.stderr));
+    if probe.major_version.is_none() {
+        return Err(format!(
+            "No se pudo leer la versión de Java de {}",
+            java_exe.display()
+        ));
+    }
+    Ok(probe)
+}
+
+/// Tamaño en disco de un directorio, sin seguir enlaces simbólicos
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => dir_size(&entry.path()),
+            Ok(file_type) if file_type.is_file() => {
+                entry.metadata().map(|metadata| metadata.len()).unwrap_or(0)
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Home de Java con el que se lanzaría la instancia: el fijado por el usuario o el runtime
+/// instalado que corresponde al `javaVersion` de su versión de Minecraft
+fn instance_java_home(java_manager: &JavaManager, instance: &MinecraftInstance) -> Option<PathBuf> {
+    if let Some(java_path) = instance.java_override() {
+        return Some(PathBuf::from(java_path));
+    }
+
+    let version = &instance.minecraftVersion;
+    let version_file = Path::new(instance.instanceDirectory.as_deref()?)
+        .join("minecraft")
+        .join("versions")
+        .join(version)
+        .join(format!("{}.json", version));
+    let version_details: Value =
+        serde_json::from_str(&fs::read_to_string(version_file).ok()?).ok()?;
+
+    let major_version = get_java_version_requirement(&version_details).ok()?;
+    let component = get_java_runtime_component(&version_details);
+    java_manager.installed_runtime(&major_version, component.as_deref())
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// `install_dir` es el directorio cuyo tamaño se informa: en los runtimes de Mojang para
+/// macOS el home está dentro de `jre.bundle`, pero se cuenta el runtime completo
+fn describe(
+    java_home: PathBuf,
+    install_dir: &Path,
+    id: Option<String>,
+    source: JavaSource,
+    usages: &[(MinecraftInstance, PathBuf)],
+) -> JavaInstallation {
+    let (probe, valid) = match probe_java(&java_home) {
+        Ok(probe) => (probe, true),
+        Err(e) => {
+            log::warn!("Java at {} failed to run: {}", java_home.display(), e);
+            (JavaProbe::default(), false)
+        }
+    };
+
+    let instances = usages
+        .iter()
+        .filter(|(_, used_home)| same_path(used_home, &java_home))
+        .map(|(instance, _)| JavaInstanceUsage {
+            instance_id: instance.instanceId.clone(),
+            instance_name: instance.instanceName.clone(),
+            pinned: instance.java_override().is_some(),
+        })
+        .collect();
+
+    JavaInstallation {
+        id,
+        source,
+        path: java_home.to_string_lossy().to_string(),
+        probe,
+        valid,
+        size_bytes: dir_size(install_dir),
+        instances,
+    }
+}
+
+fn java_manager() -> Result<JavaManager, String> {
+    JavaManager::new().map_err(|e| format!("Error al inicializar JavaManager: {}", e))
+}
+
+fn list_installations() -> Result<Vec<JavaInstallation>, String> {
+    let java_manager = java_manager()?;
+    let usages: Vec<(MinecraftInstance, PathBuf)> = get_all_instances()?
+        .into_iter()
+        .filter_map(|instance| {
+            let java_home = instance_java_home(&java_manager, &instance)?;
+            Some((instance, java_home))
+        })
+        .collect();
+
+    let managed = java_manager.managed_runtimes().into_iter().map(|runtime| {
+        describe(
+            runtime.java_home,
+            &runtime.dir,
+            Some(runtime.id),
+            JavaSource::Managed,
+            &usages,
+        )
+    });
+    let system = java_manager
+        .system_java_homes()
+        .into_iter()
+        .map(|java_home| {
+            let install_dir = java_home.clone();
+            describe(java_home, &install_dir, None, JavaSource::System, &usages)
+        });
+
+    Ok(managed.chain(system).collect())
+}
+
+/// Lista los runtimes gestionados por el launcher y los JVM del sistema
+#[tauri::command]
+pub async fn get_java_installations() -> Result<Vec<JavaInstallation>, String> {
+    task::spawn_blocking(list_installations)
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
+/// Elimina un runtime gestionado. Se rechaza si alguna instancia lo tiene fijado o lo está
+/// usando en ese momento; las demás lo volverán a instalar al lanzarse si lo necesitan.
+#[tauri::command]
+pub async fn remove_java_runtime(id: String) -> Result<(), String> {
+    task::spawn_blocking(move || {
+        let java_manager = java_manager()?;
+        let runtime = java_manager
+            .managed_runtimes()
+            .into_iter()
+            .find(|runtime| runtime.id == id)
+            .ok_or_else(|| format!("El runtime {} no está instalado", id))?;
+
+        let pinned_by: Vec<String> = get_all_instances()?
+            .into_iter()
+            .filter(|instance| {
+                instance
+                    .java_override()
+                    .is_some_and(|java_path| same_path(Path::new(java_path), &runtime.java_home))
+            })
+            .map(|instance| instance.instanceName)
+            .collect();
+        if !pinned_by.is_empty() {
+            return Err(format!(
+                "El runtime {} está fijado en: {}",
+                id,
+                pinned_by.join(", ")
+            ));
+        }
+
+        let running_on =
+            running_instances::java_users(|java_home| same_path(java_home, &runtime.java_home))?;
+        if !running_on.is_empty() {
+            return Err(format!(
+                "El runtime {} está en uso por: {}",
+                id,
+                running_on.join(", ")
+            ));
+        }
+
+        java_manager
+            .remove_runtime(&id)
+            .map_err(|e| format!("Error al eliminar el runtime {}: {}", id, e))?;
+        log::info!("Removed Java runtime {}", id);
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
+
+/// Vuelve a comprobar una instalación ejecutando `java -version`
+#[tauri::command]
+pub async fn verify_java_installation(path: String) -> Result<JavaProbe, String> {
+    task::spawn_blocking(move || probe_java(Path::new(&path)))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_java_properties() {
+        let java_17 = "Property settings:\n    file.encoding = UTF-8\n    java.runtime.version = 17.0.8+7\n    java.specification.version = 17\n    java.vendor = Eclipse Adoptium\n    java.version = 17.0.8\n    os.arch = amd64\n\nopenjdk version \"17.0.8\" 2023-07-18\n";
+        assert_eq!(
+            parse_java_properties(java_17),
+            JavaProbe {
+                version: Some("17.0.8+7".into()),
+                major_version: Some(17),
+                vendor: Some("Eclipse Adoptium".into()),
+                arch: Some("amd64".into()),
+            }
+        );
+
+        let java_8 = "    java.specification.version = 1.8\n    java.version = 1.8.0_382\n    os.arch = aarch64\n";
+        let probe = parse_java_properties(java_8);
+        assert_eq!(probe.major_version, Some(8));
+        assert_eq!(probe.version.as_deref(), Some("1.8.0_382"));
+        assert_eq!(probe.vendor, None);
+
+        assert_eq!(
+            parse_java_properties("Error: could not find libjava.so"),
+            JavaProbe::default()
+        );
+    }
+
+    #[test]
+    fn test_dir_size_and_missing_java() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("lib/server")).unwrap();
+        fs::write(dir.path().join("release"), "12345").unwrap();
+        fs::write(dir.path().join("lib/server/libjvm.so"), "1234567890").unwrap();
+        assert_eq!(dir_size(dir.path()), 15);
+
+        assert!(probe_java(dir.path()).is_err());
+    }
+}
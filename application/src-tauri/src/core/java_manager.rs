@@ -1,11 +1,18 @@
+use crate::core::mojang_runtime::{default_component, install_runtime};
+use crate::core::tasks_manager::{add_task, is_task_cancelled, update_task, TaskStatus};
+use crate::utils::safe_path::{
+    is_archive_root_entry, resolve_link_target, resolve_within, sanitize_relative_path,
+};
 use anyhow::{anyhow, Context, Result};
 use dirs;
 use flate2::read::GzDecoder;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs::{self, create_dir_all, File};
-use std::io::{self, copy, Cursor, Read, Write};
+use std::io::{self, copy, Read, Write};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tar::Archive;
 use tauri_plugin_http::reqwest;
 use zip::ZipArchive;
@@ -17,15 +24,154 @@ pub struct JavaVersion {
     pub major_version: u8,
 }
 
+/// Origen de los runtimes de Java que descarga el launcher
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JavaProvider {
+    /// Builds de Temurin desde la API de Adoptium, instalados en `java{N}`
+    Adoptium,
+    /// Runtimes `java-runtime-*` del launcher oficial, instalados con el nombre del componente
+    Mojang,
+}
+
+impl JavaProvider {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "mojang" => JavaProvider::Mojang,
+            _ => JavaProvider::Adoptium,
+        }
+    }
+}
+
+/// Paquete de Adoptium (`binary.package` en la API) que se descarga para una versión
+#[derive(Debug, Clone, Deserialize)]
+struct JavaPackage {
+    link: String,
+    /// SHA-256 del archivo
+    checksum: Option<String>,
+    checksum_link: Option<String>,
+    size: Option<u64>,
+}
+
+/// Parte del progreso de la tarea que corresponde a la descarga; el resto es
+/// verificación y extracción
+const DOWNLOAD_PROGRESS_SHARE: f32 = 0.9;
+
+/// Bytes descargados, velocidad y tiempo restante de una descarga, publicados como
+/// mucho cada `PROGRESS_INTERVAL` para no saturar al frontend
+struct DownloadProgress {
+    total: u64,
+    downloaded: u64,
+    started: Instant,
+    last_report: Option<Instant>,
+}
+
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+impl DownloadProgress {
+    fn new(total: u64) -> Self {
+        Self {
+            total,
+            downloaded: 0,
+            started: Instant::now(),
+            last_report: None,
+        }
+    }
+
+    /// Suma los bytes recibidos; devuelve `true` cuando toca publicar el progreso
+    fn advance(&mut self, bytes: u64) -> bool {
+        self.downloaded += bytes;
+        let now = Instant::now();
+        let due = self
+            .last_report
+            .is_none_or(|last| now.duration_since(last) >= PROGRESS_INTERVAL);
+        if due || self.downloaded == self.total {
+            self.last_report = Some(now);
+            return true;
+        }
+        false
+    }
+
+    /// Porcentaje descargado (0 si el servidor no informa el tamaño)
+    fn percentage(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.downloaded as f64 / self.total as f64 * 100.0).min(100.0) as f32
+    }
+
+    fn bytes_per_second(&self) -> u64 {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        if elapsed <= 0.0 {
+            return 0;
+        }
+        (self.downloaded as f64 / elapsed) as u64
+    }
+
+    /// Segundos restantes estimados según la velocidad media
+    fn eta_seconds(&self) -> Option<u64> {
+        let speed = self.bytes_per_second();
+        if self.total == 0 || speed == 0 {
+            return None;
+        }
+        Some(self.total.saturating_sub(self.downloaded) / speed)
+    }
+
+    fn message(&self, version: u8) -> String {
+        let downloaded_mb = self.downloaded as f64 / 1_048_576.0;
+        match self.eta_seconds() {
+            Some(eta) => format!(
+                "Descargando Java {}: {:.1} de {:.1} MB ({} s restantes)",
+                version,
+                downloaded_mb,
+                self.total as f64 / 1_048_576.0,
+                eta
+            ),
+            None => format!("Descargando Java {}: {:.1} MB", version, downloaded_mb),
+        }
+    }
+
+    fn data(&self) -> serde_json::Value {
+        serde_json::json!({
+            "downloadedBytes": self.downloaded,
+            "totalBytes": self.total,
+            "bytesPerSecond": self.bytes_per_second(),
+            "etaSeconds": self.eta_seconds()
+        })
+    }
+}
+
+/// Runtime instalado por el launcher dentro de `_java_versions`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManagedRuntime {
+    /// Nombre del directorio: `java{N}` (Adoptium) o el componente de Mojang
+    pub id: String,
+    pub dir: PathBuf,
+    pub java_home: PathBuf,
+}
+
 // Estructura principal del JavaManager
 pub struct JavaManager {
     // Directorio base para las versiones de Java
     base_path: PathBuf,
+    // Proveedor configurado para descargar nuevas versiones
+    provider: JavaProvider,
 }
 
 impl JavaManager {
     /// Inicializa un nuevo JavaManager con el directorio base configurado
     pub fn new() -> Result<Self> {
+        let provider = crate::config::get_config_manager()
+            .lock()
+            .ok()
+            .and_then(|config| config.as_ref().ok().map(|c| c.get_java_provider()))
+            .unwrap_or(JavaProvider::Adoptium);
+
+        Self::with_provider(provider)
+    }
+
+    /// Igual que `new`, para quien ya tiene la configuración bloqueada
+    pub fn with_provider(provider: JavaProvider) -> Result<Self> {
         let config_path = dirs::config_dir()
             .ok_or_else(|| anyhow!("No se pudo obtener el directorio de configuración"))?
             .join("dev.alexitoo.modpackstore")
@@ -39,15 +185,47 @@ impl JavaManager {
 
         Ok(JavaManager {
             base_path: config_path,
+            provider,
         })
     }
 
     /// Obtiene la ruta al ejecutable de Java para una versión específica
     /// Si la versión no está instalada, la descarga
     pub async fn get_java_path(&self, major_version: &str) -> Result<PathBuf> {
+        self.get_java_runtime(major_version, None).await
+    }
+
+    /// Igual que `get_java_path`, pero con el componente (`javaVersion.component`) que
+    /// pide la versión de Minecraft, usado por el proveedor de Mojang
+    pub async fn get_java_runtime(
+        &self,
+        major_version: &str,
+        component: Option<&str>,
+    ) -> Result<PathBuf> {
         let version_num = major_version
             .parse::<u8>()
             .context("La versión de Java no es un número válido")?;
+
+        if self.provider == JavaProvider::Mojang {
+            let component = component
+                .filter(|component| is_valid_component(component))
+                .or_else(|| default_component(version_num));
+            match component {
+                Some(component) => match self.get_mojang_runtime(component).await {
+                    Ok(java_home) => return Ok(java_home),
+                    Err(e) => log::warn!(
+                        "Mojang runtime {} unavailable, falling back to Adoptium: {}",
+                        component,
+                        e
+                    ),
+                },
+                None => log::warn!(
+                    "No Mojang runtime known for Java {}, falling back to Adoptium",
+                    version_num
+                ),
+            }
+        }
+
         let version_dir = self.base_path.join(format!("java{}", major_version));
 
         // Comprobar si la versión ya está instalada
@@ -59,6 +237,43 @@ impl JavaManager {
         Ok(self.get_java_directory(major_version))
     }
 
+    /// Runtime ya instalado que sirve para la versión pedida, sin descargar nada.
+    /// Se prefiere el del proveedor configurado y luego el de Adoptium.
+    pub fn installed_runtime(
+        &self,
+        major_version: &str,
+        component: Option<&str>,
+    ) -> Option<PathBuf> {
+        let version_num = major_version.parse::<u8>().ok()?;
+        let mojang_home = if self.provider == JavaProvider::Mojang {
+            component
+                .filter(|component| is_valid_component(component))
+                .or_else(|| default_component(version_num))
+                .map(|component| mojang_java_home(&self.base_path.join(component)))
+        } else {
+            None
+        };
+
+        mojang_home
+            .into_iter()
+            .chain(std::iter::once(self.get_java_directory(major_version)))
+            .find(|java_home| self.is_java_installed(java_home))
+    }
+
+    /// Instala (si hace falta) un runtime de Mojang y devuelve su directorio home
+    async fn get_mojang_runtime(&self, component: &str) -> Result<PathBuf> {
+        let runtime_dir = self.base_path.join(component);
+        if !self.is_java_installed(&mojang_java_home(&runtime_dir)) {
+            install_runtime(component, &runtime_dir).await?;
+        }
+
+        let java_home = mojang_java_home(&runtime_dir);
+        if !self.is_java_installed(&java_home) {
+            return Err(anyhow!("La instalación del runtime {} falló", component));
+        }
+        Ok(java_home)
+    }
+
     /// Comprueba si Java está instalado en el directorio especificado
     fn is_java_installed(&self, version_dir: &PathBuf) -> bool {
         if !version_dir.exists() {
@@ -94,39 +309,108 @@ impl JavaManager {
         }
     }
 
-    /// Descarga e instala la versión de Java especificada
+    /// Descarga e instala la versión de Java especificada. El progreso se publica como una
+    /// tarea cancelable; la extracción se hace en un directorio `.part` que se descarta si
+    /// algo falla, así nunca queda una instalación a medias en `target_dir`.
     async fn download_java(&self, version: u8, target_dir: &PathBuf) -> Result<()> {
-        // Determinar la URL de descarga según la plataforma y arquitectura
-        let download_url = self.get_download_url(version).await?;
+        let task_id = add_task(
+            &format!("Descargando Java {}", version),
+            Some(serde_json::json!({
+                "type": "java_download",
+                "javaVersion": version,
+                "cancellable": true
+            })),
+        );
+        update_task(
+            &task_id,
+            TaskStatus::Running,
+            0.0,
+            &format!("Buscando Java {}", version),
+            None,
+        );
 
-        println!("Descargando Java {} desde {}", version, download_url);
+        let staging_dir = staging_dir_for(target_dir);
+        let result = self
+            .install_adoptium(version, &staging_dir, target_dir, &task_id)
+            .await;
 
-        // Crear el directorio si no existe
-        if !target_dir.exists() {
-            create_dir_all(target_dir)
-                .context("No se pudo crear el directorio para la versión de Java")?;
+        if staging_dir.exists() {
+            if let Err(e) = fs::remove_dir_all(&staging_dir) {
+                log::warn!("Could not clean up {}: {}", staging_dir.display(), e);
+            }
         }
 
+        match &result {
+            Ok(()) => {
+                log::info!("Java {} installed in {}", version, target_dir.display());
+                update_task(
+                    &task_id,
+                    TaskStatus::Completed,
+                    100.0,
+                    &format!("Java {} instalado correctamente", version),
+                    None,
+                );
+            }
+            // La tarea ya quedó como cancelada
+            Err(_) if is_task_cancelled(&task_id) => {
+                log::info!("Java {} download cancelled", version);
+            }
+            Err(e) => {
+                log::error!("Java {} installation failed: {}", version, e);
+                update_task(
+                    &task_id,
+                    TaskStatus::Failed,
+                    0.0,
+                    &format!("Error al instalar Java {}: {}", version, e),
+                    None,
+                );
+            }
+        }
+
+        result
+    }
+
+    async fn install_adoptium(
+        &self,
+        version: u8,
+        staging_dir: &PathBuf,
+        target_dir: &PathBuf,
+        task_id: &str,
+    ) -> Result<()> {
+        // Determinar el paquete según la plataforma y arquitectura
+        let package = self.get_package(version).await?;
+
         // Obtener la extensión del archivo desde la URL
-        let extension = if download_url.ends_with(".zip") {
+        let extension = if package.link.ends_with(".zip") {
             "zip"
-        } else if download_url.ends_with(".tar.gz") {
+        } else if package.link.ends_with(".tar.gz") {
             "tar.gz"
         } else {
-            return Err(anyhow!("Formato de archivo no soportado: {}", download_url));
+            return Err(anyhow!("Formato de archivo no soportado: {}", package.link));
         };
 
-        // Crear el archivo temporal con la extensión adecuada
-        let temp_file = target_dir.join(format!("java_temp_archive.{}", extension));
-
         // Crear un cliente con tiempo de espera personalizado
         let client = reqwest::Client::builder()
             .timeout(std::time::Duration::from_secs(300)) // 5 minutos
             .build()?;
 
+        let expected_sha256 = self.get_package_checksum(&client, &package).await?;
+
+        if staging_dir.exists() {
+            fs::remove_dir_all(staging_dir)
+                .context("No se pudo limpiar una instalación anterior incompleta")?;
+        }
+        create_dir_all(staging_dir)
+            .context("No se pudo crear el directorio para la versión de Java")?;
+
+        // Crear el archivo temporal con la extensión adecuada
+        let temp_file = staging_dir.join(format!("java_temp_archive.{}", extension));
+
+        log::info!("Downloading Java {} from {}", version, package.link);
+
         // Iniciar la descarga
         let response = client
-            .get(&download_url)
+            .get(&package.link)
             .send()
             .await
             .context("Error al iniciar la descarga de Java")?;
@@ -135,52 +419,129 @@ impl JavaManager {
             return Err(anyhow!("Error al descargar Java: {}", response.status()));
         }
 
-        let total_size = response.content_length().unwrap_or(0);
-        println!("Tamaño total: {} bytes", total_size);
+        let total_size = response.content_length().or(package.size).unwrap_or(0);
 
         // Preparar archivo para guardar
         let mut file = File::create(&temp_file).context("No se pudo crear el archivo temporal")?;
-        let mut downloaded: u64 = 0;
+        let mut hasher = Sha256::new();
+        let mut progress = DownloadProgress::new(total_size);
         let mut stream = response.bytes_stream();
 
-        // Descargar el archivo mostrando progreso
         while let Some(chunk) = stream.next().await {
-            let chunk = chunk.context("Error al descargar fragmento")?;
-            io::copy(&mut Cursor::new(&chunk), &mut file).context("Error al escribir fragmento")?;
-
-            downloaded += chunk.len() as u64;
+            if is_task_cancelled(task_id) {
+                return Err(anyhow!("Descarga de Java {} cancelada", version));
+            }
 
-            if total_size > 0 {
-                let progress = (downloaded as f64 / total_size as f64) * 100.0;
-                println!(
-                    "Descargado: {:.2}% ({}/{} bytes)",
-                    progress, downloaded, total_size
+            let chunk = chunk.context("Error al descargar fragmento")?;
+            file.write_all(&chunk)
+                .context("Error al escribir fragmento")?;
+            hasher.update(&chunk);
+
+            if progress.advance(chunk.len() as u64) {
+                update_task(
+                    task_id,
+                    TaskStatus::Running,
+                    progress.percentage() * DOWNLOAD_PROGRESS_SHARE,
+                    &progress.message(version),
+                    Some(progress.data()),
                 );
-            } else {
-                println!("Descargado: {} bytes", downloaded);
             }
         }
+        drop(file);
+
+        update_task(
+            task_id,
+            TaskStatus::Running,
+            DOWNLOAD_PROGRESS_SHARE * 100.0,
+            &format!("Verificando Java {}", version),
+            Some(progress.data()),
+        );
+        let actual_sha256 = format!("{:x}", hasher.finalize());
+        if !actual_sha256.eq_ignore_ascii_case(&expected_sha256) {
+            return Err(anyhow!(
+                "El checksum de Java {} no coincide (esperado {}, obtenido {})",
+                version,
+                expected_sha256,
+                actual_sha256
+            ));
+        }
 
-        println!("Descarga completada. Extrayendo...");
+        if is_task_cancelled(task_id) {
+            return Err(anyhow!("Descarga de Java {} cancelada", version));
+        }
+        update_task(
+            task_id,
+            TaskStatus::Running,
+            95.0,
+            &format!("Extrayendo Java {}", version),
+            None,
+        );
 
         // Extraer el archivo según su tipo
-        self.extract_java_archive(&temp_file, target_dir)?;
+        self.extract_java_archive(&temp_file, staging_dir)?;
 
         // Eliminar el archivo temporal
         fs::remove_file(&temp_file).context("No se pudo eliminar el archivo temporal")?;
 
         // Verificar que la instalación fue correcta
-        if !self.is_java_installed(target_dir) {
+        if !self.is_java_installed(staging_dir) {
             return Err(anyhow!("La instalación de Java {} falló", version));
         }
 
-        println!("Java {} instalado correctamente", version);
+        // Reemplazar lo que hubiera de un intento anterior por la instalación nueva
+        if target_dir.exists() {
+            fs::remove_dir_all(target_dir)
+                .context("No se pudo eliminar la instalación anterior de Java")?;
+        }
+        fs::rename(staging_dir, target_dir).context("No se pudo mover la instalación de Java")?;
+
         Ok(())
     }
 
+    /// SHA-256 que publica Adoptium para el paquete, descargando el `.sha256.txt` si la
+    /// API no lo incluye
+    async fn get_package_checksum(
+        &self,
+        client: &reqwest::Client,
+        package: &JavaPackage,
+    ) -> Result<String> {
+        if let Some(checksum) = package.checksum.as_deref().filter(|c| is_sha256(c)) {
+            return Ok(checksum.to_lowercase());
+        }
+
+        let checksum_link = package
+            .checksum_link
+            .clone()
+            .unwrap_or_else(|| format!("{}.sha256.txt", package.link));
+        let response = client
+            .get(&checksum_link)
+            .send()
+            .await
+            .context("Error al descargar el checksum de Java")?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "No se pudo obtener el checksum de Java ({}): {}",
+                checksum_link,
+                response.status()
+            ));
+        }
+
+        let content = response
+            .text()
+            .await
+            .context("Error al leer el checksum de Java")?;
+        parse_sha256_file(&content)
+            .ok_or_else(|| anyhow!("Checksum de Java inválido en {}", checksum_link))
+    }
+
     /// Determina la URL de descarga de OpenJDK según la plataforma, arquitectura y versión
     /// Usa la API de Adoptium para obtener la URL de descarga más reciente
     pub async fn get_download_url(&self, version: u8) -> Result<String> {
+        Ok(self.get_package(version).await?.link)
+    }
+
+    /// Paquete más reciente de Adoptium para la plataforma, con su checksum
+    async fn get_package(&self, version: u8) -> Result<JavaPackage> {
         #[derive(Debug, Deserialize)]
         struct Asset {
             binary: Binary,
@@ -188,12 +549,7 @@ impl JavaManager {
 
         #[derive(Debug, Deserialize)]
         struct Binary {
-            package: Package,
-        }
-
-        #[derive(Debug, Deserialize)]
-        struct Package {
-            link: String,
+            package: JavaPackage,
         }
 
         let os = if cfg!(target_os = "windows") {
@@ -219,7 +575,7 @@ impl JavaManager {
             version, os, arch
         );
 
-        println!("Consultando API de Adoptium: {}", api_url);
+        log::info!("Querying Adoptium API: {}", api_url);
 
         let response = reqwest::get(&api_url)
             .await
@@ -237,8 +593,8 @@ impl JavaManager {
             .await
             .context("Error al parsear la respuesta de la API")?;
 
-        if let Some(asset) = assets.first() {
-            Ok(asset.binary.package.link.clone())
+        if let Some(asset) = assets.into_iter().next() {
+            Ok(asset.binary.package)
         } else {
             let fallback_url = match os {
                 "windows" => format!(
@@ -256,12 +612,18 @@ impl JavaManager {
                 _ => return Err(anyhow!("Sistema operativo no soportado")),
             };
 
-            println!(
-                "No se encontraron binarios en la API, usando URL predeterminada: {}",
+            log::warn!(
+                "No binaries returned by the Adoptium API, using default URL: {}",
                 fallback_url
             );
 
-            Ok(fallback_url)
+            // Las releases de GitHub publican el checksum junto al archivo
+            Ok(JavaPackage {
+                link: fallback_url,
+                checksum: None,
+                checksum_link: None,
+                size: None,
+            })
         }
     }
 
@@ -294,10 +656,11 @@ impl JavaManager {
             let mut file = archive
                 .by_index(i)
                 .context("No se pudo acceder al archivo en el ZIP")?;
-            let outpath = match file.enclosed_name() {
-                Some(path) => target_dir.join(path),
-                None => continue,
-            };
+            if is_archive_root_entry(file.name()) {
+                continue;
+            }
+            let outpath = resolve_within(target_dir, file.name())
+                .map_err(|e| anyhow!("Entrada insegura en el archivo ZIP: {}", e))?;
 
             if file.name().ends_with('/') {
                 fs::create_dir_all(&outpath)
@@ -336,9 +699,41 @@ impl JavaManager {
         let gz_decoder = GzDecoder::new(file);
         let mut archive = Archive::new(gz_decoder);
 
-        archive
-            .unpack(target_dir)
-            .context("No se pudo extraer el archivo tar.gz")?;
+        create_dir_all(target_dir).context("No se pudo crear el directorio de destino")?;
+
+        // Cada entrada (y el destino de cada enlace) se valida antes de escribir nada
+        for entry in archive
+            .entries()
+            .context("No se pudo leer el archivo tar.gz")?
+        {
+            let mut entry = entry.context("No se pudo leer una entrada del archivo tar.gz")?;
+            let entry_name = String::from_utf8_lossy(&entry.path_bytes()).to_string();
+            if is_archive_root_entry(&entry_name) {
+                continue;
+            }
+
+            let entry_relative = sanitize_relative_path(&entry_name)
+                .map_err(|e| anyhow!("Entrada insegura en el archivo tar.gz: {}", e))?;
+            resolve_within(target_dir, &entry_name)
+                .map_err(|e| anyhow!("Entrada insegura en el archivo tar.gz: {}", e))?;
+
+            if let Some(link_name) = entry
+                .link_name()
+                .context("No se pudo leer el destino de un enlace")?
+            {
+                let link_check = if entry.header().entry_type().is_hard_link() {
+                    // Los enlaces duros se expresan relativos a la raíz del archivo
+                    resolve_within(target_dir, &link_name.to_string_lossy())
+                } else {
+                    resolve_link_target(target_dir, &entry_relative, &link_name)
+                };
+                link_check.map_err(|e| anyhow!("Enlace inseguro en el archivo tar.gz: {}", e))?;
+            }
+
+            entry
+                .unpack_in(target_dir)
+                .context("No se pudo extraer el archivo tar.gz")?;
+        }
 
         // En sistemas Unix, restaurar permisos de ejecución
         #[cfg(unix)]
@@ -455,83 +850,96 @@ impl JavaManager {
     /// Busca instalaciones de Java en ubicaciones comunes del sistema
     /// Retorna la primera instalación funcional encontrada
     pub fn scan_local_java_installations(&self) -> Result<Option<String>, String> {
-        let mut search_paths = Vec::new();
-
-        // Agregar rutas comunes según el sistema operativo
-        #[cfg(target_os = "windows")]
-        {
-            if let Ok(program_files) = std::env::var("ProgramFiles") {
-                search_paths.push(std::path::PathBuf::from(program_files.clone()).join("Java"));
-                search_paths
-                    .push(std::path::PathBuf::from(program_files.clone()).join("Eclipse Adoptium"));
-                search_paths.push(std::path::PathBuf::from(program_files.clone()).join("OpenJDK"));
-            }
-            if let Ok(program_files_x86) = std::env::var("ProgramFiles(x86)") {
-                search_paths.push(std::path::PathBuf::from(program_files_x86.clone()).join("Java"));
-                search_paths.push(
-                    std::path::PathBuf::from(program_files_x86.clone()).join("Eclipse Adoptium"),
-                );
-                search_paths
-                    .push(std::path::PathBuf::from(program_files_x86.clone()).join("OpenJDK"));
-            }
-        }
-
-        #[cfg(target_os = "macos")]
-        {
-            search_paths.push(std::path::PathBuf::from(
-                "/Library/Java/JavaVirtualMachines",
-            ));
-            search_paths.push(std::path::PathBuf::from(
-                "/System/Library/Java/JavaVirtualMachines",
-            ));
-            if let Some(home) = dirs::home_dir() {
-                search_paths.push(home.join("Library/Java/JavaVirtualMachines"));
+        for candidate in local_java_candidates() {
+            // Verificar si esta ruta contiene una instalación funcional de Java
+            if let Ok(true) = self.validate_configured_java(&candidate.to_string_lossy()) {
+                return Ok(Some(candidate.to_string_lossy().to_string()));
             }
         }
 
-        #[cfg(target_os = "linux")]
-        {
-            search_paths.push(std::path::PathBuf::from("/usr/lib/jvm"));
-            search_paths.push(std::path::PathBuf::from("/usr/java"));
-            search_paths.push(std::path::PathBuf::from("/opt/java"));
-            search_paths.push(std::path::PathBuf::from("/opt/openjdk"));
-            if let Some(home) = dirs::home_dir() {
-                search_paths.push(home.join(".sdkman/candidates/java"));
-                search_paths.push(home.join(".jenv/versions"));
-            }
-        }
+        Ok(None)
+    }
 
-        // Buscar en cada ruta
-        for search_path in search_paths {
-            if let Ok(entries) = std::fs::read_dir(&search_path) {
-                for entry in entries.flatten() {
-                    let path = entry.path();
-                    if path.is_dir() {
-                        // Verificar si esta ruta contiene una instalación funcional de Java
-                        if let Ok(true) = self.validate_configured_java(&path.to_string_lossy()) {
-                            return Ok(Some(path.to_string_lossy().to_string()));
-                        }
+    /// Runtimes instalados por el launcher que tienen un ejecutable de Java
+    pub fn managed_runtimes(&self) -> Vec<ManagedRuntime> {
+        let Ok(entries) = fs::read_dir(&self.base_path) else {
+            return Vec::new();
+        };
 
-                        // En macOS, las instalaciones están en Contents/Home
-                        #[cfg(target_os = "macos")]
-                        {
-                            let macos_java_home = path.join("Contents/Home");
-                            if macos_java_home.exists() {
-                                if let Ok(true) = self
-                                    .validate_configured_java(&macos_java_home.to_string_lossy())
-                                {
-                                    return Ok(Some(macos_java_home.to_string_lossy().to_string()));
-                                }
-                            }
-                        }
-                    }
+        let mut runtimes: Vec<ManagedRuntime> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| {
+                let id = entry.file_name().to_string_lossy().to_string();
+                // Descartar instalaciones a medias (`.part`) y directorios temporales
+                if !is_valid_component(&id) {
+                    return None;
                 }
-            }
+                let java_home = mojang_java_home(&entry.path());
+                self.is_java_installed(&java_home).then(|| ManagedRuntime {
+                    id,
+                    dir: entry.path(),
+                    java_home,
+                })
+            })
+            .collect();
+        runtimes.sort_by(|a, b| a.id.cmp(&b.id));
+        runtimes
+    }
+
+    /// Elimina un runtime instalado por el launcher, identificado por su directorio
+    pub fn remove_runtime(&self, id: &str) -> Result<()> {
+        if !is_valid_component(id) {
+            return Err(anyhow!("Identificador de runtime inválido: {}", id));
+        }
+        let runtime_dir = self.base_path.join(id);
+        if !runtime_dir.is_dir() {
+            return Err(anyhow!("El runtime {} no está instalado", id));
         }
 
-        Ok(None)
+        fs::remove_dir_all(&runtime_dir)
+            .with_context(|| format!("No se pudo eliminar {}", runtime_dir.display()))
     }
 
+    /// Homes de Java instalados en el sistema (JAVA_HOME, PATH y ubicaciones comunes),
+    /// sin duplicados y sin los que gestiona el launcher
+    pub fn system_java_homes(&self) -> Vec<PathBuf> {
+        let java_command = if cfg!(target_os = "windows") {
+            "java.exe"
+        } else {
+            "java"
+        };
+
+        let mut candidates = Vec::new();
+        if let Ok(java_home) = std::env::var("JAVA_HOME") {
+            candidates.push(PathBuf::from(java_home));
+        }
+        // En Linux `/usr/bin/java` suele ser un enlace a la instalación real
+        if let Some(java_home) = which::which(java_command)
+            .ok()
+            .and_then(|java_exe| fs::canonicalize(java_exe).ok())
+            .and_then(|java_exe| java_exe.parent()?.parent().map(PathBuf::from))
+        {
+            candidates.push(java_home);
+        }
+        candidates.extend(local_java_candidates());
+
+        let managed_root = fs::canonicalize(&self.base_path).unwrap_or(self.base_path.clone());
+        let mut seen = Vec::new();
+        let mut homes = Vec::new();
+        for candidate in candidates {
+            if !candidate.join("bin").join(java_command).is_file() {
+                continue;
+            }
+            let canonical = fs::canonicalize(&candidate).unwrap_or(candidate.clone());
+            if canonical.starts_with(&managed_root) || seen.contains(&canonical) {
+                continue;
+            }
+            seen.push(canonical);
+            homes.push(candidate);
+        }
+        homes
+    }
     /// Verifica si Java está disponible en el sistema
     /// Prioriza JAVA_HOME, luego busca en PATH
     pub fn validate_system_java(&self) -> Result<Option<String>, String> {
@@ -665,6 +1073,297 @@ impl JavaManager {
     }
 }
 
+/// Directorios donde se suelen instalar JVMs según el sistema operativo
+fn system_search_paths() -> Vec<PathBuf> {
+    let mut search_paths = Vec::new();
+
+    #[cfg(target_os = "windows")]
+    {
+        for variable in ["ProgramFiles", "ProgramFiles(x86)"] {
+            if let Ok(program_files) = std::env::var(variable) {
+                let program_files = PathBuf::from(program_files);
+                search_paths.push(program_files.join("Java"));
+                search_paths.push(program_files.join("Eclipse Adoptium"));
+                search_paths.push(program_files.join("OpenJDK"));
+            }
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        search_paths.push(PathBuf::from("/Library/Java/JavaVirtualMachines"));
+        search_paths.push(PathBuf::from("/System/Library/Java/JavaVirtualMachines"));
+        if let Some(home) = dirs::home_dir() {
+            search_paths.push(home.join("Library/Java/JavaVirtualMachines"));
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        search_paths.push(PathBuf::from("/usr/lib/jvm"));
+        search_paths.push(PathBuf::from("/usr/java"));
+        search_paths.push(PathBuf::from("/opt/java"));
+        search_paths.push(PathBuf::from("/opt/openjdk"));
+        if let Some(home) = dirs::home_dir() {
+            search_paths.push(home.join(".sdkman/candidates/java"));
+            search_paths.push(home.join(".jenv/versions"));
+        }
+    }
+
+    search_paths
+}
+
+/// Posibles homes de Java dentro de las ubicaciones comunes del sistema
+fn local_java_candidates() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    for search_path in system_search_paths() {
+        let Ok(entries) = fs::read_dir(&search_path) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            // En macOS, las instalaciones están en Contents/Home
+            let macos_java_home = path.join("Contents/Home");
+            candidates.push(path);
+            if cfg!(target_os = "macos") && macos_java_home.exists() {
+                candidates.push(macos_java_home);
+            }
+        }
+    }
+    candidates
+}
+
+/// Directorio hermano donde se extrae una instalación antes de darla por buena
+fn staging_dir_for(target_dir: &std::path::Path) -> PathBuf {
+    let mut name = target_dir
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(".part");
+    target_dir.with_file_name(name)
+}
+
+fn is_sha256(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Lee un `.sha256.txt` (`<hash>  <archivo>`, o solo el hash)
+fn parse_sha256_file(content: &str) -> Option<String> {
+    content
+        .split_whitespace()
+        .next()
+        .filter(|hash| is_sha256(hash))
+        .map(str::to_lowercase)
+}
+
+/// Los runtimes de Mojang para macOS traen el JDK dentro de `jre.bundle`
+fn mojang_java_home(runtime_dir: &std::path::Path) -> PathBuf {
+    let bundle_home = runtime_dir.join("jre.bundle").join("Contents").join("Home");
+    if bundle_home.is_dir() {
+        bundle_home
+    } else {
+        runtime_dir.to_path_buf()
+    }
+}
+
+/// El componente viene del JSON de la versión y se usa como nombre de directorio
+fn is_valid_component(component: &str) -> bool {
+    !component.is_empty()
+        && component
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn manager(base: &Path) -> JavaManager {
+        JavaManager {
+            base_path: base.to_path_buf(),
+            provider: JavaProvider::Adoptium,
+        }
+    }
+
+    /// Writes a tar.gz whose entry names are copied verbatim (tar::Builder refuses `..`)
+    fn write_raw_tar_gz(path: &Path, entries: &[(&str, tar::EntryType, &str)]) {
+        let encoder = flate2::write::GzEncoder::new(
+            File::create(path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        for (name, entry_type, content_or_link) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.as_old_mut().name[..name.len()].copy_from_slice(name.as_bytes());
+            header.set_entry_type(*entry_type);
+            header.set_mode(0o644);
+            if entry_type.is_symlink() {
+                header.set_link_name(content_or_link).unwrap();
+                header.set_size(0);
+                header.set_cksum();
+                builder.append(&header, io::empty()).unwrap();
+            } else {
+                header.set_size(content_or_link.len() as u64);
+                header.set_cksum();
+                builder.append(&header, content_or_link.as_bytes()).unwrap();
+            }
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn test_zip_slip_entries_are_rejected() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("jdk.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&archive).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file("jdk-17/bin/java.exe", options).unwrap();
+        writer.write_all(b"java").unwrap();
+        writer.start_file("../../evil.dll", options).unwrap();
+        writer.write_all(b"evil").unwrap();
+        writer.finish().unwrap();
+
+        let target = dir.path().join("runtime");
+        let result = manager(dir.path()).extract_zip(&archive, &target);
+
+        assert!(result.is_err());
+        assert!(!dir.path().join("evil.dll").exists());
+    }
+
+    #[test]
+    fn test_tar_traversal_and_escaping_symlinks_are_rejected() {
+        let dir = tempdir().unwrap();
+        let target = dir.path().join("runtime");
+
+        let traversal = dir.path().join("traversal.tar.gz");
+        write_raw_tar_gz(
+            &traversal,
+            &[("../evil.sh", tar::EntryType::Regular, "#!/bin/sh")],
+        );
+        assert!(manager(dir.path())
+            .extract_tar_gz(&traversal, &target)
+            .is_err());
+        assert!(!dir.path().join("evil.sh").exists());
+
+        let symlink = dir.path().join("symlink.tar.gz");
+        write_raw_tar_gz(
+            &symlink,
+            &[
+                ("jdk/lib", tar::EntryType::Symlink, "../../../outside"),
+                ("jdk/lib/evil.so", tar::EntryType::Regular, "evil"),
+            ],
+        );
+        assert!(manager(dir.path())
+            .extract_tar_gz(&symlink, &target)
+            .is_err());
+        assert!(!dir.path().join("outside").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_regular_tar_with_internal_symlink_is_extracted() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("jdk.tar.gz");
+        write_raw_tar_gz(
+            &archive,
+            &[
+                ("./", tar::EntryType::Directory, ""),
+                (
+                    "jdk-17/legal/java.base/LICENSE",
+                    tar::EntryType::Regular,
+                    "GPL",
+                ),
+                (
+                    "jdk-17/legal/java.desktop/LICENSE",
+                    tar::EntryType::Symlink,
+                    "../java.base/LICENSE",
+                ),
+            ],
+        );
+
+        let target = dir.path().join("runtime");
+        manager(dir.path())
+            .extract_tar_gz(&archive, &target)
+            .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(target.join("jdk-17/legal/java.desktop/LICENSE")).unwrap(),
+            "GPL"
+        );
+    }
+
+    #[test]
+    fn test_parse_sha256_file() {
+        let hash = "AB".repeat(32);
+        assert_eq!(
+            parse_sha256_file(&format!(
+                "{}  OpenJDK17U-jdk_x64_linux_hotspot_17.0.8_7.tar.gz\n",
+                hash
+            )),
+            Some(hash.to_lowercase())
+        );
+        assert_eq!(parse_sha256_file(&hash), Some(hash.to_lowercase()));
+        assert_eq!(parse_sha256_file("<html>Not Found</html>"), None);
+        assert_eq!(parse_sha256_file(""), None);
+    }
+
+    #[test]
+    fn test_download_progress() {
+        let mut progress = DownloadProgress::new(1000);
+        assert!(progress.advance(250));
+        // Throttled until the interval passes, except for the final chunk
+        assert!(!progress.advance(250));
+        assert!(progress.advance(500));
+        assert_eq!(progress.percentage(), 100.0);
+        assert_eq!(progress.data()["downloadedBytes"], 1000);
+
+        let unknown_size = DownloadProgress::new(0);
+        assert_eq!(unknown_size.percentage(), 0.0);
+        assert_eq!(unknown_size.eta_seconds(), None);
+    }
+
+    #[test]
+    fn test_staging_dir_is_a_sibling() {
+        let target = Path::new("/config/_java_versions/java17");
+        assert_eq!(
+            staging_dir_for(target),
+            Path::new("/config/_java_versions/java17.part")
+        );
+        assert!(!is_valid_component("java17.part"));
+    }
+
+    #[test]
+    fn test_installed_runtime_prefers_mojang_home() {
+        let dir = tempdir().unwrap();
+        let java = if cfg!(windows) { "javaw.exe" } else { "java" };
+        let mut java_manager = manager(dir.path());
+        assert_eq!(java_manager.installed_runtime("17", None), None);
+
+        let adoptium = dir.path().join("java17");
+        fs::create_dir_all(adoptium.join("bin")).unwrap();
+        fs::write(adoptium.join("bin").join(java), "").unwrap();
+        assert_eq!(
+            java_manager.installed_runtime("17", None),
+            Some(adoptium.clone())
+        );
+
+        java_manager.provider = JavaProvider::Mojang;
+        let mojang = mojang_java_home(&dir.path().join("java-runtime-gamma"));
+        fs::create_dir_all(mojang.join("bin")).unwrap();
+        fs::write(mojang.join("bin").join(java), "").unwrap();
+        assert_eq!(java_manager.installed_runtime("17", None), Some(mojang));
+        assert_eq!(
+            java_manager.installed_runtime("17", Some("java-runtime-delta")),
+            Some(adoptium)
+        );
+    }
+}
+
 // Ejemplo de uso:
 /*
 #[tokio::main]
@@ -0,0 +1,286 @@
+// src-tauri/src/core/manifest_signature.rs
+//! Ed25519 verification of modpack manifests.
+//!
+//! The API returns the manifest together with a detached signature:
+//!
+//! ```json
+//! {
+//!   "manifest": { ... },
+//!   "signature": { "keyId": "modpackstore-2025", "algorithm": "ed25519", "value": "<base64>" }
+//! }
+//! ```
+//!
+//! The signature covers the canonical serialization of the `manifest` object: JSON with
+//! object keys sorted by their UTF-8 bytes and no insignificant whitespace. Only keys
+//! pinned in the launcher binary are trusted, so a compromised API or a MITM cannot
+//! push files to players.
+
+use crate::core::bootstrap_error::{BootstrapError, ErrorCategory};
+use base64::{engine::general_purpose, Engine as _};
+use ed25519_dalek::{Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Publisher keys are pinned at build time through `MODPACKSTORE_MANIFEST_KEYS`,
+/// a comma separated list of `keyId:base64PublicKey` entries. `build.rs` refuses to
+/// produce a release build without them.
+const PINNED_KEYS_ENV: Option<&str> = option_env!("MODPACKSTORE_MANIFEST_KEYS");
+
+/// A publisher public key trusted by the launcher
+#[derive(Debug, Clone)]
+pub struct PublisherKey {
+    pub id: String,
+    pub key: VerifyingKey,
+}
+
+/// Detached signature shipped next to the manifest
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ManifestSignature {
+    #[serde(rename = "keyId")]
+    pub key_id: String,
+    #[serde(default = "default_algorithm")]
+    pub algorithm: String,
+    pub value: String,
+}
+
+fn default_algorithm() -> String {
+    "ed25519".to_string()
+}
+
+/// Returns the publisher keys pinned in this build
+pub fn pinned_publisher_keys() -> Vec<PublisherKey> {
+    PINNED_KEYS_ENV
+        .map(parse_publisher_keys)
+        .unwrap_or_default()
+}
+
+/// Parses `keyId:base64PublicKey` entries, skipping malformed ones
+fn parse_publisher_keys(raw: &str) -> Vec<PublisherKey> {
+    raw.split(',')
+        .filter_map(|entry| {
+            let (id, encoded) = entry.trim().split_once(':')?;
+            let bytes = general_purpose::STANDARD.decode(encoded.trim()).ok()?;
+            let bytes: [u8; 32] = bytes.try_into().ok()?;
+            match VerifyingKey::from_bytes(&bytes) {
+                Ok(key) => Some(PublisherKey {
+                    id: id.trim().to_string(),
+                    key,
+                }),
+                Err(e) => {
+                    log::error!("Invalid pinned publisher key {}: {}", id, e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Serializes a JSON value with sorted object keys and no whitespace
+pub fn canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            out.push('{');
+            for (i, key) in keys.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String((*key).clone()).to_string());
+                out.push(':');
+                write_canonical(&map[*key], out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// Verifies the manifest against the keys pinned in this build
+pub fn verify_manifest_signature(
+    manifest: &Value,
+    signature: Option<&ManifestSignature>,
+) -> Result<(), BootstrapError> {
+    verify_manifest_signature_with_keys(manifest, signature, &pinned_publisher_keys())
+}
+
+/// Verifies the manifest against an explicit set of trusted keys
+pub fn verify_manifest_signature_with_keys(
+    manifest: &Value,
+    signature: Option<&ManifestSignature>,
+    trusted_keys: &[PublisherKey],
+) -> Result<(), BootstrapError> {
+    let signature = signature.ok_or_else(|| {
+        BootstrapError::signature_error("El manifiesto del modpack no está firmado")
+    })?;
+
+    if !signature.algorithm.eq_ignore_ascii_case("ed25519") {
+        return Err(BootstrapError::signature_error(format!(
+            "Algoritmo de firma no soportado: {}",
+            signature.algorithm
+        )));
+    }
+
+    let publisher = trusted_keys
+        .iter()
+        .find(|k| k.id == signature.key_id)
+        .ok_or_else(|| {
+            BootstrapError::signature_error(
+                "El manifiesto está firmado con una clave no reconocida por el launcher",
+            )
+            .with_technical_details(format!("Unknown key id: {}", signature.key_id))
+        })?;
+
+    let signature_bytes: [u8; 64] = general_purpose::STANDARD
+        .decode(signature.value.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| {
+            BootstrapError::signature_error("La firma del manifiesto está mal formada")
+                .with_technical_details("Signature is not a base64 encoded 64 byte value")
+        })?;
+
+    let message = canonical_json(manifest);
+    publisher
+        .key
+        .verify_strict(message.as_bytes(), &Signature::from_bytes(&signature_bytes))
+        .map_err(|e| {
+            BootstrapError::signature_error(
+                "La firma del manifiesto no es válida: el contenido pudo haber sido alterado",
+            )
+            .with_technical_details(format!("Key {}: {}", publisher.id, e))
+        })
+}
+
+/// Returns true when an error string carries a serialized signature failure
+pub fn is_signature_error(error: &str) -> bool {
+    serde_json::from_str::<BootstrapError>(error)
+        .map(|e| e.category == ErrorCategory::Security)
+        .unwrap_or(false)
+}
+
+/// Serializes a signature failure the way bootstrap errors travel through `Result<_, String>`
+pub fn signature_error_to_string(error: &BootstrapError) -> String {
+    serde_json::to_string(error).unwrap_or_else(|_| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use serde_json::json;
+
+    fn test_publisher() -> (SigningKey, PublisherKey) {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let publisher = PublisherKey {
+            id: "test-key".to_string(),
+            key: signing_key.verifying_key(),
+        };
+        (signing_key, publisher)
+    }
+
+    fn sample_manifest() -> Value {
+        json!({
+            "version": "1.0.0",
+            "id": "pack",
+            "mcVersion": "1.20.1",
+            "forgeVersion": null,
+            "files": [{
+                "path": "mods/example.jar",
+                "fileHash": "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+                "file": { "type": "mod", "size": 10 },
+                "downloadUrl": "https://cdn.example.com/example.jar"
+            }]
+        })
+    }
+
+    fn sign(signing_key: &SigningKey, manifest: &Value) -> ManifestSignature {
+        let signature = signing_key.sign(canonical_json(manifest).as_bytes());
+        ManifestSignature {
+            key_id: "test-key".to_string(),
+            algorithm: "ed25519".to_string(),
+            value: general_purpose::STANDARD.encode(signature.to_bytes()),
+        }
+    }
+
+    #[test]
+    fn test_canonical_json_sorts_keys_recursively() {
+        let value = json!({ "b": 1, "a": { "d": [true, null], "c": "x" } });
+        assert_eq!(
+            canonical_json(&value),
+            r#"{"a":{"c":"x","d":[true,null]},"b":1}"#
+        );
+    }
+
+    #[test]
+    fn test_valid_signature_is_accepted() {
+        let (signing_key, publisher) = test_publisher();
+        let manifest = sample_manifest();
+        let signature = sign(&signing_key, &manifest);
+
+        assert!(
+            verify_manifest_signature_with_keys(&manifest, Some(&signature), &[publisher]).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_tampered_manifest_is_rejected() {
+        let (signing_key, publisher) = test_publisher();
+        let manifest = sample_manifest();
+        let signature = sign(&signing_key, &manifest);
+
+        let mut tampered = manifest.clone();
+        tampered["files"][0]["downloadUrl"] = json!("https://evil.example.com/payload.jar");
+
+        let error = verify_manifest_signature_with_keys(&tampered, Some(&signature), &[publisher])
+            .unwrap_err();
+        assert_eq!(error.category, ErrorCategory::Security);
+        assert!(is_signature_error(&signature_error_to_string(&error)));
+    }
+
+    #[test]
+    fn test_missing_signature_or_unknown_key_is_rejected() {
+        let (signing_key, publisher) = test_publisher();
+        let manifest = sample_manifest();
+
+        assert!(verify_manifest_signature_with_keys(
+            &manifest,
+            None,
+            std::slice::from_ref(&publisher)
+        )
+        .is_err());
+
+        let mut signature = sign(&signing_key, &manifest);
+        signature.key_id = "someone-else".to_string();
+        assert!(
+            verify_manifest_signature_with_keys(&manifest, Some(&signature), &[publisher]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_publisher_keys_skips_malformed_entries() {
+        let (_, publisher) = test_publisher();
+        let encoded = general_purpose::STANDARD.encode(publisher.key.to_bytes());
+        let raw = format!("good:{}, broken:not-base64,missing-separator", encoded);
+
+        let keys = parse_publisher_keys(&raw);
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].id, "good");
+    }
+}
@@ -1,4 +1,7 @@
 use super::rules::RuleEvaluator;
+use crate::core::bootstrap::legacy_assets::game_assets_dir;
+use crate::core::bootstrap::manifest::get_logging_config_info;
+use crate::core::minecraft::launch_options::{merge_game_args, LaunchOptions};
 use crate::core::minecraft::paths::MinecraftPaths;
 use crate::core::minecraft_account::MinecraftAccount;
 use serde_json::Value;
@@ -11,6 +14,7 @@ pub struct ArgumentProcessor<'a> {
     account: &'a MinecraftAccount,
     paths: &'a MinecraftPaths,
     memory: u32,
+    options: Option<&'a LaunchOptions>,
 }
 
 impl<'a> ArgumentProcessor<'a> {
@@ -25,15 +29,30 @@ impl<'a> ArgumentProcessor<'a> {
             account,
             paths,
             memory,
+            options: None,
         }
     }
 
+    /// Applies the instance's heap, extra arguments and overrides on top of the version's
+    pub fn with_launch_options(mut self, options: &'a LaunchOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
     pub fn process_arguments(&self) -> Result<(Vec<String>, Vec<String>), String> {
         let placeholders = self.create_placeholders();
         let features = self.create_features_map();
 
         let jvm_args = self.process_jvm_arguments(&placeholders)?;
-        let game_args = self.process_game_arguments(&placeholders, &features)?;
+        let mut game_args = self.process_game_arguments(&placeholders, &features)?;
+        if let Some(options) = self.options {
+            let extra: Vec<String> = options
+                .game_args
+                .iter()
+                .map(|arg| self.replace_placeholders(arg, &placeholders))
+                .collect();
+            merge_game_args(&mut game_args, &extra);
+        }
 
         log::debug!(
             "Successfully processed arguments - JVM: {} args, Game: {} args",
@@ -126,6 +145,16 @@ impl<'a> ArgumentProcessor<'a> {
             .unwrap_or("legacy");
 
         placeholders.insert("assets_index_name".to_string(), assets_index.to_string());
+        placeholders.insert(
+            "game_assets".to_string(),
+            game_assets_dir(
+                &self.paths.assets_dir(),
+                self.paths.game_dir(),
+                assets_index,
+            )
+            .to_string_lossy()
+            .to_string(),
+        );
 
         // Screen resolution
         let (width, height) = if let Ok(binding) = crate::GLOBAL_APP_HANDLE.lock() {
@@ -160,6 +189,16 @@ impl<'a> ArgumentProcessor<'a> {
         placeholders.insert("auth_xuid".to_string(), "".to_string());
         placeholders.insert("user_properties".to_string(), "{}".to_string());
 
+        // Pre-1.6 clients take the whole session as one argument
+        placeholders.insert(
+            "auth_session".to_string(),
+            format!(
+                "token:{}:{}",
+                self.account.access_token().unwrap_or("null"),
+                self.account.uuid()
+            ),
+        );
+
         log::debug!(
             "Created {} placeholders for argument processing",
             placeholders.len()
@@ -183,9 +222,20 @@ impl<'a> ArgumentProcessor<'a> {
         &self,
         placeholders: &HashMap<String, String>,
     ) -> Result<Vec<String>, String> {
-        let mut jvm_args = vec![format!("-Xms512M"), format!("-Xmx{}M", self.memory)];
+        let (min_memory, max_memory) = match self.options {
+            Some(options) => options.heap(self.memory)?,
+            None => LaunchOptions::default().heap(self.memory)?,
+        };
+        let mut jvm_args = vec![
+            format!("-Xms{}M", min_memory),
+            format!("-Xmx{}M", max_memory),
+        ];
 
-        log::debug!("Processing JVM arguments with {}MB memory", self.memory);
+        log::debug!(
+            "Processing JVM arguments with {}-{}MB memory",
+            min_memory,
+            max_memory
+        );
 
         // Check for modern arguments format (1.13+)
         if let Some(args_obj) = self.manifest.get("arguments").and_then(|v| v.get("jvm")) {
@@ -231,6 +281,16 @@ impl<'a> ArgumentProcessor<'a> {
             }
         }
 
+        // Log4j configuration with the XML layout the game log parses
+        if let Some(argument) = self.logging_argument() {
+            jvm_args.push(argument);
+        }
+
+        // Instance arguments go last so they can override the version's `-D` properties
+        if let Some(options) = self.options {
+            jvm_args.extend(options.jvm_args.iter().cloned());
+        }
+
         // Ensure classpath is always added if not present
         if !jvm_args
             .iter()
@@ -254,6 +314,17 @@ impl<'a> ArgumentProcessor<'a> {
         Ok(final_args)
     }
 
+    /// `logging.client.argument` pointing at the downloaded configuration, if it is present
+    fn logging_argument(&self) -> Option<String> {
+        let (id, _, argument) = get_logging_config_info(self.manifest)?;
+        let config_file = self.paths.assets_dir().join("log_configs").join(id);
+        if !config_file.is_file() {
+            log::debug!("Log configuration {} not downloaded", config_file.display());
+            return None;
+        }
+        Some(argument.replace("${path}", &config_file.to_string_lossy()))
+    }
+
     fn process_game_arguments(
         &self,
         placeholders: &HashMap<String, String>,
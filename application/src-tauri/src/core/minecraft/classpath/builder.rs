@@ -1,5 +1,6 @@
 // src/core/minecraft/launcher/classpath_builder.rs
 
+use crate::core::bootstrap::store::SharedStore;
 use crate::core::minecraft::arguments::rules::RuleEvaluator;
 use crate::core::minecraft::paths::MinecraftPaths;
 use serde_json::Value;
@@ -9,11 +10,22 @@ use std::path::{Path, PathBuf, MAIN_SEPARATOR};
 pub struct ClasspathBuilder<'a> {
     manifest: &'a Value,
     paths: &'a MinecraftPaths,
+    store: Option<SharedStore>,
 }
 
 impl<'a> ClasspathBuilder<'a> {
     pub fn new(manifest: &'a Value, paths: &'a MinecraftPaths) -> Self {
-        Self { manifest, paths }
+        Self {
+            manifest,
+            paths,
+            store: SharedStore::open().ok(),
+        }
+    }
+
+    /// Uses an explicit shared store instead of the default one under the config dir
+    pub fn with_store(mut self, store: Option<SharedStore>) -> Self {
+        self.store = store;
+        self
     }
 
     pub fn build(&self) -> Result<String, String> {
@@ -24,7 +36,13 @@ impl<'a> ClasspathBuilder<'a> {
         log::debug!("Building classpath for Minecraft launcher");
 
         // Añadir el JAR del cliente
-        let client_path = self.paths.client_jar();
+        let client_sha1 = self
+            .manifest
+            .get("downloads")
+            .and_then(|d| d.get("client"))
+            .and_then(|c| c.get("sha1"))
+            .and_then(Value::as_str);
+        let client_path = self.resolve_through_store(self.paths.client_jar(), client_sha1);
         if client_path.exists() {
             self.add_entry(
                 client_path.to_string_lossy().to_string(),
@@ -61,6 +79,12 @@ impl<'a> ClasspathBuilder<'a> {
 
                 // Intenta añadir el artefacto principal definido en "downloads.artifact".
                 if let Some(artifact_path) = self.get_library_artifact_path(lib) {
+                    let artifact_sha1 = lib
+                        .get("downloads")
+                        .and_then(|d| d.get("artifact"))
+                        .and_then(|a| a.get("sha1"))
+                        .and_then(Value::as_str);
+                    let artifact_path = self.resolve_through_store(artifact_path, artifact_sha1);
                     if let Err(e) =
                         self.add_library_if_exists(&artifact_path, &mut entries, &mut seen)
                     {
@@ -70,7 +94,9 @@ impl<'a> ClasspathBuilder<'a> {
 
                 // Para compatibilidad con formatos antiguos...
                 if let Some(native_paths) = self.get_native_library_paths(lib) {
-                    for native_path in native_paths {
+                    for (native_path, native_sha1) in native_paths {
+                        let native_path =
+                            self.resolve_through_store(native_path, native_sha1.as_deref());
                         if let Err(e) =
                             self.add_library_if_exists(&native_path, &mut entries, &mut seen)
                         {
@@ -146,7 +172,29 @@ impl<'a> ClasspathBuilder<'a> {
             })
     }
 
-    fn get_native_library_paths(&self, lib: &Value) -> Option<Vec<PathBuf>> {
+    /// Resolves a library that is missing from the instance directly to its shared store blob
+    fn resolve_through_store(&self, path: PathBuf, sha1: Option<&str>) -> PathBuf {
+        if path.exists() {
+            return path;
+        }
+
+        match (self.store.as_ref(), sha1) {
+            (Some(store), Some(hash)) => match store.resolve(hash) {
+                Some(blob) => {
+                    log::debug!(
+                        "Resolved {} from shared store: {}",
+                        path.display(),
+                        blob.display()
+                    );
+                    blob
+                }
+                None => path,
+            },
+            _ => path,
+        }
+    }
+
+    fn get_native_library_paths(&self, lib: &Value) -> Option<Vec<(PathBuf, Option<String>)>> {
         let mut native_paths = Vec::new();
         let lib_name = lib.get("name").and_then(Value::as_str)?;
 
@@ -158,16 +206,18 @@ impl<'a> ClasspathBuilder<'a> {
         {
             let os_classifiers = self.get_os_native_classifiers();
             for key in os_classifiers {
-                if let Some(path_val) = classifiers
-                    .get(key)
-                    .and_then(|i| i.get("path"))
-                    .and_then(Value::as_str)
-                {
-                    native_paths.push(
+                let info = classifiers.get(key);
+                if let Some(path_val) = info.and_then(|i| i.get("path")).and_then(Value::as_str) {
+                    let sha1 = info
+                        .and_then(|i| i.get("sha1"))
+                        .and_then(Value::as_str)
+                        .map(str::to_string);
+                    native_paths.push((
                         self.paths
                             .libraries_dir()
                             .join(path_val.replace('/', &MAIN_SEPARATOR.to_string())),
-                    );
+                        sha1,
+                    ));
                 }
             }
         }
@@ -187,8 +237,11 @@ impl<'a> ClasspathBuilder<'a> {
                     "32"
                 };
                 let classifier = classifier_template.replace("${arch}", arch);
-                native_paths
-                    .push(self.construct_library_path_from_name(lib_name, Some(&classifier)));
+                // The legacy format carries no hashes, so it can't be resolved through the store
+                native_paths.push((
+                    self.construct_library_path_from_name(lib_name, Some(&classifier)),
+                    None,
+                ));
             }
         }
 
@@ -0,0 +1,333 @@
+// src/core/minecraft/hooks.rs
+// Shell commands an instance runs before launching and after the game exits. They run in the
+// instance directory with the instance described in `INST_DIR`, `INST_ID`, `MC_VERSION` and,
+// after exit, `EXIT_CODE`.
+
+use crate::core::minecraft_instance::MinecraftInstance;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time a hook may run when the instance does not set one
+pub const DEFAULT_HOOK_TIMEOUT_SECS: u64 = 300;
+const MAX_HOOK_TIMEOUT_SECS: u64 = 3600;
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long output is still collected once the hook exited. Processes it left in the
+/// background can keep its pipes open indefinitely.
+const OUTPUT_GRACE: Duration = Duration::from_millis(500);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LaunchHooks {
+    /// Runs before the game starts; a failure or timeout aborts the launch
+    pub pre_launch: Option<String>,
+    /// Runs once the game has exited; a failure is only logged
+    pub post_exit: Option<String>,
+    /// Seconds each hook may run before it is killed
+    pub timeout_secs: Option<u64>,
+}
+
+impl LaunchHooks {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(timeout) = self.timeout_secs {
+            if !(1..=MAX_HOOK_TIMEOUT_SECS).contains(&timeout) {
+                return Err(format!(
+                    "El tiempo límite de los scripts debe estar entre 1 y {} segundos",
+                    MAX_HOOK_TIMEOUT_SECS
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn pre_launch(&self) -> Option<&str> {
+        command(&self.pre_launch)
+    }
+
+    pub fn post_exit(&self) -> Option<&str> {
+        command(&self.post_exit)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs.unwrap_or(DEFAULT_HOOK_TIMEOUT_SECS))
+    }
+}
+
+/// Blank commands count as no hook
+fn command(hook: &Option<String>) -> Option<&str> {
+    hook.as_deref().filter(|command| !command.trim().is_empty())
+}
+
+/// Variables a hook can read to know which instance it runs for
+#[derive(Debug, Clone)]
+pub struct HookContext {
+    pub instance_dir: PathBuf,
+    pub instance_id: String,
+    pub minecraft_version: String,
+    pub exit_code: Option<i32>,
+}
+
+impl HookContext {
+    pub fn new(instance: &MinecraftInstance) -> Self {
+        Self {
+            instance_dir: PathBuf::from(
+                instance
+                    .instanceDirectory
+                    .as_deref()
+                    .unwrap_or(&instance.minecraftPath),
+            ),
+            instance_id: instance.instanceId.clone(),
+            minecraft_version: instance.minecraftVersion.clone(),
+            exit_code: None,
+        }
+    }
+
+    pub fn with_exit_code(mut self, exit_code: i32) -> Self {
+        self.exit_code = Some(exit_code);
+        self
+    }
+
+    fn env(&self) -> Vec<(&'static str, String)> {
+        let mut env = vec![
+            ("INST_DIR", self.instance_dir.to_string_lossy().to_string()),
+            ("INST_ID", self.instance_id.clone()),
+            ("MC_VERSION", self.minecraft_version.clone()),
+        ];
+        if let Some(exit_code) = self.exit_code {
+            env.push(("EXIT_CODE", exit_code.to_string()));
+        }
+        env
+    }
+}
+
+/// What a hook printed, kept for the logs
+#[derive(Debug, Default)]
+pub struct HookOutput {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+fn shell_command(script: &str) -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.arg("/C").arg(script);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(script);
+        command
+    }
+}
+
+/// Output of a pipe drained on its own thread, readable before the pipe closes
+struct PipeReader {
+    output: Arc<Mutex<Vec<u8>>>,
+    /// Disconnects when the reader thread is done
+    done: mpsc::Receiver<()>,
+}
+
+impl PipeReader {
+    fn spawn(pipe: Option<impl Read + Send + 'static>) -> Self {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let (done_sender, done) = mpsc::channel::<()>();
+        let buffer = Arc::clone(&output);
+        thread::spawn(move || {
+            let _done_sender = done_sender;
+            let Some(mut pipe) = pipe else {
+                return;
+            };
+            let mut chunk = [0u8; 8192];
+            while let Ok(read) = pipe.read(&mut chunk) {
+                if read == 0 {
+                    break;
+                }
+                if let Ok(mut output) = buffer.lock() {
+                    output.extend_from_slice(&chunk[..read]);
+                }
+            }
+        });
+        Self { output, done }
+    }
+
+    /// What arrived once the pipe closed, or once `grace` elapsed if it is still open
+    fn collect(self, grace: Duration) -> String {
+        let _ = self.done.recv_timeout(grace);
+        let output = self.output.lock().unwrap_or_else(|e| e.into_inner());
+        String::from_utf8_lossy(&output).to_string()
+    }
+}
+
+/// Kills the hook and, on Unix, every process left in its process group
+fn kill_hook(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        let _ = Command::new("kill")
+            .args(["-s", "KILL", "--", &format!("-{}", child.id())])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Runs `script` through the system shell, killing it once `timeout` has elapsed
+pub fn run_hook(
+    script: &str,
+    context: &HookContext,
+    timeout: Duration,
+) -> Result<HookOutput, String> {
+    let mut command = shell_command(script);
+    command
+        .envs(context.env())
+        .current_dir(&context.instance_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Keep Windows from opening a console window for the hook
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        command.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    // Own process group, so a timeout also kills what the hook started
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("No se pudo ejecutar el script: {}", e))?;
+
+    // Drained on their own threads so a chatty hook cannot block on a full pipe
+    let stdout = PipeReader::spawn(child.stdout.take());
+    let stderr = PipeReader::spawn(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= timeout => {
+                kill_hook(&mut child);
+                return Err(format!(
+                    "El script superó el tiempo límite de {} s",
+                    timeout.as_secs()
+                ));
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(e) => return Err(format!("Error al esperar al script: {}", e)),
+        }
+    };
+
+    let output = HookOutput {
+        stdout: stdout.collect(OUTPUT_GRACE),
+        stderr: stderr.collect(OUTPUT_GRACE),
+    };
+
+    if status.success() {
+        Ok(output)
+    } else {
+        let stderr = output.stderr.trim();
+        Err(match status.code() {
+            Some(code) if stderr.is_empty() => format!("El script terminó con código {}", code),
+            Some(code) => format!("El script terminó con código {}: {}", code, stderr),
+            None => "El script fue terminado por una señal".to_string(),
+        })
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn context(dir: &std::path::Path) -> HookContext {
+        HookContext {
+            instance_dir: dir.to_path_buf(),
+            instance_id: "abc-123".into(),
+            minecraft_version: "1.20.1".into(),
+            exit_code: None,
+        }
+    }
+
+    #[test]
+    fn test_run_hook_env_and_failure() {
+        let dir = tempdir().unwrap();
+        let context = context(dir.path()).with_exit_code(1);
+
+        let output = run_hook(
+            "echo \"$INST_ID $MC_VERSION $EXIT_CODE\" && pwd",
+            &context,
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        let mut lines = output.stdout.lines();
+        assert_eq!(lines.next(), Some("abc-123 1.20.1 1"));
+        assert_eq!(
+            std::fs::canonicalize(lines.next().unwrap()).unwrap(),
+            std::fs::canonicalize(dir.path()).unwrap()
+        );
+
+        let err =
+            run_hook("echo broken >&2; exit 3", &context, Duration::from_secs(5)).unwrap_err();
+        assert!(err.contains('3') && err.contains("broken"));
+    }
+
+    #[test]
+    fn test_run_hook_timeout() {
+        let dir = tempdir().unwrap();
+        let started = Instant::now();
+        let err = run_hook(
+            "sleep 5 & sleep 5",
+            &context(dir.path()),
+            Duration::from_millis(300),
+        )
+        .unwrap_err();
+        assert!(err.contains("tiempo límite"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_run_hook_does_not_wait_for_background_processes() {
+        let dir = tempdir().unwrap();
+        let started = Instant::now();
+        let output = run_hook(
+            "echo started; sleep 30 &",
+            &context(dir.path()),
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        assert_eq!(output.stdout.trim(), "started");
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_validate_and_blank_hooks() {
+        let hooks = LaunchHooks {
+            pre_launch: Some("  ".into()),
+            post_exit: Some("./backup.sh".into()),
+            timeout_secs: None,
+        };
+        assert!(hooks.validate().is_ok());
+        assert_eq!(hooks.pre_launch(), None);
+        assert_eq!(hooks.post_exit(), Some("./backup.sh"));
+        assert_eq!(
+            hooks.timeout(),
+            Duration::from_secs(DEFAULT_HOOK_TIMEOUT_SECS)
+        );
+
+        let no_timeout = LaunchHooks {
+            timeout_secs: Some(0),
+            ..Default::default()
+        };
+        assert!(no_timeout.validate().is_err());
+    }
+}
@@ -0,0 +1,248 @@
+// src/core/minecraft/launch_options.rs
+// Per-instance launch settings (heap, extra JVM/game arguments, environment, wrapper
+// commands and hooks) layered over the global configuration when the game is launched.
+
+use super::hooks::LaunchHooks;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Initial heap used when neither the instance nor the modpack sets one
+pub const DEFAULT_MIN_MEMORY: u32 = 512;
+const MIN_HEAP_MB: u32 = 128;
+const MAX_HEAP_MB: u32 = 65536;
+
+/// Game arguments the launcher fills in itself (session, paths, version)
+const MANAGED_GAME_ARGS: [&str; 9] = [
+    "--username",
+    "--uuid",
+    "--accessToken",
+    "--userType",
+    "--session",
+    "--version",
+    "--gameDir",
+    "--assetsDir",
+    "--assetIndex",
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LaunchOptions {
+    /// `-Xmx` in MB; the global `ramAllocation` when unset
+    pub max_memory: Option<u32>,
+    /// `-Xms` in MB
+    pub min_memory: Option<u32>,
+    /// Added after the version's JVM arguments, before the main class
+    pub jvm_args: Vec<String>,
+    /// `--flag value` pairs replace the version's value for that flag, the rest is appended
+    pub game_args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    /// Replaces the global `launchWrappers` list; an empty list launches without wrappers
+    pub wrappers: Option<Vec<String>>,
+    pub hooks: LaunchHooks,
+}
+
+impl LaunchOptions {
+    /// Rejects settings that would conflict with what the launcher sets itself
+    pub fn validate(&self) -> Result<(), String> {
+        for memory in [self.max_memory, self.min_memory].into_iter().flatten() {
+            if !(MIN_HEAP_MB..=MAX_HEAP_MB).contains(&memory) {
+                return Err(format!(
+                    "La memoria debe estar entre {} y {} MB",
+                    MIN_HEAP_MB, MAX_HEAP_MB
+                ));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_memory, self.max_memory) {
+            if min > max {
+                return Err(format!(
+                    "La memoria inicial ({} MB) supera la máxima ({} MB)",
+                    min, max
+                ));
+            }
+        }
+
+        for arg in &self.jvm_args {
+            if arg.starts_with("-Xmx") || arg.starts_with("-Xms") {
+                return Err(format!(
+                    "{} entra en conflicto con la memoria de la instancia; usa los campos de memoria",
+                    arg
+                ));
+            }
+            if arg == "-cp" || arg == "-classpath" || arg == "-jar" {
+                return Err(format!("{} lo gestiona el launcher", arg));
+            }
+        }
+
+        if let Some(arg) = self
+            .game_args
+            .iter()
+            .find(|arg| MANAGED_GAME_ARGS.contains(&arg.as_str()))
+        {
+            return Err(format!("{} lo gestiona el launcher", arg));
+        }
+
+        for key in self.env.keys() {
+            if key.is_empty() || key.contains('=') || key.contains('\0') {
+                return Err(format!("Nombre de variable de entorno inválido: {:?}", key));
+            }
+        }
+
+        if let Some(wrappers) = &self.wrappers {
+            if wrappers.iter().any(|wrapper| wrapper.trim().is_empty()) {
+                return Err("Los wrappers de lanzamiento no pueden estar vacíos".to_string());
+            }
+        }
+
+        self.hooks.validate()
+    }
+
+    /// Initial and maximum heap in MB, falling back to `default_max` for the maximum
+    pub fn heap(&self, default_max: u32) -> Result<(u32, u32), String> {
+        let max = self.max_memory.unwrap_or(default_max);
+        let min = self.min_memory.unwrap_or(DEFAULT_MIN_MEMORY.min(max));
+        if min > max {
+            return Err(format!(
+                "La memoria inicial ({} MB) supera la máxima ({} MB)",
+                min, max
+            ));
+        }
+        Ok((min, max))
+    }
+}
+
+/// Applies extra game arguments: a `--flag` already present gets its value replaced (the
+/// game rejects repeated options), anything else is appended
+pub fn merge_game_args(game_args: &mut Vec<String>, extra: &[String]) {
+    let mut extra = extra.iter().peekable();
+    while let Some(arg) = extra.next() {
+        let value = extra.next_if(|value| !value.starts_with("--"));
+        let existing = arg
+            .starts_with("--")
+            .then(|| game_args.iter().position(|existing| existing == arg))
+            .flatten();
+
+        match (existing, value) {
+            (Some(index), Some(value)) if index + 1 < game_args.len() => {
+                game_args[index + 1] = value.clone();
+            }
+            (Some(_), None) => {}
+            _ => {
+                game_args.push(arg.clone());
+                game_args.extend(value.cloned());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(LaunchOptions::default().validate().is_ok());
+
+        let options = LaunchOptions {
+            max_memory: Some(4096),
+            min_memory: Some(1024),
+            jvm_args: strings(&[
+                "-XX:+UseG1GC",
+                "-Dfml.ignoreInvalidMinecraftCertificates=true",
+            ]),
+            game_args: strings(&["--width", "1280"]),
+            env: BTreeMap::from([("MESA_GL_VERSION_OVERRIDE".into(), "4.5".into())]),
+            wrappers: Some(strings(&["gamemoderun", "mangohud --dlsym"])),
+            hooks: LaunchHooks {
+                pre_launch: Some("git -C config pull".into()),
+                post_exit: None,
+                timeout_secs: Some(60),
+            },
+        };
+        assert!(options.validate().is_ok());
+
+        let conflicting_heap = LaunchOptions {
+            jvm_args: strings(&["-Xmx8G"]),
+            ..Default::default()
+        };
+        assert!(conflicting_heap.validate().is_err());
+
+        let inverted_heap = LaunchOptions {
+            max_memory: Some(1024),
+            min_memory: Some(2048),
+            ..Default::default()
+        };
+        assert!(inverted_heap.validate().is_err());
+
+        let session_override = LaunchOptions {
+            game_args: strings(&["--accessToken", "abc"]),
+            ..Default::default()
+        };
+        assert!(session_override.validate().is_err());
+
+        let bad_env = LaunchOptions {
+            env: BTreeMap::from([("A=B".into(), "1".into())]),
+            ..Default::default()
+        };
+        assert!(bad_env.validate().is_err());
+
+        let blank_wrapper = LaunchOptions {
+            wrappers: Some(strings(&["gamemoderun", " "])),
+            ..Default::default()
+        };
+        assert!(blank_wrapper.validate().is_err());
+    }
+
+    #[test]
+    fn test_heap() {
+        assert_eq!(LaunchOptions::default().heap(2048), Ok((512, 2048)));
+        assert_eq!(LaunchOptions::default().heap(256), Ok((256, 256)));
+
+        let options = LaunchOptions {
+            max_memory: Some(6144),
+            min_memory: Some(2048),
+            ..Default::default()
+        };
+        assert_eq!(options.heap(2048), Ok((2048, 6144)));
+
+        // Only the minimum is set and the global maximum is lower
+        let options = LaunchOptions {
+            min_memory: Some(4096),
+            ..Default::default()
+        };
+        assert!(options.heap(2048).is_err());
+    }
+
+    #[test]
+    fn test_merge_game_args() {
+        let mut game_args = strings(&["--username", "Steve", "--width", "854", "--guiScale", "2"]);
+        merge_game_args(
+            &mut game_args,
+            &strings(&[
+                "--width",
+                "1920",
+                "--fullscreen",
+                "--server",
+                "mc.example.org",
+            ]),
+        );
+
+        assert_eq!(
+            game_args,
+            strings(&[
+                "--username",
+                "Steve",
+                "--width",
+                "1920",
+                "--guiScale",
+                "2",
+                "--fullscreen",
+                "--server",
+                "mc.example.org",
+            ])
+        );
+    }
+}
@@ -1,23 +1,102 @@
 use crate::config::get_config_manager;
 use crate::core::accounts_manager::AccountsManager;
+use crate::core::bootstrap::manifest::{get_java_runtime_component, get_java_version_requirement};
+use crate::core::java_manager::JavaManager;
 use crate::core::minecraft::{
     arguments::ArgumentProcessor,
     classpath::ClasspathBuilder,
     manifest::{ManifestMerger, ManifestParser},
     paths::MinecraftPaths,
+    wrappers::{effective_wrappers, wrapped_java_command},
 };
 use crate::core::{minecraft_account::MinecraftAccount, minecraft_instance::MinecraftInstance};
 use crate::interfaces::game_launcher::GameLauncher;
-use std::process::{Child, Command, Stdio};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::{Child, Stdio};
 use uuid::Uuid;
 
 pub struct MinecraftLauncher {
     instance: MinecraftInstance,
+    java_home: Option<PathBuf>,
 }
 
 impl MinecraftLauncher {
     pub fn new(instance: MinecraftInstance) -> Self {
-        Self { instance }
+        Self {
+            instance,
+            java_home: None,
+        }
+    }
+
+    /// Launches with the Java found in `java_home` instead of the configured one
+    pub fn with_java_home(mut self, java_home: PathBuf) -> Self {
+        self.java_home = Some(java_home);
+        self
+    }
+
+    /// Java home matching the version's `javaVersion`, installed if missing. `None` keeps
+    /// the Java pinned for the instance or, failing that, the global `javaDir`. The config is
+    /// only locked while the manifest is read, never during a download.
+    pub fn select_java_home(&self) -> Option<PathBuf> {
+        if self.instance.java_override().is_some() {
+            log::info!("[MinecraftLauncher] Using the Java pinned for this instance");
+            return None;
+        }
+
+        let (manifest_json, provider) = {
+            let config_manager = get_config_manager().lock().ok()?;
+            let config = config_manager.as_ref().ok()?;
+            let paths = MinecraftPaths::new(&self.instance, config)?;
+            let manifest_json = ManifestParser::new(&paths)
+                .load_merged_manifest()
+                .map_err(|e| log::warn!("[MinecraftLauncher] Failed to load manifest: {}", e))
+                .ok()?;
+            (manifest_json, config.get_java_provider())
+        };
+
+        let major_version = match get_java_version_requirement(&manifest_json) {
+            Ok(major_version) => major_version,
+            Err(e) => {
+                log::warn!("[MinecraftLauncher] {}, using the configured Java", e);
+                return None;
+            }
+        };
+        let component = get_java_runtime_component(&manifest_json);
+
+        let java_manager = JavaManager::with_provider(provider)
+            .map_err(|e| log::warn!("[MinecraftLauncher] JavaManager unavailable: {}", e))
+            .ok()?;
+        if let Some(java_home) =
+            java_manager.installed_runtime(&major_version, component.as_deref())
+        {
+            log::info!(
+                "[MinecraftLauncher] Java {} required, using {}",
+                major_version,
+                java_home.display()
+            );
+            return Some(java_home);
+        }
+
+        log::info!(
+            "[MinecraftLauncher] Java {} required but not installed, downloading it",
+            major_version
+        );
+        let runtime = tokio::runtime::Runtime::new()
+            .map_err(|e| log::error!("[MinecraftLauncher] Failed to create Tokio runtime: {}", e))
+            .ok()?;
+        match runtime.block_on(java_manager.get_java_runtime(&major_version, component.as_deref()))
+        {
+            Ok(java_home) => Some(java_home),
+            Err(e) => {
+                log::error!(
+                    "[MinecraftLauncher] Could not install Java {}: {}, using the configured Java",
+                    major_version,
+                    e
+                );
+                None
+            }
+        }
     }
 }
 
@@ -49,6 +128,12 @@ impl GameLauncher for MinecraftLauncher {
 
         log::info!("Minecraft memory: {}MB", mc_memory);
 
+        let launch_options = &self.instance.launchOptions;
+        if let Err(e) = launch_options.validate() {
+            log::error!("[MinecraftLauncher] Invalid launch options: {}", e);
+            return None;
+        }
+
         // Get account
         let accounts_manager = AccountsManager::new();
         let account_uuid = self.instance.accountUuid.as_ref()?;
@@ -60,7 +145,7 @@ impl GameLauncher for MinecraftLauncher {
         );
 
         // Setup paths
-        let paths = MinecraftPaths::new(&self.instance, config)?;
+        let mut paths = MinecraftPaths::new(&self.instance, config)?;
 
         log::info!("[MinecraftLauncher] Minecraft paths: {:?}", paths);
         log::info!("[MinecraftLauncher] Java path: {:?}", paths.java_path());
@@ -76,6 +161,10 @@ impl GameLauncher for MinecraftLauncher {
 
         log::info!("[MinecraftLauncher] Manifest loaded");
 
+        if let Some(java_home) = &self.java_home {
+            paths.set_java_home(java_home);
+        }
+
         // Build classpath
         let classpath_builder = ClasspathBuilder::new(&manifest_json, &paths);
         let classpath_str = match classpath_builder.build() {
@@ -90,7 +179,8 @@ impl GameLauncher for MinecraftLauncher {
 
         // Process arguments
         let argument_processor =
-            ArgumentProcessor::new(&manifest_json, &account, &paths, mc_memory);
+            ArgumentProcessor::new(&manifest_json, &account, &paths, mc_memory)
+                .with_launch_options(launch_options);
         let (jvm_args, game_args) = match argument_processor.process_arguments() {
             Ok(args) => args,
             Err(e) => {
@@ -108,12 +198,20 @@ impl GameLauncher for MinecraftLauncher {
             }
         };
 
-        // Build and execute command
-        let mut command = Command::new(paths.java_path());
+        // Build and execute command, through the wrapper chain if one is configured
+        let wrappers = effective_wrappers(&self.instance, config);
+        let mut command = match wrapped_java_command(&wrappers, paths.java_path()) {
+            Ok(command) => command,
+            Err(e) => {
+                log::error!("[MinecraftLauncher] {}", e);
+                return None;
+            }
+        };
         command
             .args(&jvm_args)
             .arg(main_class)
             .args(&game_args)
+            .envs(&launch_options.env)
             .current_dir(paths.game_dir())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
@@ -121,16 +219,21 @@ impl GameLauncher for MinecraftLauncher {
         // Log the complete launch command for debugging
         log::info!("Launching Minecraft with command: {:?}", command);
         log::debug!("Java executable: {}", paths.java_path().display());
+        log::debug!("Launch wrappers: {:?}", wrappers);
         log::debug!("Main class: {}", main_class);
         log::debug!("Working directory: {}", paths.game_dir().display());
         log::debug!("JVM arguments ({}): {:?}", jvm_args.len(), jvm_args);
         log::debug!("Game arguments ({}): {:?}", game_args.len(), game_args);
+        log::debug!(
+            "Environment overrides: {:?}",
+            launch_options.env.keys().collect::<Vec<_>>()
+        );
 
         // Build full command string for easy debugging
-        let mut full_command = vec![paths.java_path().to_string_lossy().to_string()];
-        full_command.extend(jvm_args.iter().cloned());
-        full_command.push(main_class.to_string());
-        full_command.extend(game_args.iter().cloned());
+        let full_command: Vec<String> = std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect();
 
         log::info!(
             "[MinecraftLauncher] Full launch command: {}",
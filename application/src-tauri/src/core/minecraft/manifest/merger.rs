@@ -1,5 +1,5 @@
 use serde_json::{Map, Value};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 pub struct ManifestMerger;
 
@@ -224,80 +224,81 @@ impl ManifestMerger {
         }
     }
 
+    /// Merges `minecraftArguments` keeping vanilla's order. Forge overrides a flag's value
+    /// in place, adds its own flags at the end, and `--tweakClass` may appear several times.
     fn merge_legacy_arguments(result: &mut Value, vanilla: &Value, forge: &Value) {
         log::debug!("Starting legacy arguments merge process");
 
-        let mut kv = HashMap::new();
-        let mut vanilla_processed = false;
-        let mut forge_processed = false;
+        let mut pairs: Vec<(String, String)> = Vec::new();
+        let mut sources = Vec::new();
 
         for (source_name, src) in [
             ("vanilla", vanilla.get("minecraftArguments")),
             ("forge", forge.get("minecraftArguments")),
         ] {
-            if let Some(Value::String(s)) = src {
-                log::debug!("Processing {} legacy arguments: {}", source_name, s);
-                let args: Vec<&str> = s.split_whitespace().collect();
-
-                let mut i = 0;
-                while i < args.len() {
-                    if let Some(key) = args.get(i) {
-                        if key.starts_with("--") {
-                            if let Some(value) = args.get(i + 1) {
-                                if !value.starts_with("--") {
-                                    kv.insert(key.to_string(), value.to_string());
-                                    i += 2;
-                                    continue;
-                                }
-                            }
-                            kv.insert(key.to_string(), "".to_string());
-                            i += 1;
-                        } else {
-                            i += 1;
-                        }
-                    } else {
-                        break;
-                    }
+            let Some(Value::String(s)) = src else {
+                continue;
+            };
+            log::debug!("Processing {} legacy arguments: {}", source_name, s);
+            sources.push(source_name);
+
+            let args: Vec<&str> = s.split_whitespace().collect();
+            let mut i = 0;
+            while i < args.len() {
+                let key = args[i];
+                if !key.starts_with("--") {
+                    i += 1;
+                    continue;
                 }
 
-                if source_name == "vanilla" {
-                    vanilla_processed = true;
+                let value = match args.get(i + 1) {
+                    Some(value) if !value.starts_with("--") => {
+                        i += 2;
+                        value.to_string()
+                    }
+                    _ => {
+                        i += 1;
+                        String::new()
+                    }
+                };
+
+                if key == "--tweakClass" {
+                    if !pairs.iter().any(|(k, v)| k == key && *v == value) {
+                        pairs.push((key.to_string(), value));
+                    }
+                } else if let Some(existing) = pairs.iter_mut().find(|(k, _)| k == key) {
+                    existing.1 = value;
                 } else {
-                    forge_processed = true;
+                    pairs.push((key.to_string(), value));
                 }
             }
         }
 
-        if !kv.is_empty() {
-            let merged_legacy = kv
-                .iter()
-                .map(|(k, v)| {
-                    if v.is_empty() {
-                        k.clone()
-                    } else {
-                        format!("{} {}", k, v)
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join(" ");
-
-            result["minecraftArguments"] = Value::String(merged_legacy.clone());
+        if pairs.is_empty() {
+            log::debug!("No legacy arguments found to merge");
+            return;
+        }
 
-            log::debug!(
-                "Legacy arguments merge completed: {} pairs from {} sources",
-                kv.len(),
-                if vanilla_processed && forge_processed {
-                    "both"
-                } else if vanilla_processed {
-                    "vanilla only"
+        let merged_legacy = pairs
+            .iter()
+            .map(|(k, v)| {
+                if v.is_empty() {
+                    k.clone()
                 } else {
-                    "forge only"
+                    format!("{} {}", k, v)
                 }
-            );
-            log::debug!("Merged legacy arguments: {}", merged_legacy);
-        } else {
-            log::debug!("No legacy arguments found to merge");
-        }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        result["minecraftArguments"] = Value::String(merged_legacy.clone());
+
+        log::debug!(
+            "Legacy arguments merge completed: {} pairs from {:?}",
+            pairs.len(),
+            sources
+        );
+        log::debug!("Merged legacy arguments: {}", merged_legacy);
     }
 
     fn extract_lib_info(
@@ -513,4 +514,53 @@ mod tests {
             "commons-io:commons-io:2.6"
         );
     }
+
+    fn fixture(name: &str) -> Value {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures/versions")
+            .join(name);
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_legacy_forge_merge() {
+        let merged = ManifestMerger::merge(
+            fixture("1.12.2.json"),
+            fixture("1.12.2-forge-14.23.5.2859.json"),
+        );
+
+        assert_eq!(merged["mainClass"], "net.minecraft.launchwrapper.Launch");
+        assert_eq!(
+            merged["minecraftArguments"],
+            "--username ${auth_player_name} --version ${version_name} --gameDir ${game_directory} \
+             --assetsDir ${assets_root} --assetIndex ${assets_index_name} --uuid ${auth_uuid} \
+             --accessToken ${auth_access_token} --userType ${user_type} --versionType Forge \
+             --tweakClass net.minecraftforge.fml.common.launcher.FMLTweaker"
+        );
+
+        let names: Vec<&str> = merged["libraries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|lib| lib["name"].as_str().unwrap())
+            .collect();
+        assert!(names.contains(&"net.minecraftforge:forge:1.12.2-14.23.5.2859"));
+        assert!(names.contains(&"net.minecraft:launchwrapper:1.12"));
+        assert!(names.contains(&"org.ow2.asm:asm-all:5.2"));
+        assert!(!names.contains(&"org.ow2.asm:asm-all:5.0.3"));
+    }
+
+    #[test]
+    fn test_legacy_arguments_keep_every_tweaker() {
+        let vanilla = json!({ "minecraftArguments": "--username ${auth_player_name} --demo" });
+        let forge = json!({
+            "minecraftArguments": "--tweakClass a.Tweaker --username ${auth_player_name} --tweakClass b.Tweaker --tweakClass a.Tweaker"
+        });
+        let mut result = vanilla.clone();
+        ManifestMerger::merge_legacy_arguments(&mut result, &vanilla, &forge);
+        assert_eq!(
+            result["minecraftArguments"],
+            "--username ${auth_player_name} --demo --tweakClass a.Tweaker --tweakClass b.Tweaker"
+        );
+    }
 }
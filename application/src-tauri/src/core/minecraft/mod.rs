@@ -1,11 +1,15 @@
 pub mod arguments;
 pub mod classpath;
+pub mod hooks;
+pub mod launch_options;
 pub mod launcher;
 pub mod manifest;
 pub mod paths;
+pub mod wrappers;
 
 pub use arguments::{ArgumentProcessor, RuleEvaluator};
 pub use classpath::ClasspathBuilder;
+pub use launch_options::LaunchOptions;
 pub use launcher::MinecraftLauncher;
 pub use manifest::{ManifestMerger, ManifestParser};
 pub use paths::MinecraftPaths;
@@ -1,6 +1,10 @@
 use crate::config::get_config_manager;
+use crate::core::bootstrap::{
+    fabric::fabric_version_id, forge::forge_version_id, neoforge::neoforge_version_ids,
+    quilt::quilt_version_id,
+};
 use crate::core::minecraft::{classpath::ClasspathBuilder, manifest::ManifestMerger};
-use crate::core::minecraft_instance::MinecraftInstance;
+use crate::core::minecraft_instance::{MinecraftInstance, ModLoader};
 use dirs;
 use std::path::{Path, PathBuf};
 
@@ -12,6 +16,8 @@ pub struct MinecraftPaths {
     java_path: PathBuf,
     minecraft_version: String,
     forge_version: Option<String>,
+    loader: ModLoader,
+    loader_version: Option<String>,
 }
 
 impl MinecraftPaths {
@@ -25,8 +31,7 @@ impl MinecraftPaths {
         );
 
         let java_path = instance
-            .javaPath
-            .as_ref()
+            .java_override()
             .map(PathBuf::from)
             .unwrap_or_else(|| {
                 config
@@ -51,6 +56,8 @@ impl MinecraftPaths {
             java_path,
             minecraft_version: instance.minecraftVersion.clone(),
             forge_version: instance.forgeVersion.clone(),
+            loader: instance.mod_loader(),
+            loader_version: instance.loader_version().map(str::to_string),
         })
     }
 
@@ -62,6 +69,14 @@ impl MinecraftPaths {
         &self.java_path
     }
 
+    /// Uses the `java` executable of the given Java home
+    pub fn set_java_home(&mut self, java_home: &Path) {
+        self.java_path =
+            java_home
+                .join("bin")
+                .join(if cfg!(windows) { "javaw.exe" } else { "java" });
+    }
+
     pub fn minecraft_version(&self) -> &str {
         &self.minecraft_version
     }
@@ -73,6 +88,10 @@ impl MinecraftPaths {
     pub fn manifest_file(&self) -> PathBuf {
         let version_dir = self.game_dir.join("versions");
 
+        if let Some(loader_manifest) = self.loader_manifest_file(&version_dir) {
+            return loader_manifest;
+        }
+
         // Check if we need to find the Forge version
         if let Some(forge_ref) = &self.forge_version {
             log::info!(
@@ -181,6 +200,37 @@ impl MinecraftPaths {
             .join(format!("{}.json", self.minecraft_version))
     }
 
+    /// Version JSON written by the loader installers, if it is installed
+    fn loader_manifest_file(&self, version_dir: &Path) -> Option<PathBuf> {
+        let loader_version = self.loader_version.as_deref()?;
+        let candidates = match self.loader {
+            ModLoader::Forge => vec![forge_version_id(&self.minecraft_version, loader_version)],
+            ModLoader::Fabric => vec![fabric_version_id(&self.minecraft_version, loader_version)],
+            ModLoader::NeoForge => neoforge_version_ids(&self.minecraft_version, loader_version),
+            ModLoader::Quilt => vec![quilt_version_id(&self.minecraft_version, loader_version)],
+            ModLoader::Vanilla => return None,
+        };
+
+        let found = candidates
+            .iter()
+            .map(|id| version_dir.join(id).join(format!("{}.json", id)))
+            .find(|path| path.exists());
+
+        match &found {
+            Some(path) => log::info!(
+                "[MinecraftPaths] Using {:?} manifest: {}",
+                self.loader,
+                path.display()
+            ),
+            None => log::warn!(
+                "[MinecraftPaths] No {:?} manifest found (tried {:?})",
+                self.loader,
+                candidates
+            ),
+        }
+        found
+    }
+
     pub fn vanilla_manifest_file(&self, version: &str) -> PathBuf {
         self.game_dir
             .join("versions")
@@ -0,0 +1,143 @@
+// src/core/minecraft/wrappers.rs
+// Wrapper commands (gamemoderun, mangohud, prime-run, ...) that prefix the Java invocation.
+// Each entry is a program followed by its arguments, split on whitespace, and the chain is
+// applied in order: `gamemoderun mangohud java ...`.
+
+use crate::config::ConfigManager;
+use crate::core::minecraft_instance::MinecraftInstance;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Wrappers offered in the UI; anything else on `PATH` can still be configured by hand
+pub const KNOWN_WRAPPERS: [&str; 3] = ["gamemoderun", "mangohud", "prime-run"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WrapperAvailability {
+    pub name: String,
+    /// Where the program was found, `None` when it is not on `PATH`
+    pub path: Option<String>,
+}
+
+/// Wrapper chain for an instance: its own list when it has one, otherwise the global one
+pub fn effective_wrappers(instance: &MinecraftInstance, config: &ConfigManager) -> Vec<String> {
+    instance
+        .launchOptions
+        .wrappers
+        .clone()
+        .unwrap_or_else(|| config.get_launch_wrappers())
+}
+
+/// Resolves every wrapper program, returning the full prefix (programs and arguments) to
+/// put before the Java executable
+pub fn resolve_wrapper_chain(wrappers: &[String]) -> Result<Vec<PathBuf>, String> {
+    resolve_with(wrappers, |program| which::which(program).ok())
+}
+
+fn resolve_with(
+    wrappers: &[String],
+    find: impl Fn(&str) -> Option<PathBuf>,
+) -> Result<Vec<PathBuf>, String> {
+    let mut prefix = Vec::new();
+    for wrapper in wrappers {
+        let mut parts = wrapper.split_whitespace();
+        let Some(program) = parts.next() else {
+            continue;
+        };
+        let resolved = find(program).ok_or_else(|| {
+            format!(
+                "El wrapper \"{}\" no está instalado o no se encuentra en el PATH",
+                program
+            )
+        })?;
+        prefix.push(resolved);
+        prefix.extend(parts.map(PathBuf::from));
+    }
+    Ok(prefix)
+}
+
+/// `Command` running `java` through the wrapper chain, or `java` directly without wrappers
+pub fn wrapped_java_command(wrappers: &[String], java_path: &Path) -> Result<Command, String> {
+    let prefix = resolve_wrapper_chain(wrappers)?;
+    Ok(build_command(prefix, java_path))
+}
+
+fn build_command(prefix: Vec<PathBuf>, java_path: &Path) -> Command {
+    let mut prefix = prefix.into_iter();
+    match prefix.next() {
+        Some(program) => {
+            let mut command = Command::new(program);
+            command.args(prefix).arg(java_path);
+            command
+        }
+        None => Command::new(java_path),
+    }
+}
+
+/// Reports which of the known wrappers are installed
+#[tauri::command]
+pub fn detect_launch_wrappers() -> Vec<WrapperAvailability> {
+    KNOWN_WRAPPERS
+        .iter()
+        .map(|name| WrapperAvailability {
+            name: name.to_string(),
+            path: which::which(name)
+                .ok()
+                .map(|path| path.to_string_lossy().to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_path(program: &str) -> Option<PathBuf> {
+        ["gamemoderun", "mangohud", "taskset"]
+            .contains(&program)
+            .then(|| PathBuf::from("/usr/bin").join(program))
+    }
+
+    #[test]
+    fn test_resolve_chain() {
+        let wrappers = vec![
+            "gamemoderun".to_string(),
+            "  ".to_string(),
+            "taskset -c 0-3".to_string(),
+        ];
+        assert_eq!(
+            resolve_with(&wrappers, fake_path).unwrap(),
+            vec![
+                PathBuf::from("/usr/bin/gamemoderun"),
+                PathBuf::from("/usr/bin/taskset"),
+                PathBuf::from("-c"),
+                PathBuf::from("0-3"),
+            ]
+        );
+
+        let missing = vec!["mangohud".to_string(), "prime-run".to_string()];
+        let err = resolve_with(&missing, fake_path).unwrap_err();
+        assert!(err.contains("prime-run"));
+    }
+
+    #[test]
+    fn test_build_command() {
+        let java = Path::new("/java/bin/java");
+
+        let direct = build_command(Vec::new(), java);
+        assert_eq!(direct.get_program(), java);
+        assert_eq!(direct.get_args().count(), 0);
+
+        let wrapped = build_command(
+            vec![
+                PathBuf::from("/usr/bin/gamemoderun"),
+                PathBuf::from("/usr/bin/mangohud"),
+            ],
+            java,
+        );
+        assert_eq!(wrapped.get_program(), "/usr/bin/gamemoderun");
+        let args: Vec<_> = wrapped.get_args().collect();
+        assert_eq!(args, vec!["/usr/bin/mangohud", "/java/bin/java"]);
+    }
+}
@@ -1,5 +1,6 @@
 // src-tauri/src/minecraft_instance.rs
 use crate::core::instance_launcher::InstanceLauncher;
+use crate::core::minecraft::launch_options::LaunchOptions;
 use crate::core::tasks_manager::{TaskInfo, TaskStatus};
 use crate::utils::config_manager::ConfigManager;
 use serde::{Deserialize, Serialize};
@@ -9,6 +10,29 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::thread;
 
+/// Mod loader an instance runs on
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModLoader {
+    Vanilla,
+    Forge,
+    NeoForge,
+    Fabric,
+    Quilt,
+}
+
+impl ModLoader {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ModLoader::Vanilla => "Vanilla",
+            ModLoader::Forge => "Forge",
+            ModLoader::NeoForge => "NeoForge",
+            ModLoader::Fabric => "Fabric",
+            ModLoader::Quilt => "Quilt",
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MinecraftInstance {
     pub instanceId: String,
@@ -23,7 +47,17 @@ pub struct MinecraftInstance {
     pub minecraftVersion: String,
     pub instanceDirectory: Option<String>,
     pub forgeVersion: Option<String>,
-    pub javaPath: Option<String>, // In the future, we automatically download the correct Java version
+    pub javaPath: Option<String>,
+    // Without a pin the runtime is picked at launch from the version's javaVersion
+    #[serde(default)]
+    pub javaPathPinned: bool,
+    // Forge keeps using forgeVersion so older instance.json files stay valid
+    #[serde(default)]
+    pub loader: Option<ModLoader>,
+    #[serde(default)]
+    pub loaderVersion: Option<String>,
+    #[serde(default)]
+    pub launchOptions: LaunchOptions,
 }
 
 impl MinecraftInstance {
@@ -31,6 +65,46 @@ impl MinecraftInstance {
         self.forgeVersion.is_some()
     }
 
+    /// Loader to bootstrap and launch; a `forgeVersion` always means Forge
+    pub fn mod_loader(&self) -> ModLoader {
+        if self.forgeVersion.is_some() {
+            return ModLoader::Forge;
+        }
+        self.loader.unwrap_or(ModLoader::Vanilla)
+    }
+
+    /// Version of the instance's mod loader, `None` for vanilla
+    pub fn loader_version(&self) -> Option<&str> {
+        match self.mod_loader() {
+            ModLoader::Vanilla => None,
+            ModLoader::Forge => self.forgeVersion.as_deref(),
+            _ => self.loaderVersion.as_deref(),
+        }
+    }
+
+    /// Java home the user pinned for this instance, if any
+    pub fn java_override(&self) -> Option<&str> {
+        self.javaPath.as_deref().filter(|_| self.javaPathPinned)
+    }
+
+    pub fn set_loader(&mut self, loader: ModLoader, version: Option<String>) {
+        self.loader = Some(loader);
+        match loader {
+            ModLoader::Vanilla => {
+                self.forgeVersion = None;
+                self.loaderVersion = None;
+            }
+            ModLoader::Forge => {
+                self.forgeVersion = version;
+                self.loaderVersion = None;
+            }
+            _ => {
+                self.forgeVersion = None;
+                self.loaderVersion = version;
+            }
+        }
+    }
+
     pub fn new() -> Self {
         Self {
             instanceId: String::new(),
@@ -46,6 +120,10 @@ impl MinecraftInstance {
             instanceDirectory: None,
             forgeVersion: None,
             javaPath: None,
+            javaPathPinned: false,
+            loader: None,
+            loaderVersion: None,
+            launchOptions: LaunchOptions::default(),
         }
     }
 
@@ -174,13 +252,12 @@ impl MinecraftInstance {
         Ok(())
     }
 
-    pub fn set_java_path(&mut self, java_path: PathBuf) {
-        self.javaPath = Some(java_path.to_string_lossy().to_string());
-
-        // Guardar la ruta de Java en el archivo de configuración
-        self.save().unwrap_or_else(|e| {
-            println!("Error saving Java path: {}", e);
-        });
+    /// Pins a Java home for this instance, bypassing the automatic selection, or goes back
+    /// to the automatic selection with `None`
+    pub fn set_java_override(&mut self, java_home: Option<String>) {
+        let java_home = java_home.filter(|path| !path.trim().is_empty());
+        self.javaPathPinned = java_home.is_some();
+        self.javaPath = java_home;
     }
 }
 
@@ -2,17 +2,25 @@ pub mod accounts_manager;
 pub mod auth;
 pub mod bootstrap;
 pub mod bootstrap_error;
+pub mod file_hash_index;
+pub mod game_log;
 pub mod instance_bootstrap;
+pub mod instance_export;
 pub mod instance_launcher;
 pub mod instance_manager;
+pub mod java_inventory;
 pub mod java_manager;
+pub mod manifest_signature;
 pub mod microsoft_auth;
 pub mod minecraft;
 pub mod minecraft_account;
 pub mod minecraft_instance;
 pub mod models;
 pub mod modpack_file_manager;
+pub mod modpack_import;
+pub mod mojang_runtime;
 pub mod network_utilities;
 pub mod onboarding;
 pub mod prelaunch_appearance;
+pub mod running_instances;
 pub mod tasks_manager;
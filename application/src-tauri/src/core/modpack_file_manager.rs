@@ -1,12 +1,20 @@
+use crate::core::bootstrap::store::sha1_of_file;
 use crate::core::bootstrap::tasks::{
     emit_bootstrap_complete, emit_status, emit_status_with_stage, Stage,
 };
+use crate::core::bootstrap_error::BootstrapError;
+use crate::core::file_hash_index::FileHashIndex;
+use crate::core::manifest_signature::is_signature_error;
+use crate::core::minecraft::launch_options::LaunchOptions;
 use crate::core::minecraft::paths::MinecraftPaths;
 use crate::core::minecraft_instance::MinecraftInstance;
+use crate::core::modpack_import::is_staged_source;
 use crate::core::tasks_manager::{
-    add_task, add_task_with_auto_start, remove_task, task_exists, update_task, TaskStatus,
+    add_task, add_task_with_auto_start, remove_task, task_exists, update_task,
+    update_task_with_bootstrap_error, TaskStatus,
 };
 use crate::utils::config_manager::get_config_manager;
+use crate::utils::safe_path::resolve_within;
 use chrono;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
@@ -29,7 +37,23 @@ pub struct ModpackManifest {
     pub mc_version: String,
     #[serde(rename = "forgeVersion")]
     pub forge_version: Option<String>,
+    #[serde(
+        rename = "quiltVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub quilt_version: Option<String>,
     pub files: Vec<ModpackFileEntry>,
+    /// Base URLs mirroring the whole CDN; the primary download URL's path is appended to them
+    #[serde(rename = "baseMirrors", default, skip_serializing_if = "Vec::is_empty")]
+    pub base_mirrors: Vec<String>,
+    /// Heap, arguments and environment the modpack recommends for new instances
+    #[serde(
+        rename = "launchOptions",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub launch_options: Option<LaunchOptions>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -44,6 +68,61 @@ pub struct ModpackFileEntry {
     pub path: String,
     pub file: ModpackFileType,
     pub downloadUrl: String,
+    /// Alternative full URLs for this file, tried in order after `downloadUrl`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<String>,
+}
+
+impl ModpackFileEntry {
+    /// Location of this file inside the instance's minecraft directory.
+    /// Manifest paths are untrusted, so traversal and symlink escapes are rejected.
+    pub fn resolve_path(&self, minecraft_dir: &Path) -> Result<PathBuf, String> {
+        resolve_within(minecraft_dir, &self.path)
+            .map_err(|e| format!("Unsafe path in modpack manifest ({}): {}", self.path, e))
+    }
+
+    /// Every URL this file can be fetched from, in preference order: the primary URL,
+    /// the per-file mirrors and finally the manifest-wide base mirrors
+    pub fn source_urls(&self, base_mirrors: &[String]) -> Vec<String> {
+        let mut urls = vec![self.downloadUrl.clone()];
+        urls.extend(self.mirrors.iter().cloned());
+
+        if let Ok(primary) = url::Url::parse(&self.downloadUrl) {
+            let mut path = primary.path().trim_start_matches('/').to_string();
+            if let Some(query) = primary.query() {
+                path = format!("{}?{}", path, query);
+            }
+            for base in base_mirrors {
+                urls.push(format!("{}/{}", base.trim_end_matches('/'), path));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        urls.retain(|url| !url.is_empty() && seen.insert(url.clone()));
+        urls
+    }
+
+    /// Sources usable when installing into `instance_dir`. Local `file://` sources are
+    /// only honoured when they point into that instance's import staging directory.
+    pub fn install_sources(&self, base_mirrors: &[String], instance_dir: &Path) -> Vec<String> {
+        self.source_urls(base_mirrors)
+            .into_iter()
+            .filter(|url| {
+                let allowed = !is_local_source(url) || is_staged_source(url, instance_dir);
+                if !allowed {
+                    log::warn!(
+                        "[FileManager] Ignoring local source outside staging: {}",
+                        url
+                    );
+                }
+                allowed
+            })
+            .collect()
+    }
+}
+
+fn is_local_source(url: &str) -> bool {
+    url.starts_with("file:")
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -57,6 +136,43 @@ fn get_download_concurrency() -> usize {
     4
 }
 
+lazy_static::lazy_static! {
+    /// Failures per mirror (scheme + host) during this session. Mirrors that failed or
+    /// served a wrong hash are tried after the healthy ones until the app restarts.
+    static ref MIRROR_FAILURES: std::sync::Mutex<HashMap<String, u32>> =
+        std::sync::Mutex::new(HashMap::new());
+}
+
+fn mirror_key(url: &str) -> String {
+    url::Url::parse(url)
+        .map(|u| u.origin().ascii_serialization())
+        .unwrap_or_else(|_| url.to_string())
+}
+
+fn demote_mirror(url: &str) {
+    let key = mirror_key(url);
+    if let Ok(mut failures) = MIRROR_FAILURES.lock() {
+        let count = failures.entry(key.clone()).or_insert(0);
+        *count += 1;
+        log::warn!(
+            "[Mirrors] Demoted {} ({} failures this session)",
+            key,
+            count
+        );
+    }
+}
+
+/// Stable-sorts the candidate URLs so demoted mirrors go last
+fn order_sources_by_health(urls: &[String]) -> Vec<String> {
+    let failures = MIRROR_FAILURES
+        .lock()
+        .map(|f| f.clone())
+        .unwrap_or_default();
+    let mut ordered = urls.to_vec();
+    ordered.sort_by_key(|url| failures.get(&mirror_key(url)).copied().unwrap_or(0));
+    ordered
+}
+
 /// Identifies essential Minecraft files and directories that should never be deleted
 fn get_essential_minecraft_paths(
     minecraft_dir: &Path,
@@ -177,7 +293,7 @@ fn load_previous_manifest(instance: &MinecraftInstance) -> Option<ModpackManifes
 }
 
 /// Saves current manifest for future comparison
-fn save_manifest_cache(
+pub(crate) fn save_manifest_cache(
     instance: &MinecraftInstance,
     manifest: &ModpackManifest,
 ) -> Result<(), String> {
@@ -350,7 +466,14 @@ pub fn cleanup_obsolete_files(
 
                 if let Some(target_file) = target_location {
                     // File should be moved to new location
-                    let target_path = minecraft_dir.join(&target_file.path);
+                    let target_path = match target_file.resolve_path(&minecraft_dir) {
+                        Ok(path) => path,
+                        Err(e) => {
+                            log::error!("[Cleanup] Refusing to move {}: {}", file_to_clean, e);
+                            preserved_files.push(file_to_clean);
+                            continue;
+                        }
+                    };
 
                     // Ensure target directory exists
                     if let Some(parent) = target_path.parent() {
@@ -470,7 +593,7 @@ fn is_modpack_file(relative_path: &str) -> bool {
 }
 
 /// Gets the list of directories that are controlled by the modpack manifest
-fn get_controlled_directories() -> Vec<&'static str> {
+pub(crate) fn get_controlled_directories() -> Vec<&'static str> {
     vec![
         "mods",
         "coremods",
@@ -571,11 +694,12 @@ pub async fn download_and_install_files(
     let mut files_moved = 0;
 
     // Build hash-to-path map for efficient lookup of existing files
-    let hash_map = build_hash_to_path_map(&minecraft_dir)?;
+    let mut hash_index = FileHashIndex::load(Path::new(instance_dir), &minecraft_dir);
+    let hash_map = build_hash_to_path_map(&minecraft_dir, &mut hash_index)?;
 
     // Process each file in the manifest
     for (index, file_entry) in manifest.files.iter().enumerate() {
-        let target_path = minecraft_dir.join(&file_entry.path);
+        let target_path = file_entry.resolve_path(&minecraft_dir)?;
 
         // Create parent directory if needed
         if let Some(parent) = target_path.parent() {
@@ -594,7 +718,7 @@ pub async fn download_and_install_files(
         }
 
         // Check if file already exists at the correct location with correct hash
-        if file_exists_with_correct_hash(&target_path, &file_entry.fileHash).await {
+        if file_exists_with_correct_hash(&target_path, &file_entry.fileHash, &mut hash_index) {
             files_processed += 1;
             continue;
         }
@@ -605,7 +729,11 @@ pub async fn download_and_install_files(
 
             // Verify the existing file still has the correct hash (safety check)
             if existing_full_path != target_path
-                && file_exists_with_correct_hash(&existing_full_path, &file_entry.fileHash).await
+                && file_exists_with_correct_hash(
+                    &existing_full_path,
+                    &file_entry.fileHash,
+                    &mut hash_index,
+                )
             {
                 // Move the file to the correct location
                 log::info!(
@@ -621,6 +749,7 @@ pub async fn download_and_install_files(
                     );
                     files_to_download.push(file_entry);
                 } else {
+                    hash_index.rename(&existing_full_path, &target_path);
                     files_moved += 1;
                     files_processed += 1;
                     log::info!("[FileMove] Successfully moved file to {}", file_entry.path);
@@ -640,32 +769,36 @@ pub async fn download_and_install_files(
         files_to_download.len()
     );
 
+    if let Err(e) = hash_index.save() {
+        log::warn!("[FileManager] Failed to save file hash index: {}", e);
+    }
+
     // Download remaining files that couldn't be moved
     if !files_to_download.is_empty() {
         let download_manager = DownloadManager::with_concurrency(get_download_concurrency());
 
-        // Prepare files for parallel download
-        let files_for_download: Vec<(String, PathBuf, String)> = files_to_download
+        // Prepare files for parallel download (primary URL plus mirrors)
+        let files_for_download: Vec<(Vec<String>, PathBuf, String)> = files_to_download
             .iter()
             .map(|file_entry| {
-                let target_path = minecraft_dir.join(&file_entry.path);
-                (
-                    file_entry.downloadUrl.clone(),
+                let target_path = file_entry.resolve_path(&minecraft_dir)?;
+                Ok((
+                    file_entry.install_sources(&manifest.base_mirrors, Path::new(instance_dir)),
                     target_path,
                     file_entry.fileHash.clone(),
-                )
+                ))
             })
-            .collect();
+            .collect::<Result<_, String>>()?;
 
         let total_downloads = files_for_download.len();
         let instance_clone = instance.clone();
         let task_id_clone = task_id.clone();
 
         // Use parallel downloads with progress callback
-        download_manager
-            .download_files_parallel_with_progress(
+        let served_by = download_manager
+            .download_sources_parallel_with_progress(
                 files_for_download,
-                move |current, total, message| {
+                move |current, total, message, source| {
                     // Update task progress - only report progress for actual downloads
                     if let Some(ref tid) = task_id_clone {
                         let progress = (current as f32 / total as f32) * 100.0;
@@ -674,7 +807,7 @@ pub async fn download_and_install_files(
                             TaskStatus::Running,
                             progress,
                             &format!("Descargando archivo {} de {}: {}", current, total, message),
-                            None,
+                            Some(serde_json::json!({ "lastServedBy": source })),
                         );
                     }
 
@@ -689,6 +822,10 @@ pub async fn download_and_install_files(
             .await
             .map_err(|e| format!("Failed to download files: {}", e))?;
 
+        if let Some(ref tid) = task_id {
+            report_served_by(tid, &files_to_download, &served_by);
+        }
+
         files_processed += files_to_download.len();
     } else {
         // No files need downloading, just report that processing is complete
@@ -852,17 +989,18 @@ fn is_directory_empty(dir: &Path) -> Result<bool, String> {
     Ok(entries.count() == 0)
 }
 
-async fn file_exists_with_correct_hash(file_path: &Path, expected_hash: &str) -> bool {
-    if !file_path.exists() {
+fn file_exists_with_correct_hash(
+    file_path: &Path,
+    expected_hash: &str,
+    hash_index: &mut FileHashIndex,
+) -> bool {
+    if !file_path.is_file() {
         return false;
     }
 
-    // Read file and compute hash
-    match fs::read(file_path) {
-        Ok(contents) => {
-            let computed_hash = compute_file_hash(&contents);
-            computed_hash == expected_hash
-        }
+    // Only re-hashes the file if its size or mtime changed since the last check
+    match hash_index.hash_of(file_path) {
+        Ok(computed_hash) => computed_hash.eq_ignore_ascii_case(expected_hash),
         Err(_) => false,
     }
 }
@@ -975,6 +1113,10 @@ pub fn audit_user_data_protection(
         instanceDirectory: None,
         forgeVersion: None,
         javaPath: None,
+        javaPathPinned: false,
+        loader: None,
+        loaderVersion: None,
+        launchOptions: Default::default(),
     };
 
     let essential_paths = get_essential_minecraft_paths(minecraft_dir, &dummy_instance);
@@ -1123,12 +1265,13 @@ pub async fn validate_modpack_assets(
     }
 
     // Build hash-to-path map for efficient lookup
-    let hash_map = build_hash_to_path_map(&minecraft_dir)?;
+    let mut hash_index = FileHashIndex::load(&instance_dir, &minecraft_dir);
+    let hash_map = build_hash_to_path_map(&minecraft_dir, &mut hash_index)?;
 
     let total_files = manifest.files.len();
 
     for (index, file_entry) in manifest.files.iter().enumerate() {
-        let file_path = minecraft_dir.join(&file_entry.path);
+        let file_path = file_entry.resolve_path(&minecraft_dir)?;
 
         // Update progress
         if let Some(task_id) = &task_id {
@@ -1178,7 +1321,7 @@ pub async fn validate_modpack_assets(
 
             // Check file hash if size is correct
             if !needs_download
-                && !file_exists_with_correct_hash(&file_path, &file_entry.fileHash).await
+                && !file_exists_with_correct_hash(&file_path, &file_entry.fileHash, &mut hash_index)
             {
                 // Check if correct hash exists elsewhere
                 if !hash_map.contains_key(&file_entry.fileHash) {
@@ -1193,6 +1336,10 @@ pub async fn validate_modpack_assets(
         }
     }
 
+    if let Err(e) = hash_index.save() {
+        log::warn!("[Validation] Failed to save file hash index: {}", e);
+    }
+
     if let Some(task_id) = &task_id {
         update_task(
             task_id,
@@ -1240,98 +1387,15 @@ impl DownloadManager {
         self.max_concurrent_downloads
     }
 
-    /// Download a single file with streaming and hash verification
+    /// Download a single file with streaming and hash verification.
+    /// Interrupted downloads are resumed from their `.part` file when possible.
     pub async fn download_file_with_hash(
         &self,
         url: &str,
         target_path: &Path,
         expected_hash: &str,
     ) -> Result<(), String> {
-        const MAX_RETRIES: usize = 3;
-
-        for attempt in 1..=MAX_RETRIES {
-            match self
-                .download_file_attempt(url, target_path, expected_hash)
-                .await
-            {
-                Ok(()) => return Ok(()),
-                Err(e) => {
-                    if attempt == MAX_RETRIES {
-                        return Err(format!("Failed after {} attempts: {}", MAX_RETRIES, e));
-                    }
-                    log::warn!("Download attempt {} failed: {}, retrying...", attempt, e);
-
-                    // Clean up partial file on retry
-                    if target_path.exists() {
-                        let _ = fs::remove_file(target_path);
-                    }
-                }
-            }
-        }
-
-        unreachable!()
-    }
-
-    /// Single download attempt with streaming and hash verification
-    async fn download_file_attempt(
-        &self,
-        url: &str,
-        target_path: &Path,
-        expected_hash: &str,
-    ) -> Result<(), String> {
-        // Create parent directories if needed
-        if let Some(parent) = target_path.parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
-        }
-
-        // Start the download
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| format!("Failed to connect to {}: {}", url, e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("HTTP error {} for {}", response.status(), url));
-        }
-
-        // Create the output file
-        let mut file = fs::File::create(target_path)
-            .map_err(|e| format!("Failed to create file {}: {}", target_path.display(), e))?;
-
-        // Initialize hash calculator
-        let mut hasher = Sha1::new();
-        let mut stream = response.bytes_stream();
-
-        // Stream the content directly to disk while computing hash
-        while let Some(chunk_result) = stream.next().await {
-            let chunk =
-                chunk_result.map_err(|e| format!("Failed to read chunk from stream: {}", e))?;
-
-            // Update hash with chunk
-            hasher.update(&chunk);
-
-            // Write chunk to file
-            file.write_all(&chunk)
-                .map_err(|e| format!("Failed to write to file: {}", e))?;
-        }
-
-        // Ensure all data is written to disk
-        file.sync_all()
-            .map_err(|e| format!("Failed to sync file to disk: {}", e))?;
-
-        // Verify hash
-        let computed_hash = format!("{:x}", hasher.finalize());
-        if computed_hash != expected_hash {
-            return Err(format!(
-                "Hash mismatch: expected {}, got {}",
-                expected_hash, computed_hash
-            ));
-        }
-
-        Ok(())
+        Self::download_file_with_hash_static(&self.client, url, target_path, expected_hash).await
     }
 
     /// Download multiple files sequentially with progress reporting
@@ -1405,10 +1469,39 @@ impl DownloadManager {
     ) -> Result<usize, String>
     where
         F: FnMut(usize, usize, &str) + Send + 'static,
+    {
+        let sources = files
+            .into_iter()
+            .map(|(url, target_path, expected_hash)| (vec![url], target_path, expected_hash))
+            .collect();
+
+        let served_by = self
+            .download_sources_parallel_with_progress(sources, move |current, total, message, _| {
+                progress_callback(current, total, message)
+            })
+            .await?;
+
+        Ok(served_by.len())
+    }
+
+    /// Same as `download_files_parallel_with_progress`, but every file carries a list of
+    /// source URLs (primary URL first, then mirrors). Sources are tried in order, skipping
+    /// ahead to the next one when a mirror fails or serves a wrong hash; such mirrors are
+    /// demoted for the rest of the session.
+    ///
+    /// The progress callback also receives the URL that served the file, and the returned
+    /// Vec holds that URL for each input file, in input order.
+    pub async fn download_sources_parallel_with_progress<F>(
+        &self,
+        files: Vec<(Vec<String>, PathBuf, String)>, // (urls, target_path, expected_hash)
+        progress_callback: F,
+    ) -> Result<Vec<String>, String>
+    where
+        F: FnMut(usize, usize, &str, &str) + Send + 'static,
     {
         let total_files = files.len();
         if total_files == 0 {
-            return Ok(0);
+            return Ok(Vec::new());
         }
 
         // Create semaphore to limit concurrent downloads
@@ -1419,7 +1512,7 @@ impl DownloadManager {
         let next_to_report = Arc::new(Mutex::new(0usize));
         // Box the callback into a trait object that is Send so it can be shared across spawned tasks
         let progress_callback = Arc::new(tokio::sync::Mutex::new(
-            Box::new(progress_callback) as Box<dyn FnMut(usize, usize, &str) + Send>
+            Box::new(progress_callback) as Box<dyn FnMut(usize, usize, &str, &str) + Send>
         ));
 
         // Clone file info for filename tracking
@@ -1437,7 +1530,7 @@ impl DownloadManager {
         // Spawn download tasks
         let mut download_tasks = Vec::new();
 
-        for (index, (url, target_path, expected_hash)) in files.into_iter().enumerate() {
+        for (index, (urls, target_path, expected_hash)) in files.into_iter().enumerate() {
             let semaphore = semaphore.clone();
             let client = self.client.clone();
             let completed_downloads = completed_downloads.clone();
@@ -1452,68 +1545,93 @@ impl DownloadManager {
                     .await
                     .map_err(|e| format!("Semaphore error: {}", e))?;
 
-                // Download the file
-                let result = Self::download_file_with_hash_static(
+                // Download the file from the first source that works
+                let served_by = Self::download_from_sources_static(
                     &client,
-                    &url,
+                    &urls,
                     &target_path,
                     &expected_hash,
                 )
-                .await;
-
-                if let Err(e) = result {
-                    return Err(format!(
-                        "Failed to download {}: {}",
-                        target_path.display(),
-                        e
-                    ));
-                }
+                .await
+                .map_err(|e| format!("Failed to download {}: {}", target_path.display(), e))?;
 
                 // Mark this download as complete and check if we can report progress
                 {
                     let mut completed = completed_downloads.lock().await;
-                    let file_name = target_path
-                        .file_name()
-                        .unwrap_or_default()
-                        .to_string_lossy()
-                        .to_string();
-                    completed.insert(index, file_name);
+                    completed.insert(index, served_by.clone());
 
                     let mut next = next_to_report.lock().await;
 
                     // Report progress for all consecutive completed downloads
-                    while completed.contains_key(&*next) {
-                        if let Some(_completed_file_name) = completed.get(&*next) {
-                            // Call progress callback with the correct filename for this index
-                            {
-                                let mut callback = progress_callback.lock().await; // Using tokio::sync::Mutex<Box<dyn FnMut...>>
-                                let msg = format!("Descargando {}", file_names[*next]);
-                                (&mut *callback)(*next + 1, total_files, &msg);
-                            }
+                    while let Some(source) = completed.remove(&*next) {
+                        // Call progress callback with the correct filename for this index
+                        {
+                            let mut callback = progress_callback.lock().await; // Using tokio::sync::Mutex<Box<dyn FnMut...>>
+                            let msg = format!("Descargando {}", file_names[*next]);
+                            (&mut *callback)(*next + 1, total_files, &msg, &source);
                         }
 
-                        completed.remove(&*next);
                         *next += 1;
                     }
                 }
 
-                Ok::<(), String>(())
+                Ok::<String, String>(served_by)
             });
 
             download_tasks.push(task);
         }
 
         // Wait for all downloads to complete
-        let mut download_count = 0;
+        let mut served_by = Vec::with_capacity(total_files);
         for task in download_tasks {
             match task.await {
-                Ok(Ok(())) => download_count += 1,
+                Ok(Ok(source)) => served_by.push(source),
                 Ok(Err(e)) => return Err(e),
                 Err(e) => return Err(format!("Task join error: {}", e)),
             }
         }
 
-        Ok(download_count)
+        Ok(served_by)
+    }
+
+    /// Tries each source URL (healthy mirrors first) and returns the one that served the file
+    async fn download_from_sources_static(
+        client: &reqwest::Client,
+        urls: &[String],
+        target_path: &Path,
+        expected_hash: &str,
+    ) -> Result<String, String> {
+        let mut errors = Vec::new();
+
+        for url in order_sources_by_health(urls) {
+            let result = if is_local_source(&url) {
+                copy_local_source(&url, target_path, expected_hash)
+            } else {
+                Self::download_file_with_hash_static(client, &url, target_path, expected_hash).await
+            };
+
+            match result {
+                Ok(()) => return Ok(url),
+                Err(e) => {
+                    if !is_local_source(&url) {
+                        demote_mirror(&url);
+                    }
+                    log::warn!(
+                        "[Mirrors] {} failed for {}: {}",
+                        url,
+                        target_path.display(),
+                        e
+                    );
+                    errors.push(format!("{}: {}", url, e));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            return Err("No download URL available".to_string());
+        }
+
+        Err(errors.join("; "))
     }
 
     /// Static version of download_file_with_hash for use in async tasks
@@ -1533,12 +1651,8 @@ impl DownloadManager {
                     if attempt == MAX_RETRIES {
                         return Err(format!("Failed after {} attempts: {}", MAX_RETRIES, e));
                     }
+                    // The .part file is kept so the next attempt resumes instead of restarting
                     log::warn!("Download attempt {} failed: {}, retrying...", attempt, e);
-
-                    // Clean up partial file on retry
-                    if target_path.exists() {
-                        let _ = fs::remove_file(target_path);
-                    }
                 }
             }
         }
@@ -1546,7 +1660,13 @@ impl DownloadManager {
         unreachable!()
     }
 
-    /// Static version of download_file_attempt for use in async tasks
+    /// Single download attempt, usable from spawned tasks
+    ///
+    /// Data is streamed into `<target>.part` together with a small metadata file holding the
+    /// URL, ETag and Content-Length. A later attempt (even after an app restart) checks those
+    /// against a HEAD request and, if they still match, continues with an HTTP `Range` request.
+    /// The file is only moved to `target_path` once its SHA-1 has been verified. With an empty
+    /// `expected_hash` its size must match the length the server reported instead.
     async fn download_file_attempt_static(
         client: &reqwest::Client,
         url: &str,
@@ -1559,33 +1679,116 @@ impl DownloadManager {
                 .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
         }
 
+        let part_path = partial_download_path(target_path);
+        let meta_path = partial_meta_path(target_path);
+
+        // Decide whether the existing partial file can be resumed
+        let mut stored_meta: Option<PartialDownloadMeta> = None;
+        let mut remote_len = None;
+        let mut resume_from = 0u64;
+        if part_path.exists() {
+            let existing_len = fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+            let local_meta = load_partial_meta(&meta_path);
+            let remote_meta = match &local_meta {
+                Some(meta) if meta.url == url && existing_len > 0 => {
+                    Self::probe_remote(client, url).await.ok()
+                }
+                _ => None,
+            };
+
+            match (local_meta, remote_meta) {
+                (Some(local), Some(remote)) if can_resume(&local, &remote, existing_len) => {
+                    log::info!(
+                        "Resuming download of {} from byte {}",
+                        target_path.display(),
+                        existing_len
+                    );
+                    resume_from = existing_len;
+                    remote_len = remote.content_length;
+                    stored_meta = Some(local);
+                }
+                _ => discard_partial_download(target_path),
+            }
+        }
+
         // Start the download
-        let response = client
-            .get(url)
+        let mut request = client.get(url);
+        if resume_from > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", resume_from));
+            if let Some(etag) = stored_meta.as_ref().and_then(|m| m.etag.as_ref()) {
+                request = request.header(reqwest::header::IF_RANGE, etag.as_str());
+            }
+        }
+
+        let response = request
             .send()
             .await
             .map_err(|e| format!("Failed to connect to {}: {}", url, e))?;
 
-        if !response.status().is_success() {
-            return Err(format!("HTTP error {} for {}", response.status(), url));
+        let status = response.status();
+        let resuming = resume_from > 0 && status == reqwest::StatusCode::PARTIAL_CONTENT;
+
+        if resume_from > 0 && status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            // The partial file may already be complete; let the hash (or length) check decide
+            let expected_len = stored_meta
+                .as_ref()
+                .and_then(|m| m.content_length)
+                .or(remote_len);
+            return finalize_partial_download(target_path, expected_hash, expected_len);
+        }
+
+        if !status.is_success() {
+            return Err(format!("HTTP error {} for {}", status, url));
+        }
+
+        if resuming {
+            // The server must be serving the same file we started with
+            let total = response
+                .headers()
+                .get(reqwest::header::CONTENT_RANGE)
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_content_range_total);
+            let expected_total = stored_meta.as_ref().and_then(|m| m.content_length);
+            if total.is_some() && expected_total.is_some() && total != expected_total {
+                discard_partial_download(target_path);
+                return Err(format!(
+                    "Remote file changed while resuming {}, restarting",
+                    url
+                ));
+            }
+        } else {
+            // Fresh download (or the server ignored the Range header): record what we are fetching
+            let meta = PartialDownloadMeta {
+                url: url.to_string(),
+                etag: response
+                    .headers()
+                    .get(reqwest::header::ETAG)
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string),
+                content_length: response.content_length(),
+            };
+            save_partial_meta(&meta_path, &meta)?;
+            stored_meta = Some(meta);
         }
 
-        // Create the output file
-        let mut file = fs::File::create(target_path)
-            .map_err(|e| format!("Failed to create file {}: {}", target_path.display(), e))?;
+        // Open the partial file, appending when resuming
+        let mut file = if resuming {
+            fs::OpenOptions::new()
+                .append(true)
+                .open(&part_path)
+                .map_err(|e| format!("Failed to open file {}: {}", part_path.display(), e))?
+        } else {
+            fs::File::create(&part_path)
+                .map_err(|e| format!("Failed to create file {}: {}", part_path.display(), e))?
+        };
 
-        // Initialize hash calculator
-        let mut hasher = Sha1::new();
         let mut stream = response.bytes_stream();
 
-        // Stream the content directly to disk while computing hash
+        // Stream the content directly to disk
         while let Some(chunk_result) = stream.next().await {
             let chunk =
                 chunk_result.map_err(|e| format!("Failed to read chunk from stream: {}", e))?;
 
-            // Update hash with chunk
-            hasher.update(&chunk);
-
             // Write chunk to file
             file.write_all(&chunk)
                 .map_err(|e| format!("Failed to write to file: {}", e))?;
@@ -1594,36 +1797,220 @@ impl DownloadManager {
         // Ensure all data is written to disk
         file.sync_all()
             .map_err(|e| format!("Failed to sync file to disk: {}", e))?;
+        drop(file);
+
+        // A short body means the connection dropped; keep the .part for the next attempt
+        let written = fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+        let expected_len = stored_meta.and_then(|m| m.content_length);
+        if let Some(expected_len) = expected_len {
+            if written < expected_len {
+                return Err(format!(
+                    "Incomplete download: {} of {} bytes received",
+                    written, expected_len
+                ));
+            }
+        }
+
+        // Without a Content-Length the body ran to a clean end, so what was written is the file
+        finalize_partial_download(
+            target_path,
+            expected_hash,
+            Some(expected_len.unwrap_or(written)),
+        )
+    }
+
+    /// Fetches the ETag and Content-Length of a remote file without downloading it
+    async fn probe_remote(
+        client: &reqwest::Client,
+        url: &str,
+    ) -> Result<PartialDownloadMeta, String> {
+        let response = client
+            .head(url)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to connect to {}: {}", url, e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("HTTP error {} for {}", response.status(), url));
+        }
+
+        // HEAD responses have no body, so read the header rather than the body size hint
+        Ok(PartialDownloadMeta {
+            url: url.to_string(),
+            etag: response
+                .headers()
+                .get(reqwest::header::ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string),
+            content_length: response
+                .headers()
+                .get(reqwest::header::CONTENT_LENGTH)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse().ok()),
+        })
+    }
+}
+
+/// Metadata persisted next to a `.part` file so an interrupted download can be resumed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct PartialDownloadMeta {
+    url: String,
+    etag: Option<String>,
+    #[serde(rename = "contentLength")]
+    content_length: Option<u64>,
+}
+
+fn partial_download_path(target_path: &Path) -> PathBuf {
+    append_to_file_name(target_path, ".part")
+}
+
+fn partial_meta_path(target_path: &Path) -> PathBuf {
+    append_to_file_name(target_path, ".part.json")
+}
+
+fn append_to_file_name(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+fn load_partial_meta(meta_path: &Path) -> Option<PartialDownloadMeta> {
+    fs::read_to_string(meta_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+fn save_partial_meta(meta_path: &Path, meta: &PartialDownloadMeta) -> Result<(), String> {
+    let content = serde_json::to_string(meta)
+        .map_err(|e| format!("Failed to serialize download metadata: {}", e))?;
+    fs::write(meta_path, content).map_err(|e| {
+        format!(
+            "Failed to write download metadata {}: {}",
+            meta_path.display(),
+            e
+        )
+    })
+}
+
+fn discard_partial_download(target_path: &Path) {
+    let _ = fs::remove_file(partial_download_path(target_path));
+    let _ = fs::remove_file(partial_meta_path(target_path));
+}
+
+/// A partial download is only resumable if the remote still reports the same ETag and size,
+/// and the local file hasn't grown past it. Without any validator we can't trust a resume.
+fn can_resume(
+    local: &PartialDownloadMeta,
+    remote: &PartialDownloadMeta,
+    existing_len: u64,
+) -> bool {
+    if local.etag.is_none() && local.content_length.is_none() {
+        return false;
+    }
+    if local.etag.is_some() && local.etag != remote.etag {
+        return false;
+    }
+    if local.content_length.is_some() && local.content_length != remote.content_length {
+        return false;
+    }
+    match local.content_length {
+        Some(total) => existing_len <= total,
+        None => true,
+    }
+}
+
+/// Extracts the total size from a `Content-Range: bytes start-end/total` header
+fn parse_content_range_total(value: &str) -> Option<u64> {
+    value
+        .rsplit('/')
+        .next()
+        .filter(|total| *total != "*")
+        .and_then(|total| total.trim().parse().ok())
+}
 
-        // Verify hash
-        let computed_hash = format!("{:x}", hasher.finalize());
-        if computed_hash != expected_hash {
+/// Copies a staged local file (`file://` source) into place with the same hash check as a download
+fn copy_local_source(url: &str, target_path: &Path, expected_hash: &str) -> Result<(), String> {
+    let source = url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.to_file_path().ok())
+        .ok_or_else(|| format!("Invalid local source: {}", url))?;
+
+    if let Some(parent) = target_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+
+    discard_partial_download(target_path);
+    let copied = fs::copy(&source, partial_download_path(target_path))
+        .map_err(|e| format!("Failed to copy {}: {}", source.display(), e))?;
+    finalize_partial_download(target_path, expected_hash, Some(copied))
+}
+
+/// Verifies the completed `.part` file and moves it into place
+///
+/// Without an expected hash the file is only accepted when its size matches `expected_len`;
+/// an unknown length can't prove the file is complete, so the partial is discarded.
+fn finalize_partial_download(
+    target_path: &Path,
+    expected_hash: &str,
+    expected_len: Option<u64>,
+) -> Result<(), String> {
+    let part_path = partial_download_path(target_path);
+
+    if expected_hash.is_empty() {
+        let actual_len = fs::metadata(&part_path)
+            .map(|m| m.len())
+            .map_err(|e| format!("Failed to read {}: {}", part_path.display(), e))?;
+        if expected_len != Some(actual_len) {
+            discard_partial_download(target_path);
+            return Err(match expected_len {
+                Some(expected_len) => format!(
+                    "Size mismatch: expected {} bytes, got {}",
+                    expected_len, actual_len
+                ),
+                None => format!(
+                    "Cannot verify {} without a hash or a known size",
+                    target_path.display()
+                ),
+            });
+        }
+    } else {
+        let computed_hash = sha1_of_file(&part_path)?;
+        if !computed_hash.eq_ignore_ascii_case(expected_hash) {
+            // Corrupt data can't be resumed, start from scratch next time
+            discard_partial_download(target_path);
             return Err(format!(
                 "Hash mismatch: expected {}, got {}",
                 expected_hash, computed_hash
             ));
         }
-
-        Ok(())
     }
+
+    fs::rename(&part_path, target_path)
+        .map_err(|e| format!("Failed to move {} into place: {}", target_path.display(), e))?;
+    let _ = fs::remove_file(partial_meta_path(target_path));
+
+    Ok(())
 }
 
+#[cfg(test)]
 fn compute_file_hash(contents: &[u8]) -> String {
     let mut hasher = Sha1::new();
     hasher.update(contents);
     format!("{:x}", hasher.finalize())
 }
 
-/// Calculate hash for a file at the given path
+/// Calculate hash for a file at the given path without loading it into memory
 fn calculate_file_hash(file_path: &Path) -> Result<String, String> {
-    let contents = fs::read(file_path)
-        .map_err(|e| format!("Failed to read file {}: {}", file_path.display(), e))?;
-    Ok(compute_file_hash(&contents))
+    sha1_of_file(file_path)
 }
 
 /// Builds a map of file hash -> path for all files in the instance directory
 /// This helps identify files that exist but may be in the wrong location
-fn build_hash_to_path_map(minecraft_dir: &Path) -> Result<HashMap<String, PathBuf>, String> {
+fn build_hash_to_path_map(
+    minecraft_dir: &Path,
+    hash_index: &mut FileHashIndex,
+) -> Result<HashMap<String, PathBuf>, String> {
     let mut hash_map = HashMap::new();
 
     // Only scan modpack-related directories to avoid performance issues
@@ -1642,7 +2029,7 @@ fn build_hash_to_path_map(minecraft_dir: &Path) -> Result<HashMap<String, PathBu
     for dir_name in scan_dirs {
         let dir_path = minecraft_dir.join(dir_name);
         if dir_path.exists() && dir_path.is_dir() {
-            scan_directory_for_hashes(&dir_path, minecraft_dir, &mut hash_map)?;
+            scan_directory_for_hashes(&dir_path, minecraft_dir, &mut hash_map, hash_index)?;
         }
     }
 
@@ -1651,8 +2038,7 @@ fn build_hash_to_path_map(minecraft_dir: &Path) -> Result<HashMap<String, PathBu
     for file_name in standalone_files {
         let file_path = minecraft_dir.join(file_name);
         if file_path.exists() && file_path.is_file() {
-            if let Ok(contents) = fs::read(&file_path) {
-                let hash = compute_file_hash(&contents);
+            if let Ok(hash) = hash_index.hash_of(&file_path) {
                 let relative_path = file_path
                     .strip_prefix(minecraft_dir)
                     .map_err(|_| "Failed to get relative path")?;
@@ -1670,6 +2056,7 @@ fn scan_directory_for_hashes(
     dir: &Path,
     minecraft_dir: &Path,
     hash_map: &mut HashMap<String, PathBuf>,
+    hash_index: &mut FileHashIndex,
 ) -> Result<(), String> {
     let entries = fs::read_dir(dir)
         .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
@@ -1679,15 +2066,14 @@ fn scan_directory_for_hashes(
         let path = entry.path();
 
         if path.is_file() {
-            if let Ok(contents) = fs::read(&path) {
-                let hash = compute_file_hash(&contents);
+            if let Ok(hash) = hash_index.hash_of(&path) {
                 let relative_path = path
                     .strip_prefix(minecraft_dir)
                     .map_err(|_| "Failed to get relative path")?;
                 hash_map.insert(hash, relative_path.to_path_buf());
             }
         } else if path.is_dir() {
-            scan_directory_for_hashes(&path, minecraft_dir, hash_map)?;
+            scan_directory_for_hashes(&path, minecraft_dir, hash_map, hash_index)?;
         }
     }
 
@@ -1865,6 +2251,18 @@ pub async fn validate_and_download_modpack_assets(instance_id: String) -> Result
     let manifest = match fetch_modpack_manifest(modpack_id, version_id).await {
         Ok(manifest) => manifest,
         Err(e) => {
+            // A manifest that fails signature verification must never be treated as "offline"
+            if is_signature_error(&e) {
+                if let Ok(bootstrap_error) = serde_json::from_str::<BootstrapError>(&e) {
+                    update_task_with_bootstrap_error(&task_id, &bootstrap_error);
+                }
+                log::error!(
+                    "Manifest signature verification failed, aborting launch: {}",
+                    e
+                );
+                return Err(e);
+            }
+
             update_task(
                 &task_id,
                 TaskStatus::Failed,
@@ -1986,8 +2384,13 @@ pub async fn validate_and_download_modpack_assets(instance_id: String) -> Result
         None,
     );
 
-    let downloaded_count =
-        download_modpack_files(&instance, &files_to_download, Some(task_id.clone())).await?;
+    let downloaded_count = download_modpack_files(
+        &instance,
+        &files_to_download,
+        &manifest.base_mirrors,
+        Some(task_id.clone()),
+    )
+    .await?;
 
     emit_bootstrap_complete(&instance, "forge");
 
@@ -2020,9 +2423,45 @@ pub async fn validate_and_download_modpack_assets(instance_id: String) -> Result
     Ok(downloaded_count)
 }
 
+/// Stores in the task data which URL served each downloaded file (path -> url)
+fn report_served_by<E: std::borrow::Borrow<ModpackFileEntry>>(
+    task_id: &str,
+    files: &[E],
+    served_by: &[String],
+) {
+    let served_map: serde_json::Map<String, serde_json::Value> = files
+        .iter()
+        .zip(served_by)
+        .map(|(entry, url)| (entry.borrow().path.clone(), serde_json::json!(url)))
+        .collect();
+    let mirrored = files
+        .iter()
+        .zip(served_by)
+        .filter(|(entry, url)| entry.borrow().downloadUrl != **url)
+        .count();
+
+    if mirrored > 0 {
+        log::info!("[Mirrors] {} files were served by mirrors", mirrored);
+    }
+
+    if let Some(task) = crate::core::tasks_manager::get_task(task_id) {
+        update_task(
+            task_id,
+            task.status,
+            task.progress,
+            &task.message,
+            Some(serde_json::json!({
+                "servedBy": served_map,
+                "mirroredFiles": mirrored
+            })),
+        );
+    }
+}
+
 async fn download_modpack_files(
     instance: &MinecraftInstance,
     files: &[ModpackFileEntry],
+    base_mirrors: &[String],
     task_id: Option<String>,
 ) -> Result<usize, String> {
     let instance_dir = PathBuf::from(
@@ -2047,48 +2486,57 @@ async fn download_modpack_files(
         &initial_stage,
     );
 
-    // Prepare files for download with (url, target_path, expected_hash) format
-    let files_to_download: Vec<(String, PathBuf, String)> = files
+    // Prepare files for download with (urls, target_path, expected_hash) format
+    let files_to_download: Vec<(Vec<String>, PathBuf, String)> = files
         .iter()
         .map(|file_entry| {
-            let target_path = minecraft_dir.join(&file_entry.path);
-            (
-                file_entry.downloadUrl.clone(),
+            let target_path = file_entry.resolve_path(&minecraft_dir)?;
+            Ok((
+                file_entry.install_sources(base_mirrors, &instance_dir),
                 target_path,
                 file_entry.fileHash.clone(),
-            )
+            ))
         })
-        .collect();
+        .collect::<Result<_, String>>()?;
 
     // Use parallel downloads with progress callback for task and stage updates
     let instance_clone = instance.clone();
     let task_id_clone = task_id.clone();
 
-    let downloaded_count = download_manager
-        .download_files_parallel_with_progress(files_to_download, move |current, total, message| {
-            // Update task progress
-            if let Some(ref tid) = task_id_clone {
-                let progress = (current as f32 / total as f32) * 100.0;
-                update_task(
-                    tid,
-                    TaskStatus::Running,
-                    progress,
-                    &format!("{} ({}/{})", message, current, total),
-                    None,
-                );
-            }
+    let served_by = download_manager
+        .download_sources_parallel_with_progress(
+            files_to_download,
+            move |current, total, message, source| {
+                // Update task progress
+                if let Some(ref tid) = task_id_clone {
+                    let progress = (current as f32 / total as f32) * 100.0;
+                    update_task(
+                        tid,
+                        TaskStatus::Running,
+                        progress,
+                        &format!("{} ({}/{})", message, current, total),
+                        Some(serde_json::json!({ "lastServedBy": source })),
+                    );
+                }
 
-            // Emit status with stage
-            let stage = Stage::DownloadingModpackFiles { current, total };
-            emit_status_with_stage(
-                &instance_clone,
-                "instance-downloading-modpack-files",
-                &stage,
-            );
-        })
+                // Emit status with stage
+                let stage = Stage::DownloadingModpackFiles { current, total };
+                emit_status_with_stage(
+                    &instance_clone,
+                    "instance-downloading-modpack-files",
+                    &stage,
+                );
+            },
+        )
         .await
         .map_err(|e| format!("Parallel download failed: {}", e))?;
 
+    if let Some(ref tid) = task_id {
+        report_served_by(tid, files, &served_by);
+    }
+
+    let downloaded_count = served_by.len();
+
     emit_status(
         instance,
         "instance-finish-assets-download",
@@ -2198,7 +2646,8 @@ mod tests {
         let _ = std::fs::write(&test_file, test_content);
 
         // Build hash map
-        let hash_map = build_hash_to_path_map(&temp_dir).unwrap();
+        let mut hash_index = FileHashIndex::load(&temp_dir, &temp_dir);
+        let hash_map = build_hash_to_path_map(&temp_dir, &mut hash_index).unwrap();
 
         // Verify the file was found and mapped correctly
         let expected_hash = compute_file_hash(test_content);
@@ -2437,4 +2886,359 @@ mod tests {
         // Cleanup
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
+
+    fn partial_meta(etag: Option<&str>, content_length: Option<u64>) -> PartialDownloadMeta {
+        PartialDownloadMeta {
+            url: "http://example.invalid/file.jar".to_string(),
+            etag: etag.map(str::to_string),
+            content_length,
+        }
+    }
+
+    #[test]
+    fn test_can_resume_requires_matching_validators() {
+        let local = partial_meta(Some("\"v1\""), Some(100));
+
+        assert!(can_resume(
+            &local,
+            &partial_meta(Some("\"v1\""), Some(100)),
+            40
+        ));
+        assert!(!can_resume(
+            &local,
+            &partial_meta(Some("\"v2\""), Some(100)),
+            40
+        ));
+        assert!(!can_resume(
+            &local,
+            &partial_meta(Some("\"v1\""), Some(120)),
+            40
+        ));
+        // Local file larger than the remote one can't be a prefix of it
+        assert!(!can_resume(
+            &local,
+            &partial_meta(Some("\"v1\""), Some(100)),
+            150
+        ));
+        // Nothing to validate against
+        assert!(!can_resume(
+            &partial_meta(None, None),
+            &partial_meta(None, None),
+            40
+        ));
+    }
+
+    #[test]
+    fn test_parse_content_range_total() {
+        assert_eq!(parse_content_range_total("bytes 100-199/200"), Some(200));
+        assert_eq!(parse_content_range_total("bytes 0-0/*"), None);
+        assert_eq!(parse_content_range_total("garbage"), None);
+    }
+
+    #[test]
+    fn test_finalize_partial_download_discards_corrupt_data() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("mod.jar");
+        std::fs::write(partial_download_path(&target), b"corrupt").unwrap();
+        std::fs::write(partial_meta_path(&target), "{}").unwrap();
+
+        let result = finalize_partial_download(&target, &compute_file_hash(b"expected"), None);
+
+        assert!(result.is_err());
+        assert!(!target.exists());
+        assert!(!partial_download_path(&target).exists());
+        assert!(!partial_meta_path(&target).exists());
+    }
+
+    #[test]
+    fn test_finalize_partial_download_without_hash_checks_length() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("mod.jar");
+
+        // Size differs from what the server reported
+        std::fs::write(partial_download_path(&target), b"truncated").unwrap();
+        assert!(finalize_partial_download(&target, "", Some(100)).is_err());
+        assert!(!target.exists());
+        assert!(!partial_download_path(&target).exists());
+
+        // Nothing to compare against
+        std::fs::write(partial_download_path(&target), b"unknown").unwrap();
+        assert!(finalize_partial_download(&target, "", None).is_err());
+        assert!(!target.exists());
+        assert!(!partial_download_path(&target).exists());
+
+        std::fs::write(partial_download_path(&target), b"complete").unwrap();
+        finalize_partial_download(&target, "", Some(8)).unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), b"complete");
+    }
+
+    /// Minimal HTTP server that understands HEAD and ranged GET requests for a single file
+    fn spawn_range_server(
+        content: &'static [u8],
+        etag: &'static str,
+    ) -> (String, Arc<StdMutex<Vec<String>>>) {
+        use std::io::{BufRead, BufReader, Write as _};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/file.jar", listener.local_addr().unwrap());
+        let requests = Arc::new(StdMutex::new(Vec::new()));
+        let requests_clone = requests.clone();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => break,
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut range_start = None;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    let lower = line.to_ascii_lowercase();
+                    if let Some(value) = lower.strip_prefix("range: bytes=") {
+                        range_start = value.trim().trim_end_matches('-').parse::<usize>().ok();
+                    }
+                }
+
+                let is_head = request_line.starts_with("HEAD");
+                requests_clone.lock().unwrap().push(format!(
+                    "{} range={:?}",
+                    if is_head { "HEAD" } else { "GET" },
+                    range_start
+                ));
+
+                let (status, body, extra) = match range_start {
+                    Some(start) if !is_head => (
+                        "206 Partial Content",
+                        &content[start..],
+                        format!(
+                            "Content-Range: bytes {}-{}/{}\r\n",
+                            start,
+                            content.len() - 1,
+                            content.len()
+                        ),
+                    ),
+                    _ => ("200 OK", content, String::new()),
+                };
+
+                let header = format!(
+                    "HTTP/1.1 {}\r\nETag: {}\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n",
+                    status,
+                    etag,
+                    body.len(),
+                    extra
+                );
+                let _ = stream.write_all(header.as_bytes());
+                if !is_head {
+                    let _ = stream.write_all(body);
+                }
+            }
+        });
+
+        (url, requests)
+    }
+
+    #[tokio::test]
+    async fn test_download_resumes_from_partial_file() {
+        const CONTENT: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+        let (url, requests) = spawn_range_server(CONTENT, "\"v1\"");
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("mods").join("big.jar");
+        std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+
+        // Simulate a download interrupted in a previous session
+        std::fs::write(partial_download_path(&target), &CONTENT[..10]).unwrap();
+        save_partial_meta(
+            &partial_meta_path(&target),
+            &PartialDownloadMeta {
+                url: url.clone(),
+                etag: Some("\"v1\"".to_string()),
+                content_length: Some(CONTENT.len() as u64),
+            },
+        )
+        .unwrap();
+
+        DownloadManager::new()
+            .download_file_with_hash(&url, &target, &compute_file_hash(CONTENT))
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&target).unwrap(), CONTENT);
+        assert!(!partial_download_path(&target).exists());
+        assert!(!partial_meta_path(&target).exists());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests.contains(&"GET range=Some(10)".to_string()));
+        assert!(!requests.contains(&"GET range=None".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_download_restarts_when_remote_changed() {
+        const CONTENT: &[u8] = b"new remote content that replaced the old one";
+        let (url, requests) = spawn_range_server(CONTENT, "\"v2\"");
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("big.jar");
+
+        std::fs::write(partial_download_path(&target), b"old remote").unwrap();
+        save_partial_meta(
+            &partial_meta_path(&target),
+            &PartialDownloadMeta {
+                url: url.clone(),
+                etag: Some("\"v1\"".to_string()),
+                content_length: Some(CONTENT.len() as u64),
+            },
+        )
+        .unwrap();
+
+        DownloadManager::new()
+            .download_file_with_hash(&url, &target, &compute_file_hash(CONTENT))
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&target).unwrap(), CONTENT);
+        assert!(requests
+            .lock()
+            .unwrap()
+            .contains(&"GET range=None".to_string()));
+    }
+
+    fn file_entry(url: &str, mirrors: &[&str]) -> ModpackFileEntry {
+        ModpackFileEntry {
+            fileHash: String::new(),
+            path: "mods/example.jar".to_string(),
+            file: ModpackFileType {
+                size: 0,
+                r#type: "mod".to_string(),
+            },
+            downloadUrl: url.to_string(),
+            mirrors: mirrors.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_source_urls_order_and_base_mirrors() {
+        let entry = file_entry(
+            "https://cdn.example.com/files/abc/example.jar",
+            &["https://mirror-a.example.org/example.jar"],
+        );
+        let base_mirrors = vec![
+            "https://backup.example.net/cdn/".to_string(),
+            "https://cdn.example.com".to_string(),
+        ];
+
+        assert_eq!(
+            entry.source_urls(&base_mirrors),
+            vec![
+                "https://cdn.example.com/files/abc/example.jar".to_string(),
+                "https://mirror-a.example.org/example.jar".to_string(),
+                "https://backup.example.net/cdn/files/abc/example.jar".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_manifest_without_mirrors_still_parses() {
+        let manifest: ModpackManifest = serde_json::from_value(serde_json::json!({
+            "id": "pack",
+            "version": "1",
+            "mcVersion": "1.20.1",
+            "forgeVersion": null,
+            "files": [{
+                "fileHash": "abc",
+                "path": "mods/a.jar",
+                "file": { "size": 1, "type": "mod" },
+                "downloadUrl": "https://cdn.example.com/a.jar"
+            }]
+        }))
+        .unwrap();
+
+        assert!(manifest.base_mirrors.is_empty());
+        assert!(manifest.files[0].mirrors.is_empty());
+    }
+
+    #[test]
+    fn test_malicious_manifest_paths_are_rejected() {
+        let manifest: ModpackManifest = serde_json::from_value(serde_json::json!({
+            "id": "pack",
+            "version": "1",
+            "mcVersion": "1.20.1",
+            "forgeVersion": null,
+            "files": [
+                { "fileHash": "a", "path": "mods/ok.jar", "file": { "size": 1, "type": "mod" }, "downloadUrl": "https://cdn.example.com/ok.jar" },
+                { "fileHash": "b", "path": "../../../.bashrc", "file": { "size": 1, "type": "config" }, "downloadUrl": "https://cdn.example.com/b" },
+                { "fileHash": "c", "path": "C:\\Users\\Public\\evil.exe", "file": { "size": 1, "type": "config" }, "downloadUrl": "https://cdn.example.com/c" },
+                { "fileHash": "d", "path": "/usr/local/bin/evil", "file": { "size": 1, "type": "config" }, "downloadUrl": "https://cdn.example.com/d" }
+            ]
+        }))
+        .unwrap();
+        let minecraft_dir = Path::new("/instances/pack/minecraft");
+
+        assert_eq!(
+            manifest.files[0].resolve_path(minecraft_dir).unwrap(),
+            minecraft_dir.join("mods").join("ok.jar")
+        );
+        for entry in &manifest.files[1..] {
+            assert!(entry.resolve_path(minecraft_dir).is_err(), "{}", entry.path);
+        }
+    }
+
+    #[test]
+    fn test_demoted_mirrors_are_tried_last() {
+        let urls = vec![
+            "https://demoted-mirror.test/a.jar".to_string(),
+            "https://healthy-mirror.test/a.jar".to_string(),
+        ];
+
+        demote_mirror("https://demoted-mirror.test/other.jar");
+
+        assert_eq!(
+            order_sources_by_health(&urls),
+            vec![urls[1].clone(), urls[0].clone()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_falls_back_to_mirror() {
+        const CONTENT: &[u8] = b"mirrored jar content";
+        let (mirror_url, _) = spawn_range_server(CONTENT, "\"m1\"");
+
+        // Grab a free port and close it so the primary URL refuses connections
+        let dead_primary = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/file.jar", listener.local_addr().unwrap())
+        };
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("mods").join("example.jar");
+
+        let served_by = DownloadManager::new()
+            .download_sources_parallel_with_progress(
+                vec![(
+                    vec![dead_primary.clone(), mirror_url.clone()],
+                    target.clone(),
+                    compute_file_hash(CONTENT),
+                )],
+                |_, _, _, _| {},
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(served_by, vec![mirror_url]);
+        assert_eq!(std::fs::read(&target).unwrap(), CONTENT);
+        // The dead primary stays demoted for the rest of the session
+        assert!(MIRROR_FAILURES
+            .lock()
+            .unwrap()
+            .get(&mirror_key(&dead_primary))
+            .is_some());
+    }
 }
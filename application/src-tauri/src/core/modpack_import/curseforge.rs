@@ -0,0 +1,684 @@
+// src-tauri/src/core/modpack_import/curseforge.rs
+// CurseForge modpack export (.zip with manifest.json) import
+
+use super::{
+    file_type_for_path, merge_overrides, normalize_archive_path, run_import, stage_overrides,
+    ImportedLoader, ImportedPack,
+};
+use crate::core::bootstrap::store::is_valid_sha1;
+use crate::core::modpack_file_manager::{ModpackFileEntry, ModpackFileType, ModpackManifest};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use tauri_plugin_http::reqwest;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const DEFAULT_OVERRIDES: &str = "overrides";
+const API_BASE_URL: &str = "https://api.curseforge.com/v1";
+
+/// The CurseForge API key is provided at build time through `CURSEFORGE_API_KEY`
+const API_KEY_ENV: Option<&str> = option_env!("CURSEFORGE_API_KEY");
+
+/// CurseForge hash algorithm ids (`HashAlgo` in the API)
+const HASH_ALGO_SHA1: u32 = 1;
+
+/// CurseForge class ids (`classId` of a project) of the content a modpack can ship
+const CLASS_MODS: u32 = 6;
+const CLASS_RESOURCE_PACKS: u32 = 12;
+const CLASS_WORLDS: u32 = 17;
+const CLASS_SHADER_PACKS: u32 = 6552;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CurseForgeManifest {
+    minecraft: CurseForgeMinecraft,
+    manifest_type: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    files: Vec<CurseForgeManifestFile>,
+    #[serde(default)]
+    overrides: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CurseForgeMinecraft {
+    version: String,
+    #[serde(default)]
+    mod_loaders: Vec<CurseForgeModLoader>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurseForgeModLoader {
+    id: String,
+    #[serde(default)]
+    primary: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurseForgeManifestFile {
+    #[serde(rename = "projectID")]
+    project_id: u32,
+    #[serde(rename = "fileID")]
+    file_id: u32,
+    #[serde(default = "default_required")]
+    required: bool,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+/// A `projectID`/`fileID` pair from `manifest.json`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CurseForgeFileRef {
+    pub project_id: u32,
+    pub file_id: u32,
+}
+
+/// Download information for a CurseForge file
+#[derive(Debug, Clone)]
+pub struct ResolvedFile {
+    pub file_id: u32,
+    pub file_name: String,
+    pub sha1: String,
+    pub size: u64,
+    /// `None` when the author opted out of third-party distribution
+    pub download_url: Option<String>,
+    /// `classId` of the project, which decides the folder the file goes to
+    pub class_id: Option<u32>,
+}
+
+/// Turns `projectID`/`fileID` pairs into downloadable files.
+///
+/// Files the resolver does not return make the import fail, so an implementation may
+/// resolve in batches and return the results in any order.
+pub trait FileResolver: Send {
+    fn resolve(&self, files: &[CurseForgeFileRef]) -> Result<Vec<ResolvedFile>, String>;
+}
+
+/// Resolver backed by the official CurseForge API
+pub struct CurseForgeApiResolver {
+    client: reqwest::blocking::Client,
+    api_key: String,
+}
+
+impl CurseForgeApiResolver {
+    /// Creates a resolver using the API key baked into this build
+    pub fn from_build_env() -> Result<Self, String> {
+        let api_key = API_KEY_ENV
+            .filter(|key| !key.trim().is_empty())
+            .ok_or("Esta versión del launcher no tiene acceso a la API de CurseForge")?;
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(|e| format!("Error creando cliente HTTP: {}", e))?;
+
+        Ok(Self {
+            client,
+            api_key: api_key.to_string(),
+        })
+    }
+
+    fn post<T: DeserializeOwned>(&self, path: &str, body: serde_json::Value) -> Result<T, String> {
+        let response = self
+            .client
+            .post(format!("{}{}", API_BASE_URL, path))
+            .header("x-api-key", &self.api_key)
+            .header("Accept", "application/json")
+            .json(&body)
+            .send()
+            .map_err(|e| format!("Error consultando la API de CurseForge: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "La API de CurseForge respondió con estado {}",
+                response.status()
+            ));
+        }
+
+        response
+            .json()
+            .map_err(|e| format!("Respuesta inválida de la API de CurseForge: {}", e))
+    }
+}
+
+impl FileResolver for CurseForgeApiResolver {
+    fn resolve(&self, files: &[CurseForgeFileRef]) -> Result<Vec<ResolvedFile>, String> {
+        let mut api_files = Vec::with_capacity(files.len());
+
+        // The batch endpoints accept a limited number of ids per request
+        for chunk in files.chunks(100) {
+            let file_ids: Vec<u32> = chunk.iter().map(|f| f.file_id).collect();
+            let body: ApiResponse<ApiFile> =
+                self.post("/mods/files", serde_json::json!({ "fileIds": file_ids }))?;
+            api_files.extend(body.data);
+        }
+
+        // Files do not carry the class of their project, the mods endpoint does
+        let mut mod_ids: Vec<u32> = api_files.iter().map(|file| file.mod_id).collect();
+        mod_ids.sort_unstable();
+        mod_ids.dedup();
+        let mut classes = HashMap::new();
+        for chunk in mod_ids.chunks(100) {
+            let body: ApiResponse<ApiMod> =
+                self.post("/mods", serde_json::json!({ "modIds": chunk }))?;
+            classes.extend(
+                body.data
+                    .into_iter()
+                    .filter_map(|m| Some((m.id, m.class_id?))),
+            );
+        }
+
+        Ok(api_files
+            .into_iter()
+            .filter_map(|file| {
+                let class_id = classes.get(&file.mod_id).copied();
+                file.into_resolved(class_id)
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    data: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiMod {
+    id: u32,
+    #[serde(default)]
+    class_id: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiFile {
+    id: u32,
+    mod_id: u32,
+    file_name: String,
+    #[serde(default)]
+    file_length: u64,
+    #[serde(default)]
+    download_url: Option<String>,
+    #[serde(default)]
+    hashes: Vec<ApiFileHash>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiFileHash {
+    value: String,
+    algo: u32,
+}
+
+impl ApiFile {
+    fn into_resolved(self, class_id: Option<u32>) -> Option<ResolvedFile> {
+        let sha1 = self
+            .hashes
+            .iter()
+            .find(|h| h.algo == HASH_ALGO_SHA1)?
+            .value
+            .to_ascii_lowercase();
+
+        Some(ResolvedFile {
+            file_id: self.id,
+            file_name: self.file_name,
+            sha1,
+            size: self.file_length,
+            // The API leaves it out when the author opted out of third-party downloads
+            download_url: self.download_url.filter(|url| !url.trim().is_empty()),
+            class_id,
+        })
+    }
+}
+
+/// Reads a CurseForge export, resolving its files and staging overrides under `staging_root`
+pub fn read_curseforge_zip(
+    archive_path: &Path,
+    staging_root: &Path,
+    resolver: &dyn FileResolver,
+) -> Result<ImportedPack, String> {
+    let file = fs::File::open(archive_path)
+        .map_err(|e| format!("No se pudo abrir {}: {}", archive_path.display(), e))?;
+    let mut archive = zip::ZipArchive::new(std::io::BufReader::new(file))
+        .map_err(|e| format!("El archivo no es un ZIP válido: {}", e))?;
+
+    let manifest: CurseForgeManifest = {
+        let mut entry = archive
+            .by_name(MANIFEST_FILE)
+            .map_err(|_| "El archivo no es una exportación de CurseForge".to_string())?;
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| format!("Error leyendo {}: {}", MANIFEST_FILE, e))?;
+        serde_json::from_str(&content).map_err(|e| format!("{} inválido: {}", MANIFEST_FILE, e))?
+    };
+
+    if manifest.manifest_type != "minecraftModpack" {
+        return Err(format!(
+            "Tipo de manifest no soportado: {}",
+            manifest.manifest_type
+        ));
+    }
+
+    let loader = map_mod_loaders(&manifest.minecraft.mod_loaders)?;
+
+    let refs: Vec<CurseForgeFileRef> = manifest
+        .files
+        .iter()
+        .filter(|file| file.required)
+        .map(|file| CurseForgeFileRef {
+            project_id: file.project_id,
+            file_id: file.file_id,
+        })
+        .collect();
+
+    let mut files = resolve_files(&refs, resolver)?;
+
+    let overrides_dir = normalize_archive_path(
+        manifest
+            .overrides
+            .as_deref()
+            .filter(|dir| !dir.trim().is_empty())
+            .unwrap_or(DEFAULT_OVERRIDES),
+    )
+    .map_err(|e| format!("Carpeta de overrides inválida: {}", e))?;
+    let prefix = format!("{}/", overrides_dir);
+    let overrides = stage_overrides(&mut archive, &[prefix.as_str()], staging_root)?;
+    merge_overrides(&mut files, overrides);
+
+    let name = if manifest.name.trim().is_empty() {
+        "CurseForge".to_string()
+    } else {
+        manifest.name.clone()
+    };
+
+    let minecraft_version = manifest.minecraft.version;
+    let pack_manifest = ModpackManifest {
+        id: format!("curseforge:{}", name),
+        version: manifest.version,
+        mc_version: minecraft_version.clone(),
+        forge_version: loader.forge_version(),
+        quilt_version: loader.quilt_version(),
+        files,
+        base_mirrors: Vec::new(),
+        launch_options: None,
+    };
+
+    Ok(ImportedPack {
+        name,
+        minecraft_version,
+        loader,
+        cached_manifest: Some(pack_manifest.clone()),
+        manifest: pack_manifest,
+        base_instance: None,
+    })
+}
+
+/// Maps `modLoaders` ids (`forge-47.2.0`, `fabric-0.15.11`...) preferring the primary one
+fn map_mod_loaders(loaders: &[CurseForgeModLoader]) -> Result<ImportedLoader, String> {
+    let Some(loader) = loaders
+        .iter()
+        .find(|loader| loader.primary)
+        .or_else(|| loaders.first())
+    else {
+        return Ok(ImportedLoader::Vanilla);
+    };
+
+    let (kind, version) = loader
+        .id
+        .split_once('-')
+        .ok_or_else(|| format!("Mod loader inválido: {}", loader.id))?;
+    let version = version.to_string();
+
+    match kind {
+        "forge" => Ok(ImportedLoader::Forge(version)),
+        "neoforge" => Ok(ImportedLoader::NeoForge(version)),
+        "fabric" => Ok(ImportedLoader::Fabric(version)),
+        "quilt" => Ok(ImportedLoader::Quilt(version)),
+        _ => Err(format!("Mod loader desconocido: {}", loader.id)),
+    }
+}
+
+/// Folder a file goes to according to the class of its project. Files whose class is
+/// unknown are assumed to be mods, the bulk of any modpack.
+fn target_folder(class_id: Option<u32>) -> Result<&'static str, String> {
+    match class_id {
+        None | Some(CLASS_MODS) => Ok("mods"),
+        Some(CLASS_RESOURCE_PACKS) => Ok("resourcepacks"),
+        Some(CLASS_SHADER_PACKS) => Ok("shaderpacks"),
+        Some(CLASS_WORLDS) => Ok("saves"),
+        Some(other) => Err(format!(
+            "Tipo de contenido de CurseForge no soportado: {}",
+            other
+        )),
+    }
+}
+
+/// Resolves every file reference into a manifest entry in the folder of its class.
+/// Files whose authors opted out of third-party downloads fail the import, listed so the
+/// user can download them manually.
+fn resolve_files(
+    refs: &[CurseForgeFileRef],
+    resolver: &dyn FileResolver,
+) -> Result<Vec<ModpackFileEntry>, String> {
+    if refs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let resolved: HashMap<u32, ResolvedFile> = resolver
+        .resolve(refs)?
+        .into_iter()
+        .map(|file| (file.file_id, file))
+        .collect();
+
+    let mut entries = Vec::with_capacity(refs.len());
+    let mut manual_downloads = Vec::new();
+    for file_ref in refs {
+        let file = resolved.get(&file_ref.file_id).ok_or_else(|| {
+            format!(
+                "No se pudo resolver el archivo {} del proyecto {}",
+                file_ref.file_id, file_ref.project_id
+            )
+        })?;
+
+        let Some(download_url) = &file.download_url else {
+            manual_downloads.push(format!(
+                "{} (proyecto {}, archivo {})",
+                file.file_name, file_ref.project_id, file_ref.file_id
+            ));
+            continue;
+        };
+
+        // The file name comes from the API, it must not smuggle in directories
+        if file.file_name.contains(['/', '\\']) {
+            return Err(format!("Nombre de archivo inválido: {}", file.file_name));
+        }
+        let folder = target_folder(file.class_id)?;
+        let path = normalize_archive_path(&format!("{}/{}", folder, file.file_name))?;
+
+        if !is_valid_sha1(&file.sha1) {
+            return Err(format!("{} no tiene un hash SHA-1 válido", file.file_name));
+        }
+        if !download_url.starts_with("https://") {
+            return Err(format!(
+                "{} no tiene una URL de descarga HTTPS",
+                file.file_name
+            ));
+        }
+
+        entries.push(ModpackFileEntry {
+            fileHash: file.sha1.to_ascii_lowercase(),
+            file: ModpackFileType {
+                size: file.size,
+                r#type: file_type_for_path(&path).to_string(),
+            },
+            path,
+            downloadUrl: download_url.clone(),
+            mirrors: Vec::new(),
+        });
+    }
+
+    if !manual_downloads.is_empty() {
+        return Err(format!(
+            "Sus autores no permiten descargar estos archivos desde otros launchers, descárgalos manualmente desde CurseForge: {}",
+            manual_downloads.join(", ")
+        ));
+    }
+    Ok(entries)
+}
+
+/// Imports a CurseForge modpack export (.zip) as a new local instance
+#[tauri::command]
+pub async fn import_curseforge_zip(
+    file_path: String,
+    instance_name: Option<String>,
+) -> Result<String, String> {
+    let resolver = CurseForgeApiResolver::from_build_env()?;
+    run_import(
+        file_path,
+        instance_name,
+        "curseforge_import",
+        move |archive_path, staging_root| {
+            read_curseforge_zip(archive_path, staging_root, &resolver)
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    const HELLO_SHA1: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+    /// Local stand-in for the CurseForge API
+    struct LocalResolver(Vec<ResolvedFile>);
+
+    impl FileResolver for LocalResolver {
+        fn resolve(&self, files: &[CurseForgeFileRef]) -> Result<Vec<ResolvedFile>, String> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|r| files.iter().any(|f| f.file_id == r.file_id))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn resolved(file_id: u32, file_name: &str) -> ResolvedFile {
+        ResolvedFile {
+            file_id,
+            file_name: file_name.to_string(),
+            sha1: HELLO_SHA1.to_uppercase(),
+            size: 5,
+            download_url: Some(format!("https://cdn.example.com/{}", file_name)),
+            class_id: Some(CLASS_MODS),
+        }
+    }
+
+    fn write_export(path: &Path, manifest: serde_json::Value, extra: &[(&str, &[u8])]) {
+        let mut writer = zip::ZipWriter::new(fs::File::create(path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file(MANIFEST_FILE, options).unwrap();
+        writer.write_all(manifest.to_string().as_bytes()).unwrap();
+        for (name, content) in extra {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn sample_manifest(files: serde_json::Value, overrides: &str) -> serde_json::Value {
+        serde_json::json!({
+            "minecraft": {
+                "version": "1.20.1",
+                "modLoaders": [
+                    { "id": "forge-47.2.0", "primary": true }
+                ]
+            },
+            "manifestType": "minecraftModpack",
+            "manifestVersion": 1,
+            "name": "Test Pack",
+            "version": "2.0.0",
+            "author": "someone",
+            "files": files,
+            "overrides": overrides
+        })
+    }
+
+    #[test]
+    fn test_read_curseforge_zip_resolves_files_and_overrides() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("pack.zip");
+        write_export(
+            &archive,
+            sample_manifest(
+                serde_json::json!([
+                    { "projectID": 238222, "fileID": 4712866, "required": true },
+                    { "projectID": 111, "fileID": 222, "required": false }
+                ]),
+                "custom-overrides",
+            ),
+            &[
+                ("custom-overrides/config/jei.toml", b"hello"),
+                ("overrides/ignored.txt", b"not part of the pack"),
+            ],
+        );
+
+        let resolver = LocalResolver(vec![resolved(4712866, "jei-1.20.1.jar")]);
+        let staging = dir.path().join("instance/.modpack_cache/import");
+        let pack = read_curseforge_zip(&archive, &staging, &resolver).unwrap();
+
+        assert_eq!(pack.minecraft_version, "1.20.1");
+        assert_eq!(pack.loader, ImportedLoader::Forge("47.2.0".to_string()));
+        assert_eq!(pack.manifest.version, "2.0.0");
+
+        let paths: Vec<&str> = pack
+            .manifest
+            .files
+            .iter()
+            .map(|f| f.path.as_str())
+            .collect();
+        assert_eq!(paths, vec!["mods/jei-1.20.1.jar", "config/jei.toml"]);
+
+        let jei = &pack.manifest.files[0];
+        assert_eq!(jei.fileHash, HELLO_SHA1);
+        assert_eq!(jei.downloadUrl, "https://cdn.example.com/jei-1.20.1.jar");
+        assert_eq!(jei.file.r#type, "mod");
+        assert!(pack.manifest.files[1].downloadUrl.starts_with("file://"));
+    }
+
+    #[test]
+    fn test_unresolved_or_unsafe_files_fail_the_import() {
+        let dir = tempdir().unwrap();
+        let staging = dir.path().join("staging");
+        let archive = dir.path().join("pack.zip");
+        write_export(
+            &archive,
+            sample_manifest(
+                serde_json::json!([{ "projectID": 1, "fileID": 10 }]),
+                "overrides",
+            ),
+            &[],
+        );
+
+        assert!(read_curseforge_zip(&archive, &staging, &LocalResolver(Vec::new())).is_err());
+        assert!(read_curseforge_zip(
+            &archive,
+            &staging,
+            &LocalResolver(vec![resolved(10, "../../evil.jar")])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_files_go_to_the_folder_of_their_class() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("pack.zip");
+        write_export(
+            &archive,
+            sample_manifest(
+                serde_json::json!([
+                    { "projectID": 1, "fileID": 10 },
+                    { "projectID": 2, "fileID": 20 },
+                    { "projectID": 3, "fileID": 30 }
+                ]),
+                "overrides",
+            ),
+            &[],
+        );
+
+        let resolver = LocalResolver(vec![
+            ResolvedFile {
+                class_id: Some(CLASS_RESOURCE_PACKS),
+                ..resolved(10, "Faithful.zip")
+            },
+            ResolvedFile {
+                class_id: Some(CLASS_SHADER_PACKS),
+                ..resolved(20, "BSL.zip")
+            },
+            ResolvedFile {
+                class_id: None,
+                ..resolved(30, "jei.jar")
+            },
+        ]);
+        let pack = read_curseforge_zip(&archive, &dir.path().join("staging"), &resolver).unwrap();
+
+        let entries: Vec<(&str, &str)> = pack
+            .manifest
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.file.r#type.as_str()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("resourcepacks/Faithful.zip", "resourcepack"),
+                ("shaderpacks/BSL.zip", "shaderpack"),
+                ("mods/jei.jar", "mod"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_opted_out_files_require_manual_download() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("pack.zip");
+        write_export(
+            &archive,
+            sample_manifest(
+                serde_json::json!([
+                    { "projectID": 1, "fileID": 10 },
+                    { "projectID": 2, "fileID": 20 }
+                ]),
+                "overrides",
+            ),
+            &[],
+        );
+
+        let resolver = LocalResolver(vec![
+            resolved(10, "allowed.jar"),
+            ResolvedFile {
+                download_url: None,
+                ..resolved(20, "optout.jar")
+            },
+        ]);
+        let err =
+            read_curseforge_zip(&archive, &dir.path().join("staging"), &resolver).unwrap_err();
+        assert!(err.contains("optout.jar (proyecto 2, archivo 20)"));
+        assert!(!err.contains("allowed.jar"));
+    }
+
+    #[test]
+    fn test_api_file_without_download_url_is_not_guessed() {
+        let file: ApiFile = serde_json::from_value(serde_json::json!({
+            "id": 4712866,
+            "modId": 238222,
+            "fileName": "jei 1.20.jar",
+            "fileLength": 5,
+            "downloadUrl": null,
+            "hashes": [
+                { "value": "d41d8cd98f00b204e9800998ecf8427e", "algo": 2 },
+                { "value": HELLO_SHA1, "algo": 1 }
+            ]
+        }))
+        .unwrap();
+
+        let resolved = file.into_resolved(Some(CLASS_MODS)).unwrap();
+        assert_eq!(resolved.sha1, HELLO_SHA1);
+        assert_eq!(resolved.download_url, None);
+        assert_eq!(resolved.class_id, Some(CLASS_MODS));
+    }
+}
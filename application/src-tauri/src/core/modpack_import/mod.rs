@@ -0,0 +1,368 @@
+// src-tauri/src/core/modpack_import/mod.rs
+// Import of third-party modpack archives into local instances.
+//
+// Importers turn an archive into a `ModpackManifest` and hand it to the regular
+// modpack installation pipeline (`download_and_install_files`). Files bundled inside
+// the archive (overrides) are staged under the instance and referenced as `file://`
+// sources, so they get the same path sanitizing and hash verification as downloads.
+
+pub mod curseforge;
+pub mod instance_archive;
+pub mod mrpack;
+
+use crate::core::bootstrap::manifest::is_valid_loader_version;
+use crate::core::bootstrap::store::sha1_of_file;
+use crate::core::instance_manager::{
+    get_instances_dir, normalize_path, spawn_modpack_creation_task, DEFAULT_FORGE_ICON,
+    DEFAULT_VANILLA_ICON,
+};
+use crate::core::minecraft_instance::{MinecraftInstance, ModLoader};
+use crate::core::modpack_file_manager::{
+    save_manifest_cache, ModpackFileEntry, ModpackFileType, ModpackManifest,
+};
+use crate::core::tasks_manager::{add_task, update_task, TaskStatus};
+use crate::utils::safe_path::{is_archive_root_entry, resolve_within, sanitize_relative_path};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read, Seek};
+use std::path::{Path, PathBuf};
+use tokio::task;
+
+/// Where archive overrides are staged inside the instance until they are installed
+const IMPORT_STAGING_DIR: [&str; 2] = [".modpack_cache", "import"];
+
+/// Mod loader requested by an imported pack
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportedLoader {
+    Vanilla,
+    Forge(String),
+    NeoForge(String),
+    Fabric(String),
+    Quilt(String),
+}
+
+impl ImportedLoader {
+    /// Stores the loader on the instance, rejecting versions that aren't plain version strings
+    pub fn apply(&self, instance: &mut MinecraftInstance) -> Result<(), String> {
+        let (loader, version) = match self {
+            ImportedLoader::Vanilla => (ModLoader::Vanilla, None),
+            ImportedLoader::Forge(version) => (ModLoader::Forge, Some(version)),
+            ImportedLoader::NeoForge(version) => (ModLoader::NeoForge, Some(version)),
+            ImportedLoader::Fabric(version) => (ModLoader::Fabric, Some(version)),
+            ImportedLoader::Quilt(version) => (ModLoader::Quilt, Some(version)),
+        };
+
+        if let Some(version) = version.filter(|v| !is_valid_loader_version(v)) {
+            return Err(format!(
+                "Versión de {} inválida en el modpack: {}",
+                loader.display_name(),
+                version
+            ));
+        }
+
+        instance.set_loader(loader, version.cloned());
+        Ok(())
+    }
+
+    /// Version for the manifest's `forgeVersion` field
+    pub fn forge_version(&self) -> Option<String> {
+        match self {
+            ImportedLoader::Forge(version) => Some(version.clone()),
+            _ => None,
+        }
+    }
+
+    /// Version for the manifest's `quiltVersion` field
+    pub fn quilt_version(&self) -> Option<String> {
+        match self {
+            ImportedLoader::Quilt(version) => Some(version.clone()),
+            _ => None,
+        }
+    }
+
+    /// Loader currently configured on an instance
+    pub fn from_instance(instance: &MinecraftInstance) -> Self {
+        let version = instance.loader_version().unwrap_or_default().to_string();
+        match instance.mod_loader() {
+            ModLoader::Vanilla => ImportedLoader::Vanilla,
+            ModLoader::Forge => ImportedLoader::Forge(version),
+            ModLoader::NeoForge => ImportedLoader::NeoForge(version),
+            ModLoader::Fabric => ImportedLoader::Fabric(version),
+            ModLoader::Quilt => ImportedLoader::Quilt(version),
+        }
+    }
+}
+
+/// Result of reading an archive: everything needed to create and install the instance
+#[derive(Debug, Clone)]
+pub struct ImportedPack {
+    pub name: String,
+    pub minecraft_version: String,
+    pub loader: ImportedLoader,
+    pub manifest: ModpackManifest,
+    /// Settings of an exported instance to restore; `None` starts from a blank instance
+    pub base_instance: Option<MinecraftInstance>,
+    /// Manifest seeded into `.modpack_cache` for later `cleanup_obsolete_files` runs
+    pub cached_manifest: Option<ModpackManifest>,
+}
+
+/// Directory where overrides of an instance being imported are staged
+pub fn import_staging_dir(instance_dir: &Path) -> PathBuf {
+    IMPORT_STAGING_DIR
+        .iter()
+        .fold(instance_dir.to_path_buf(), |dir, part| dir.join(part))
+}
+
+/// True if `url` is a `file://` URL pointing inside the instance's staging directory
+pub fn is_staged_source(url: &str, instance_dir: &Path) -> bool {
+    let Some(path) = url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.to_file_path().ok())
+    else {
+        return false;
+    };
+
+    // Compare canonical paths so `..` segments or symlinks can't point elsewhere
+    match (
+        fs::canonicalize(&path),
+        fs::canonicalize(import_staging_dir(instance_dir)),
+    ) {
+        (Ok(file), Ok(staging)) => file.starts_with(staging),
+        _ => false,
+    }
+}
+
+/// Removes the staging directory once the install pipeline consumed it
+pub fn discard_import_staging(instance: &MinecraftInstance) {
+    let Some(instance_dir) = instance.instanceDirectory.as_ref() else {
+        return;
+    };
+    let staging = import_staging_dir(Path::new(instance_dir));
+    if staging.exists() {
+        if let Err(e) = fs::remove_dir_all(&staging) {
+            log::warn!(
+                "[Import] Failed to remove staging directory {}: {}",
+                staging.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Extracts every archive entry below one of `prefixes` into `staging_root` and returns
+/// manifest entries pointing at the staged copies. Later prefixes win on path conflicts
+/// (e.g. `client-overrides/` over `overrides/`).
+pub(crate) fn stage_overrides<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    prefixes: &[&str],
+    staging_root: &Path,
+) -> Result<Vec<ModpackFileEntry>, String> {
+    let mut staged: Vec<ModpackFileEntry> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for (layer, prefix) in prefixes.iter().enumerate() {
+        for i in 0..archive.len() {
+            let mut entry = archive
+                .by_index(i)
+                .map_err(|e| format!("Error leyendo entrada del archivo: {}", e))?;
+
+            let Some(relative) = entry.name().strip_prefix(prefix).map(str::to_string) else {
+                continue;
+            };
+            if entry.is_dir() || is_archive_root_entry(&relative) {
+                continue;
+            }
+
+            let relative = normalize_archive_path(&relative)
+                .map_err(|e| format!("Ruta insegura en {}: {}", entry.name(), e))?;
+
+            // Each layer gets its own folder so a later layer never overwrites an earlier copy
+            let layer_root = staging_root.join(layer.to_string());
+            let staged_path = resolve_within(&layer_root, &relative)?;
+            if let Some(parent) = staged_path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Error creando directorio de importación: {}", e))?;
+            }
+
+            let mut output = fs::File::create(&staged_path)
+                .map_err(|e| format!("Error creando {}: {}", staged_path.display(), e))?;
+            let size = io::copy(&mut entry, &mut output)
+                .map_err(|e| format!("Error extrayendo {}: {}", relative, e))?;
+            drop(output);
+
+            let file_entry = ModpackFileEntry {
+                fileHash: sha1_of_file(&staged_path)?,
+                path: relative.clone(),
+                file: ModpackFileType {
+                    size,
+                    r#type: file_type_for_path(&relative).to_string(),
+                },
+                downloadUrl: url::Url::from_file_path(&staged_path)
+                    .map_err(|_| {
+                        format!("Ruta de importación inválida: {}", staged_path.display())
+                    })?
+                    .to_string(),
+                mirrors: Vec::new(),
+            };
+
+            match positions.get(&relative) {
+                Some(&position) => staged[position] = file_entry,
+                None => {
+                    positions.insert(relative, staged.len());
+                    staged.push(file_entry);
+                }
+            }
+        }
+    }
+
+    Ok(staged)
+}
+
+/// Sanitizes an untrusted relative path and renders it with `/` separators
+pub(crate) fn normalize_archive_path(relative: &str) -> Result<String, String> {
+    Ok(sanitize_relative_path(relative)?
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+/// Replaces manifest entries that share a path with an override, then appends the rest
+pub(crate) fn merge_overrides(files: &mut Vec<ModpackFileEntry>, overrides: Vec<ModpackFileEntry>) {
+    for override_entry in overrides {
+        match files.iter_mut().find(|f| f.path == override_entry.path) {
+            Some(existing) => *existing = override_entry,
+            None => files.push(override_entry),
+        }
+    }
+}
+
+/// Best-effort file category used by the manifest, based on the top-level folder
+pub(crate) fn file_type_for_path(path: &str) -> &'static str {
+    match path.split('/').next().unwrap_or_default() {
+        "mods" | "coremods" => "mod",
+        "config" | "defaultconfigs" => "config",
+        "resourcepacks" => "resourcepack",
+        "shaderpacks" => "shaderpack",
+        "scripts" => "script",
+        _ => "other",
+    }
+}
+
+/// Creates and saves a new instance (with a fresh id) for an imported pack
+pub(crate) fn create_imported_instance(
+    instance_id: &str,
+    instance_dir: &Path,
+    instance_name: String,
+    pack: &ImportedPack,
+) -> Result<MinecraftInstance, String> {
+    let mut instance = match &pack.base_instance {
+        Some(base) => {
+            let mut instance = base.clone();
+            // Account and Java path belong to the machine the instance was exported from
+            instance.accountUuid = None;
+            instance.javaPath = None;
+            instance.javaPathPinned = false;
+            // Wrappers and hooks run host commands; a shared archive must not bring its own
+            instance.launchOptions.wrappers = None;
+            instance.launchOptions.hooks = Default::default();
+            instance
+        }
+        None => MinecraftInstance::new(),
+    };
+    instance.instanceId = instance_id.to_string();
+    instance.instanceName = instance_name;
+    instance.minecraftVersion = pack.minecraft_version.clone();
+    pack.loader.apply(&mut instance)?;
+
+    if pack.base_instance.is_none() {
+        instance.usesDefaultIcon = true;
+        instance.bannerUrl = Some(
+            if instance.forgeVersion.is_some() {
+                DEFAULT_FORGE_ICON
+            } else {
+                DEFAULT_VANILLA_ICON
+            }
+            .to_string(),
+        );
+    }
+
+    instance.instanceDirectory = Some(normalize_path(instance_dir));
+    instance.minecraftPath = normalize_path(&instance_dir.join("minecraft"));
+
+    fs::create_dir_all(instance_dir)
+        .map_err(|e| format!("Error creando directorio de la instancia: {}", e))?;
+    instance
+        .save()
+        .map_err(|e| format!("Error guardando configuración: {}", e))?;
+
+    // Seed the manifest cache so later updates clean up obsolete files like store packs do
+    if let Some(cached_manifest) = &pack.cached_manifest {
+        save_manifest_cache(&instance, cached_manifest)?;
+    }
+
+    Ok(instance)
+}
+
+/// Allocates the id and directory of an instance about to be imported
+pub(crate) fn allocate_instance_dir() -> Result<(String, PathBuf), String> {
+    let instance_id = uuid::Uuid::new_v4().to_string();
+    let instance_dir = get_instances_dir()?.join(&instance_id);
+    Ok((instance_id, instance_dir))
+}
+
+/// Shared flow of the import commands: reads the archive off the async runtime, creates
+/// the instance and hands the manifest to the regular installation task
+pub(crate) async fn run_import<F>(
+    file_path: String,
+    instance_name: Option<String>,
+    import_type: &str,
+    read: F,
+) -> Result<String, String>
+where
+    F: FnOnce(&Path, &Path) -> Result<ImportedPack, String> + Send + 'static,
+{
+    let (instance_id, instance_dir) = allocate_instance_dir()?;
+    let archive_path = PathBuf::from(&file_path);
+    let staging_root = import_staging_dir(&instance_dir);
+
+    let pack = task::spawn_blocking(move || read(&archive_path, &staging_root))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+
+    let instance = pack.and_then(|pack| {
+        let name = instance_name
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| pack.name.clone());
+        create_imported_instance(&instance_id, &instance_dir, name, &pack)
+            .map(|instance| (instance, pack.manifest))
+    });
+
+    let (instance, manifest) = match instance {
+        Ok(result) => result,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&instance_dir);
+            return Err(e);
+        }
+    };
+
+    let task_id = add_task(
+        &format!("Importando modpack {}", instance.instanceName),
+        Some(serde_json::json!({
+            "type": import_type,
+            "instanceName": instance.instanceName,
+            "instanceId": instance.instanceId
+        })),
+    );
+    update_task(
+        &task_id,
+        TaskStatus::Running,
+        10.0,
+        &format!("{} archivos a instalar", manifest.files.len()),
+        None,
+    );
+
+    spawn_modpack_creation_task(instance, manifest, task_id);
+
+    Ok(instance_id)
+}
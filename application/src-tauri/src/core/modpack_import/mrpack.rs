@@ -0,0 +1,327 @@
+// src-tauri/src/core/modpack_import/mrpack.rs
+// Modrinth modpack (.mrpack) import
+
+use super::{
+    file_type_for_path, merge_overrides, normalize_archive_path, run_import, stage_overrides,
+    ImportedLoader, ImportedPack,
+};
+use crate::core::bootstrap::store::is_valid_sha1;
+use crate::core::modpack_file_manager::{ModpackFileEntry, ModpackFileType, ModpackManifest};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+const INDEX_FILE: &str = "modrinth.index.json";
+const OVERRIDE_PREFIXES: [&str; 2] = ["overrides/", "client-overrides/"];
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MrpackIndex {
+    format_version: u32,
+    game: String,
+    version_id: String,
+    name: String,
+    #[serde(default)]
+    files: Vec<MrpackFile>,
+    #[serde(default)]
+    dependencies: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MrpackFile {
+    path: String,
+    #[serde(default)]
+    hashes: HashMap<String, String>,
+    #[serde(default)]
+    env: Option<MrpackEnv>,
+    #[serde(default)]
+    downloads: Vec<String>,
+    #[serde(default)]
+    file_size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct MrpackEnv {
+    #[serde(default)]
+    client: Option<String>,
+}
+
+/// Reads a `.mrpack`, staging its overrides under `staging_root`
+pub fn read_mrpack(archive_path: &Path, staging_root: &Path) -> Result<ImportedPack, String> {
+    let file = fs::File::open(archive_path)
+        .map_err(|e| format!("No se pudo abrir {}: {}", archive_path.display(), e))?;
+    let mut archive = zip::ZipArchive::new(std::io::BufReader::new(file))
+        .map_err(|e| format!("El archivo no es un .mrpack válido: {}", e))?;
+
+    let index: MrpackIndex = {
+        let mut entry = archive
+            .by_name(INDEX_FILE)
+            .map_err(|_| format!("El archivo no contiene {}", INDEX_FILE))?;
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| format!("Error leyendo {}: {}", INDEX_FILE, e))?;
+        serde_json::from_str(&content).map_err(|e| format!("{} inválido: {}", INDEX_FILE, e))?
+    };
+
+    if index.format_version != 1 || index.game != "minecraft" {
+        return Err(format!(
+            "Formato de .mrpack no soportado (formatVersion {}, game {})",
+            index.format_version, index.game
+        ));
+    }
+
+    let (minecraft_version, loader) = map_dependencies(&index.dependencies)?;
+
+    let mut files = Vec::new();
+    for file in &index.files {
+        if let Some(entry) = convert_file(file)? {
+            files.push(entry);
+        }
+    }
+
+    let overrides = stage_overrides(&mut archive, &OVERRIDE_PREFIXES, staging_root)?;
+    merge_overrides(&mut files, overrides);
+
+    let manifest = ModpackManifest {
+        id: format!("mrpack:{}", index.name),
+        version: index.version_id,
+        mc_version: minecraft_version.clone(),
+        forge_version: loader.forge_version(),
+        quilt_version: loader.quilt_version(),
+        files,
+        base_mirrors: Vec::new(),
+        launch_options: None,
+    };
+
+    Ok(ImportedPack {
+        name: index.name,
+        minecraft_version,
+        loader,
+        cached_manifest: Some(manifest.clone()),
+        manifest,
+        base_instance: None,
+    })
+}
+
+/// Maps `dependencies` (minecraft, forge, neoforge, fabric-loader, quilt-loader)
+fn map_dependencies(
+    dependencies: &HashMap<String, String>,
+) -> Result<(String, ImportedLoader), String> {
+    let minecraft_version = dependencies
+        .get("minecraft")
+        .cloned()
+        .ok_or("El .mrpack no indica la versión de Minecraft")?;
+
+    let loaders: Vec<ImportedLoader> = dependencies
+        .iter()
+        .filter_map(|(key, version)| match key.as_str() {
+            "forge" => Some(ImportedLoader::Forge(version.clone())),
+            "neoforge" => Some(ImportedLoader::NeoForge(version.clone())),
+            "fabric-loader" => Some(ImportedLoader::Fabric(version.clone())),
+            "quilt-loader" => Some(ImportedLoader::Quilt(version.clone())),
+            _ => None,
+        })
+        .collect();
+
+    match loaders.len() {
+        0 => Ok((minecraft_version, ImportedLoader::Vanilla)),
+        1 => Ok((minecraft_version, loaders[0].clone())),
+        _ => Err("El .mrpack declara más de un mod loader".to_string()),
+    }
+}
+
+/// Converts a `files[]` entry, skipping files marked unsupported on the client
+fn convert_file(file: &MrpackFile) -> Result<Option<ModpackFileEntry>, String> {
+    let client_env = file.env.as_ref().and_then(|env| env.client.as_deref());
+    if client_env == Some("unsupported") {
+        log::info!("[Import] Skipping server-only file {}", file.path);
+        return Ok(None);
+    }
+
+    let path = normalize_archive_path(&file.path)
+        .map_err(|e| format!("Ruta insegura en {}: {}", INDEX_FILE, e))?;
+
+    let sha1 = file
+        .hashes
+        .get("sha1")
+        .filter(|hash| is_valid_sha1(hash))
+        .ok_or_else(|| format!("{} no tiene un hash SHA-1 válido", file.path))?;
+
+    let mut downloads = file
+        .downloads
+        .iter()
+        .filter(|url| url.starts_with("https://"));
+    let download_url = downloads
+        .next()
+        .ok_or_else(|| format!("{} no tiene una URL de descarga HTTPS", file.path))?;
+
+    Ok(Some(ModpackFileEntry {
+        fileHash: sha1.to_ascii_lowercase(),
+        file: ModpackFileType {
+            size: file.file_size,
+            r#type: file_type_for_path(&path).to_string(),
+        },
+        path,
+        downloadUrl: download_url.clone(),
+        mirrors: downloads.cloned().collect(),
+    }))
+}
+
+/// Imports a Modrinth `.mrpack` file as a new local instance
+#[tauri::command]
+pub async fn import_mrpack(
+    file_path: String,
+    instance_name: Option<String>,
+) -> Result<String, String> {
+    run_import(file_path, instance_name, "mrpack_import", read_mrpack).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    const HELLO_SHA1: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+    fn write_mrpack(path: &Path, index: serde_json::Value, extra: &[(&str, &[u8])]) {
+        let mut writer = zip::ZipWriter::new(fs::File::create(path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file(INDEX_FILE, options).unwrap();
+        writer.write_all(index.to_string().as_bytes()).unwrap();
+        for (name, content) in extra {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn sample_index(files: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "formatVersion": 1,
+            "game": "minecraft",
+            "versionId": "1.2.0",
+            "name": "Test Pack",
+            "files": files,
+            "dependencies": { "minecraft": "1.20.1", "forge": "47.2.0" }
+        })
+    }
+
+    #[test]
+    fn test_read_mrpack_builds_manifest_and_stages_overrides() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("pack.mrpack");
+        write_mrpack(
+            &archive,
+            sample_index(serde_json::json!([
+                {
+                    "path": "mods/jei.jar",
+                    "hashes": { "sha1": HELLO_SHA1, "sha512": "ignored" },
+                    "env": { "client": "required", "server": "required" },
+                    "downloads": ["https://cdn.modrinth.com/jei.jar", "https://mirror.example.com/jei.jar"],
+                    "fileSize": 5
+                },
+                {
+                    "path": "mods/server-only.jar",
+                    "hashes": { "sha1": HELLO_SHA1 },
+                    "env": { "client": "unsupported", "server": "required" },
+                    "downloads": ["https://cdn.modrinth.com/server-only.jar"],
+                    "fileSize": 5
+                }
+            ])),
+            &[
+                ("overrides/config/jei.toml", b"shared"),
+                ("overrides/options.txt", b"common"),
+                ("client-overrides/options.txt", b"hello"),
+            ],
+        );
+
+        let staging = dir.path().join("instance/.modpack_cache/import");
+        let pack = read_mrpack(&archive, &staging).unwrap();
+
+        assert_eq!(pack.minecraft_version, "1.20.1");
+        assert_eq!(pack.loader, ImportedLoader::Forge("47.2.0".to_string()));
+        assert_eq!(pack.manifest.forge_version.as_deref(), Some("47.2.0"));
+
+        let paths: Vec<&str> = pack
+            .manifest
+            .files
+            .iter()
+            .map(|f| f.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            vec!["mods/jei.jar", "config/jei.toml", "options.txt"]
+        );
+
+        let jei = &pack.manifest.files[0];
+        assert_eq!(jei.downloadUrl, "https://cdn.modrinth.com/jei.jar");
+        assert_eq!(jei.mirrors, vec!["https://mirror.example.com/jei.jar"]);
+
+        // client-overrides wins over overrides and is served from the staging directory
+        let options = &pack.manifest.files[2];
+        assert_eq!(options.fileHash, HELLO_SHA1);
+        assert!(options.downloadUrl.starts_with("file://"));
+        assert!(super::super::is_staged_source(
+            &options.downloadUrl,
+            &dir.path().join("instance")
+        ));
+    }
+
+    #[test]
+    fn test_read_mrpack_rejects_malicious_paths() {
+        let dir = tempdir().unwrap();
+        let staging = dir.path().join("staging");
+
+        let traversal = dir.path().join("traversal.mrpack");
+        write_mrpack(
+            &traversal,
+            sample_index(serde_json::json!([{
+                "path": "../../.minecraft/mods/evil.jar",
+                "hashes": { "sha1": HELLO_SHA1 },
+                "downloads": ["https://cdn.modrinth.com/evil.jar"],
+                "fileSize": 5
+            }])),
+            &[],
+        );
+        assert!(read_mrpack(&traversal, &staging).is_err());
+
+        let bad_override = dir.path().join("override.mrpack");
+        write_mrpack(
+            &bad_override,
+            sample_index(serde_json::json!([])),
+            &[("overrides/../../evil.sh", b"#!/bin/sh")],
+        );
+        assert!(read_mrpack(&bad_override, &staging).is_err());
+        assert!(!dir.path().join("evil.sh").exists());
+    }
+
+    #[test]
+    fn test_map_dependencies() {
+        let deps = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        assert_eq!(
+            map_dependencies(&deps(&[
+                ("minecraft", "1.20.1"),
+                ("fabric-loader", "0.15.11")
+            ]))
+            .unwrap()
+            .1,
+            ImportedLoader::Fabric("0.15.11".to_string())
+        );
+        assert_eq!(
+            map_dependencies(&deps(&[("minecraft", "1.21")])).unwrap().1,
+            ImportedLoader::Vanilla
+        );
+        assert!(map_dependencies(&deps(&[("forge", "47.2.0")])).is_err());
+    }
+}
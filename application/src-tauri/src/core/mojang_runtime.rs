@@ -0,0 +1,391 @@
+// Proveedor de Java basado en los runtimes que publica Mojang (java-runtime-*).
+// Cada componente tiene un manifiesto con la lista de archivos del runtime, su SHA-1
+// y si son ejecutables, así que se descargan archivo por archivo en lugar de un archivo
+// comprimido.
+
+use crate::utils::safe_path::{resolve_link_target, resolve_within, sanitize_relative_path};
+use anyhow::{anyhow, Context, Result};
+use futures_util::{stream, StreamExt};
+use serde_json::Value;
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri_plugin_http::reqwest;
+
+pub const JAVA_RUNTIME_MANIFEST_URL: &str = "https://launchermeta.mojang.com/v1/products/java-runtime/2ec0cc96c44e5a76b9c8b7c39df7210883d12871/all.json";
+
+/// Descargas simultáneas de archivos del runtime
+const RUNTIME_DOWNLOAD_CONCURRENCY: usize = 8;
+
+/// Entrada del manifiesto de un runtime
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuntimeEntry {
+    Directory {
+        path: String,
+    },
+    File {
+        path: String,
+        url: String,
+        sha1: String,
+        executable: bool,
+    },
+    Link {
+        path: String,
+        target: String,
+    },
+}
+
+/// Nombre de plataforma que usa Mojang en el manifiesto de runtimes
+pub fn runtime_platform() -> Option<&'static str> {
+    if cfg!(target_os = "windows") {
+        if cfg!(target_arch = "x86_64") {
+            Some("windows-x64")
+        } else if cfg!(target_arch = "aarch64") {
+            Some("windows-arm64")
+        } else {
+            Some("windows-x86")
+        }
+    } else if cfg!(target_os = "macos") {
+        if cfg!(target_arch = "aarch64") {
+            Some("mac-os-arm64")
+        } else {
+            Some("mac-os")
+        }
+    } else if cfg!(target_os = "linux") {
+        if cfg!(target_arch = "x86") {
+            Some("linux-i386")
+        } else {
+            Some("linux")
+        }
+    } else {
+        None
+    }
+}
+
+/// Componente que usa el launcher oficial para una versión mayor de Java, para
+/// versiones de Minecraft cuyo JSON no declara `javaVersion.component`
+pub fn default_component(major_version: u8) -> Option<&'static str> {
+    match major_version {
+        8 => Some("jre-legacy"),
+        16 => Some("java-runtime-alpha"),
+        17 => Some("java-runtime-gamma"),
+        21 => Some("java-runtime-delta"),
+        25 => Some("java-runtime-epsilon"),
+        _ => None,
+    }
+}
+
+/// URL del manifiesto de archivos de un componente para la plataforma indicada
+pub fn find_runtime_manifest_url(all: &Value, platform: &str, component: &str) -> Option<String> {
+    all[platform][component]
+        .as_array()?
+        .iter()
+        .find_map(|runtime| runtime["manifest"]["url"].as_str())
+        .map(str::to_string)
+}
+
+/// Lee las entradas del manifiesto de un runtime, ordenadas para crear primero los
+/// directorios y al final los enlaces
+pub fn parse_runtime_entries(manifest: &Value) -> Result<Vec<RuntimeEntry>> {
+    let files = manifest["files"]
+        .as_object()
+        .ok_or_else(|| anyhow!("El manifiesto del runtime no contiene archivos"))?;
+
+    let mut entries = Vec::with_capacity(files.len());
+    for (path, info) in files {
+        let entry = match info["type"].as_str() {
+            Some("directory") => RuntimeEntry::Directory { path: path.clone() },
+            Some("file") => {
+                let raw = &info["downloads"]["raw"];
+                RuntimeEntry::File {
+                    path: path.clone(),
+                    url: raw["url"]
+                        .as_str()
+                        .ok_or_else(|| anyhow!("{} no tiene URL de descarga", path))?
+                        .to_string(),
+                    sha1: raw["sha1"]
+                        .as_str()
+                        .ok_or_else(|| anyhow!("{} no tiene SHA-1", path))?
+                        .to_string(),
+                    executable: info["executable"].as_bool().unwrap_or(false),
+                }
+            }
+            Some("link") => RuntimeEntry::Link {
+                path: path.clone(),
+                target: info["target"]
+                    .as_str()
+                    .ok_or_else(|| anyhow!("El enlace {} no tiene destino", path))?
+                    .to_string(),
+            },
+            other => {
+                return Err(anyhow!(
+                    "Tipo de entrada desconocido en {}: {:?}",
+                    path,
+                    other
+                ))
+            }
+        };
+        entries.push(entry);
+    }
+
+    entries.sort_by_key(|entry| match entry {
+        RuntimeEntry::Directory { .. } => 0,
+        RuntimeEntry::File { .. } => 1,
+        RuntimeEntry::Link { .. } => 2,
+    });
+    Ok(entries)
+}
+
+/// Descarga el componente indicado en `target_dir`. Se instala primero en un directorio
+/// temporal que se elimina si algo falla, de modo que nunca queda un runtime a medias.
+pub async fn install_runtime(component: &str, target_dir: &Path) -> Result<()> {
+    let platform = runtime_platform()
+        .ok_or_else(|| anyhow!("Sistema operativo no soportado por los runtimes de Mojang"))?;
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(300))
+        .build()?;
+
+    let all: Value = client
+        .get(JAVA_RUNTIME_MANIFEST_URL)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .context("Error al consultar los runtimes de Java de Mojang")?
+        .json()
+        .await
+        .context("Error al parsear los runtimes de Java de Mojang")?;
+
+    let manifest_url = find_runtime_manifest_url(&all, platform, component).ok_or_else(|| {
+        anyhow!(
+            "Mojang no publica el runtime {} para {}",
+            component,
+            platform
+        )
+    })?;
+    let manifest: Value = client
+        .get(&manifest_url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .context("Error al descargar el manifiesto del runtime")?
+        .json()
+        .await
+        .context("Error al parsear el manifiesto del runtime")?;
+    let entries = parse_runtime_entries(&manifest)?;
+
+    let staging_dir = target_dir.with_extension("part");
+    if staging_dir.exists() {
+        fs::remove_dir_all(&staging_dir)
+            .context("No se pudo limpiar una instalación anterior incompleta")?;
+    }
+    fs::create_dir_all(&staging_dir).context("No se pudo crear el directorio del runtime")?;
+
+    let result = install_entries(&client, &entries, &staging_dir).await;
+    if let Err(e) = result {
+        let _ = fs::remove_dir_all(&staging_dir);
+        return Err(e);
+    }
+
+    if target_dir.exists() {
+        fs::remove_dir_all(target_dir).context("No se pudo reemplazar el runtime anterior")?;
+    }
+    fs::rename(&staging_dir, target_dir).context("No se pudo finalizar la instalación")?;
+
+    log::info!(
+        "Installed Mojang runtime {} ({} entries) in {}",
+        component,
+        entries.len(),
+        target_dir.display()
+    );
+    Ok(())
+}
+
+async fn install_entries(
+    client: &reqwest::Client,
+    entries: &[RuntimeEntry],
+    target_dir: &Path,
+) -> Result<()> {
+    for entry in entries {
+        if let RuntimeEntry::Directory { path } = entry {
+            let dir = resolve_within(target_dir, path).map_err(|e| anyhow!(e))?;
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    let downloads = entries.iter().filter_map(|entry| match entry {
+        RuntimeEntry::File {
+            path,
+            url,
+            sha1,
+            executable,
+        } => Some(async move {
+            let bytes = client
+                .get(url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .with_context(|| format!("Error al descargar {}", path))?
+                .bytes()
+                .await
+                .with_context(|| format!("Error al descargar {}", path))?;
+            write_runtime_file(target_dir, path, sha1, *executable, &bytes)
+        }),
+        _ => None,
+    });
+    let mut downloads = stream::iter(downloads).buffer_unordered(RUNTIME_DOWNLOAD_CONCURRENCY);
+    while let Some(result) = downloads.next().await {
+        result?;
+    }
+
+    for entry in entries {
+        if let RuntimeEntry::Link { path, target } = entry {
+            create_runtime_link(target_dir, path, target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Escribe un archivo del runtime tras comprobar su SHA-1
+fn write_runtime_file(
+    target_dir: &Path,
+    path: &str,
+    sha1: &str,
+    executable: bool,
+    bytes: &[u8],
+) -> Result<()> {
+    let actual = format!("{:x}", Sha1::digest(bytes));
+    if !actual.eq_ignore_ascii_case(sha1) {
+        return Err(anyhow!(
+            "SHA-1 incorrecto para {}: se esperaba {}, se obtuvo {}",
+            path,
+            sha1,
+            actual
+        ));
+    }
+
+    let destination = resolve_within(target_dir, path).map_err(|e| anyhow!(e))?;
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&destination, bytes).with_context(|| format!("No se pudo escribir {}", path))?;
+
+    #[cfg(unix)]
+    if executable {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&destination, fs::Permissions::from_mode(0o755))?;
+    }
+    #[cfg(not(unix))]
+    let _ = executable;
+
+    Ok(())
+}
+
+/// Crea un enlace del runtime; los destinos que salen del directorio se rechazan
+fn create_runtime_link(target_dir: &Path, path: &str, target: &str) -> Result<PathBuf> {
+    let relative = sanitize_relative_path(path).map_err(|e| anyhow!(e))?;
+    resolve_link_target(target_dir, &relative, Path::new(target)).map_err(|e| anyhow!(e))?;
+    let link = resolve_within(target_dir, path).map_err(|e| anyhow!(e))?;
+    if let Some(parent) = link.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Los runtimes de Windows no usan enlaces
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(target, &link)
+        .with_context(|| format!("No se pudo crear el enlace {}", path))?;
+
+    Ok(link)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_find_manifest_and_parse_entries() {
+        let all = json!({
+            "linux": {
+                "java-runtime-gamma": [
+                    { "manifest": { "url": "https://piston-meta.mojang.com/gamma.json" } }
+                ],
+                "jre-legacy": []
+            }
+        });
+        assert_eq!(
+            find_runtime_manifest_url(&all, "linux", "java-runtime-gamma").as_deref(),
+            Some("https://piston-meta.mojang.com/gamma.json")
+        );
+        assert_eq!(find_runtime_manifest_url(&all, "linux", "jre-legacy"), None);
+        assert_eq!(
+            find_runtime_manifest_url(&all, "mac-os", "java-runtime-gamma"),
+            None
+        );
+        assert_eq!(default_component(17), Some("java-runtime-gamma"));
+        assert_eq!(default_component(11), None);
+
+        let manifest = json!({
+            "files": {
+                "bin/java": {
+                    "type": "file",
+                    "executable": true,
+                    "downloads": { "raw": { "url": "https://example.org/java", "sha1": "aa" } }
+                },
+                "bin": { "type": "directory" },
+                "lib/libjli.so": { "type": "link", "target": "../bin/libjli.so" }
+            }
+        });
+        assert_eq!(
+            parse_runtime_entries(&manifest).unwrap(),
+            vec![
+                RuntimeEntry::Directory { path: "bin".into() },
+                RuntimeEntry::File {
+                    path: "bin/java".into(),
+                    url: "https://example.org/java".into(),
+                    sha1: "aa".into(),
+                    executable: true,
+                },
+                RuntimeEntry::Link {
+                    path: "lib/libjli.so".into(),
+                    target: "../bin/libjli.so".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runtime_files_are_verified() {
+        let dir = tempdir().unwrap();
+        // SHA-1 of "hello"
+        let sha1 = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+        assert!(write_runtime_file(dir.path(), "bin/java", sha1, true, b"tampered").is_err());
+        assert!(!dir.path().join("bin/java").exists());
+        assert!(write_runtime_file(dir.path(), "../java", sha1, true, b"hello").is_err());
+
+        write_runtime_file(dir.path(), "bin/java", sha1, true, b"hello").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dir.path().join("bin/java"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o111, 0o111);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_runtime_links_stay_inside_runtime() {
+        let dir = tempdir().unwrap();
+        assert!(create_runtime_link(dir.path(), "lib/evil", "../../../etc/passwd").is_err());
+
+        let link = create_runtime_link(dir.path(), "lib/libjli.so", "../bin/libjli.so").unwrap();
+        assert_eq!(
+            fs::read_link(link).unwrap(),
+            PathBuf::from("../bin/libjli.so")
+        );
+    }
+}
@@ -0,0 +1,355 @@
+// src/core/running_instances.rs
+// Registry of the instances being launched or played. An instance is reserved when its
+// launch starts, gets its process attached once the game is spawned and is released when
+// the launch fails or the game exits. Every change is emitted as `instance-state-changed`.
+
+use crate::core::minecraft_instance::MinecraftInstance;
+use lazy_static::lazy_static;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::sync::{Arc, Mutex};
+use tauri::Emitter;
+
+const EVENT_STATE_CHANGED: &str = "instance-state-changed";
+
+lazy_static! {
+    static ref REGISTRY: Mutex<Registry> = Mutex::new(Registry::default());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunState {
+    /// Preparing files; the game process does not exist yet
+    Launching,
+    Running,
+    /// Kill requested, waiting for the process to exit
+    Stopping,
+    /// Only sent in events, once the instance leaves the registry
+    Stopped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunningInstance {
+    pub instance_id: String,
+    pub instance_name: String,
+    pub account_uuid: Option<String>,
+    pub pid: Option<u32>,
+    /// Milliseconds since the Unix epoch when the launch started
+    pub started_at: i64,
+    pub state: RunState,
+}
+
+struct Entry {
+    info: RunningInstance,
+    child: Option<Arc<Mutex<Child>>>,
+    /// Java the game runs on, known once the launch has resolved it
+    java_home: Option<PathBuf>,
+}
+
+#[derive(Default)]
+struct Registry {
+    entries: HashMap<String, Entry>,
+}
+
+impl Registry {
+    fn reserve(
+        &mut self,
+        instance: &MinecraftInstance,
+        now: i64,
+    ) -> Result<RunningInstance, String> {
+        if let Some(entry) = self.entries.get(&instance.instanceId) {
+            return Err(match entry.info.state {
+                RunState::Launching => {
+                    format!(
+                        "La instancia {} ya se está iniciando",
+                        instance.instanceName
+                    )
+                }
+                _ => format!(
+                    "La instancia {} ya se está ejecutando",
+                    instance.instanceName
+                ),
+            });
+        }
+
+        let info = RunningInstance {
+            instance_id: instance.instanceId.clone(),
+            instance_name: instance.instanceName.clone(),
+            account_uuid: instance.accountUuid.clone(),
+            pid: None,
+            started_at: now,
+            state: RunState::Launching,
+        };
+        self.entries.insert(
+            instance.instanceId.clone(),
+            Entry {
+                info: info.clone(),
+                child: None,
+                java_home: None,
+            },
+        );
+        Ok(info)
+    }
+
+    fn attach(
+        &mut self,
+        instance: &MinecraftInstance,
+        pid: u32,
+        child: Arc<Mutex<Child>>,
+        now: i64,
+    ) -> RunningInstance {
+        let entry = self
+            .entries
+            .entry(instance.instanceId.clone())
+            .or_insert_with(|| Entry {
+                info: RunningInstance {
+                    instance_id: instance.instanceId.clone(),
+                    instance_name: instance.instanceName.clone(),
+                    account_uuid: instance.accountUuid.clone(),
+                    pid: None,
+                    started_at: now,
+                    state: RunState::Launching,
+                },
+                child: None,
+                java_home: None,
+            });
+        entry.info.pid = Some(pid);
+        entry.info.state = RunState::Running;
+        entry.child = Some(child);
+        entry.info.clone()
+    }
+
+    fn stop(&mut self, instance_id: &str) -> Result<(RunningInstance, Arc<Mutex<Child>>), String> {
+        let entry = self
+            .entries
+            .get_mut(instance_id)
+            .ok_or_else(|| "La instancia no se está ejecutando".to_string())?;
+        let child = entry
+            .child
+            .clone()
+            .ok_or_else(|| "La instancia todavía se está preparando".to_string())?;
+        entry.info.state = RunState::Stopping;
+        Ok((entry.info.clone(), child))
+    }
+
+    fn set_java_home(&mut self, instance_id: &str, java_home: PathBuf) {
+        if let Some(entry) = self.entries.get_mut(instance_id) {
+            entry.java_home = Some(java_home);
+        }
+    }
+
+    /// Names of the instances whose Java satisfies `uses`, plus the ones that haven't
+    /// resolved theirs yet and might still pick it
+    fn java_users(&self, uses: impl Fn(&Path) -> bool) -> Vec<String> {
+        self.entries
+            .values()
+            .filter(|entry| match &entry.java_home {
+                Some(java_home) => uses(java_home),
+                None => true,
+            })
+            .map(|entry| entry.info.instance_name.clone())
+            .collect()
+    }
+
+    fn release(&mut self, instance_id: &str) -> Option<RunningInstance> {
+        let mut info = self.entries.remove(instance_id)?.info;
+        info.state = RunState::Stopped;
+        Some(info)
+    }
+
+    fn list(&self) -> Vec<RunningInstance> {
+        let mut instances: Vec<RunningInstance> = self
+            .entries
+            .values()
+            .map(|entry| entry.info.clone())
+            .collect();
+        instances.sort_by_key(|instance| instance.started_at);
+        instances
+    }
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+fn emit_state(instance: &RunningInstance) {
+    if let Ok(guard) = crate::GLOBAL_APP_HANDLE.lock() {
+        if let Some(app_handle) = guard.as_ref() {
+            if let Err(e) = app_handle.emit(EVENT_STATE_CHANGED, instance) {
+                log::error!(
+                    "[Instance: {}] Failed to emit event '{}': {}",
+                    instance.instance_id,
+                    EVENT_STATE_CHANGED,
+                    e
+                );
+            }
+        }
+    }
+}
+
+fn registry() -> Result<std::sync::MutexGuard<'static, Registry>, String> {
+    REGISTRY
+        .lock()
+        .map_err(|_| "Failed to lock running instances registry".to_string())
+}
+
+/// Marks the instance as launching; fails if it is already launching or running
+pub fn reserve(instance: &MinecraftInstance) -> Result<(), String> {
+    let info = registry()?.reserve(instance, now())?;
+    emit_state(&info);
+    Ok(())
+}
+
+/// Records the spawned game process of a reserved instance
+pub fn attach(instance: &MinecraftInstance, child: Arc<Mutex<Child>>) {
+    let Ok(pid) = child.lock().map(|child| child.id()) else {
+        return;
+    };
+    let Ok(mut registry) = registry() else {
+        return;
+    };
+    let info = registry.attach(instance, pid, child, now());
+    drop(registry);
+    emit_state(&info);
+}
+
+/// Records the Java home a launching instance resolved
+pub fn set_java_home(instance_id: &str, java_home: PathBuf) {
+    if let Ok(mut registry) = registry() {
+        registry.set_java_home(instance_id, java_home);
+    }
+}
+
+/// Instances launching or running on a Java home accepted by `uses`, including those
+/// still resolving which Java to use
+pub fn java_users(uses: impl Fn(&Path) -> bool) -> Result<Vec<String>, String> {
+    Ok(registry()?.java_users(uses))
+}
+
+/// Removes the instance once its launch failed or the game exited
+pub fn release(instance_id: &str) {
+    let Ok(mut registry) = registry() else {
+        return;
+    };
+    let released = registry.release(instance_id);
+    drop(registry);
+    if let Some(info) = released {
+        emit_state(&info);
+    }
+}
+
+/// Whether `kill_instance` was called for the instance
+pub fn is_stopping(instance_id: &str) -> bool {
+    registry().is_ok_and(|registry| {
+        registry
+            .entries
+            .get(instance_id)
+            .is_some_and(|entry| entry.info.state == RunState::Stopping)
+    })
+}
+
+/// Instances currently launching or running, oldest first
+#[tauri::command]
+pub fn get_running_instances() -> Result<Vec<RunningInstance>, String> {
+    Ok(registry()?.list())
+}
+
+/// Kills the game process of a running instance. The instance leaves the registry when its
+/// monitor sees the process exit.
+#[tauri::command]
+pub fn kill_instance(instance_id: String) -> Result<(), String> {
+    let (info, child) = registry()?.stop(&instance_id)?;
+    emit_state(&info);
+
+    log::info!(
+        "[Instance: {}] Killing game process {:?}",
+        instance_id,
+        info.pid
+    );
+    let mut child = child
+        .lock()
+        .map_err(|_| "Failed to lock game process".to_string())?;
+    if let Ok(Some(_)) = child.try_wait() {
+        // Exited on its own in the meantime
+        return Ok(());
+    }
+    child
+        .kill()
+        .map_err(|e| format!("No se pudo detener la instancia: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(id: &str) -> MinecraftInstance {
+        let mut instance = MinecraftInstance::new();
+        instance.instanceId = id.to_string();
+        instance.instanceName = format!("Instance {}", id);
+        instance
+    }
+
+    #[test]
+    fn test_reserve_refuses_second_launch() {
+        let mut registry = Registry::default();
+        let first = registry.reserve(&instance("a"), 10).unwrap();
+        assert_eq!(first.state, RunState::Launching);
+        assert!(registry.reserve(&instance("a"), 20).is_err());
+        assert!(registry.reserve(&instance("b"), 20).is_ok());
+
+        // A launch still preparing files has no process to kill yet
+        assert!(registry.stop("a").is_err());
+        assert!(registry.stop("missing").is_err());
+
+        assert_eq!(registry.release("a").unwrap().state, RunState::Stopped);
+        assert!(registry.release("a").is_none());
+        assert!(registry.reserve(&instance("a"), 30).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_attach_and_stop() {
+        let mut registry = Registry::default();
+        registry.reserve(&instance("a"), 10).unwrap();
+
+        let child = Arc::new(Mutex::new(
+            std::process::Command::new("true").spawn().unwrap(),
+        ));
+        let pid = child.lock().unwrap().id();
+        let running = registry.attach(&instance("a"), pid, child.clone(), 50);
+        assert_eq!(running.state, RunState::Running);
+        assert_eq!(running.pid, Some(pid));
+        assert_eq!(running.started_at, 10);
+
+        let (stopping, stopped_child) = registry.stop("a").unwrap();
+        assert_eq!(stopping.state, RunState::Stopping);
+        assert!(Arc::ptr_eq(&stopped_child, &child));
+        assert_eq!(registry.list()[0].state, RunState::Stopping);
+
+        child.lock().unwrap().wait().unwrap();
+    }
+
+    #[test]
+    fn test_java_users() {
+        let mut registry = Registry::default();
+        registry.reserve(&instance("a"), 10).unwrap();
+        registry.reserve(&instance("b"), 20).unwrap();
+        let runtime = Path::new("/runtimes/java-runtime-gamma");
+
+        // Neither launch has picked its Java yet
+        assert_eq!(registry.java_users(|home| home == runtime).len(), 2);
+
+        registry.set_java_home("a", runtime.to_path_buf());
+        registry.set_java_home("b", PathBuf::from("/usr/lib/jvm/java-17"));
+        assert_eq!(
+            registry.java_users(|home| home == runtime),
+            vec!["Instance a".to_string()]
+        );
+
+        registry.release("a");
+        assert!(registry.java_users(|home| home == runtime).is_empty());
+    }
+}
@@ -319,6 +319,36 @@ pub fn task_exists(id: &str) -> bool {
     }
 }
 
+/// Whether the user cancelled this task with `cancel_task`
+pub fn is_task_cancelled(id: &str) -> bool {
+    get_task(id).is_some_and(|task| task.status == TaskStatus::Cancelled)
+}
+
+// Tauri command to cancel a task that declared itself cancellable (`"cancellable": true`
+// in its data). The task's owner polls `is_task_cancelled` and stops on its own.
+#[tauri::command]
+pub fn cancel_task(id: String) -> Result<(), String> {
+    let task = get_task(&id).ok_or_else(|| format!("Task {} not found", id))?;
+    let cancellable = task
+        .data
+        .as_ref()
+        .and_then(|data| data["cancellable"].as_bool())
+        .unwrap_or(false);
+    if !cancellable {
+        return Err("Esta tarea no se puede cancelar".to_string());
+    }
+
+    info!("Cancelling task: {} ({})", task.id, task.label);
+    update_task(
+        &id,
+        TaskStatus::Cancelled,
+        task.progress,
+        "Cancelado por el usuario",
+        None,
+    );
+    Ok(())
+}
+
 // Tauri command to expose get_all_tasks to frontend for synchronization
 #[tauri::command]
 pub fn get_all_tasks_command() -> Vec<TaskInfo> {
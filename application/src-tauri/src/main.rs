@@ -154,6 +154,13 @@ pub fn main() {
             core::onboarding::validate_java_installation,
             core::onboarding::install_java,
             core::onboarding::repair_java_installation,
+            core::java_inventory::get_java_installations,
+            core::java_inventory::remove_java_runtime,
+            core::java_inventory::verify_java_installation,
+            core::minecraft::wrappers::detect_launch_wrappers,
+            core::game_log::get_instance_log,
+            core::running_instances::get_running_instances,
+            core::running_instances::kill_instance,
             core::network_utilities::check_connection,
             core::network_utilities::check_real_connection,
             core::instance_manager::get_all_instances,
@@ -165,6 +172,10 @@ pub fn main() {
             core::instance_manager::update_instance,
             core::instance_manager::create_local_instance,
             core::instance_manager::create_modpack_instance,
+            core::modpack_import::curseforge::import_curseforge_zip,
+            core::modpack_import::instance_archive::import_instance_archive,
+            core::modpack_import::mrpack::import_mrpack,
+            core::instance_export::export_instance,
             core::instance_manager::check_modpack_updates,
             core::instance_manager::update_modpack_instance,
             core::instance_manager::validate_modpack_password,
@@ -191,6 +202,7 @@ pub fn main() {
             core::prelaunch_appearance::update_prelaunch_appearance,
             core::tasks_manager::get_all_tasks_command,
             core::tasks_manager::resync_tasks_command,
+            core::tasks_manager::cancel_task,
             utils::desktop_integration::create_shortcut,
             get_git_hash,
             splash_done,
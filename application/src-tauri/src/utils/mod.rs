@@ -1,2 +1,3 @@
 pub mod config_manager;
 pub mod desktop_integration;
+pub mod safe_path;
@@ -0,0 +1,248 @@
+// src-tauri/src/utils/safe_path.rs
+//! Resolution of untrusted relative paths (manifest entries, archive entry names)
+//! against a root directory.
+//!
+//! Every writer that takes a path from the network or from an archive must go through
+//! [`resolve_within`] so a malicious manifest or zip cannot write outside the instance
+//! or runtime directory ("zip-slip").
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Splits an untrusted relative path into its normal components.
+///
+/// Both `/` and `\` are treated as separators so Windows-style traversal is caught on
+/// every platform. Rejects absolute paths, `..`, drive letters, UNC prefixes and
+/// anything that would resolve to the root itself.
+pub fn sanitize_relative_path(relative: &str) -> Result<PathBuf, String> {
+    if relative.trim().is_empty() {
+        return Err("Empty path".to_string());
+    }
+
+    if relative.contains('\0') {
+        return Err(format!("Path contains a NUL byte: {:?}", relative));
+    }
+
+    if relative.starts_with('/') || relative.starts_with('\\') {
+        return Err(format!("Absolute paths are not allowed: {}", relative));
+    }
+
+    let mut sanitized = PathBuf::new();
+    for part in relative.split(['/', '\\']) {
+        match part {
+            "" | "." => continue,
+            ".." => {
+                return Err(format!(
+                    "Parent directory traversal is not allowed: {}",
+                    relative
+                ))
+            }
+            // `C:`, `C:foo` or NTFS alternate data streams (`file.jar:stream`)
+            _ if part.contains(':') => {
+                return Err(format!(
+                    "Drive or stream prefixes are not allowed: {}",
+                    relative
+                ))
+            }
+            _ => sanitized.push(part),
+        }
+    }
+
+    // Belt and braces: the platform parser must agree that only normal components remain
+    if sanitized.as_os_str().is_empty()
+        || !sanitized
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+    {
+        return Err(format!("Invalid relative path: {}", relative));
+    }
+
+    Ok(sanitized)
+}
+
+/// Archive entries such as `./` that only name the extraction root itself
+pub fn is_archive_root_entry(name: &str) -> bool {
+    name.split(['/', '\\'])
+        .all(|part| part.is_empty() || part == ".")
+}
+
+/// Resolves `relative` under `root`, refusing paths that escape it either lexically
+/// or through a symlink already present on disk.
+pub fn resolve_within(root: &Path, relative: &str) -> Result<PathBuf, String> {
+    let sanitized = sanitize_relative_path(relative)?;
+    let resolved = root.join(&sanitized);
+    ensure_no_symlink_escape(root, &sanitized)?;
+    Ok(resolved)
+}
+
+/// Resolves the target of a symlink (or hardlink) entry found in an archive.
+///
+/// `link_relative` is the link's own sanitized location under `root`. Relative targets
+/// may use `..` as long as they stay inside `root`; absolute targets are rejected.
+pub fn resolve_link_target(
+    root: &Path,
+    link_relative: &Path,
+    target: &Path,
+) -> Result<PathBuf, String> {
+    let target_str = target.to_string_lossy();
+    if target.has_root()
+        || target_str.starts_with('/')
+        || target_str.starts_with('\\')
+        || target_str.contains(':')
+    {
+        return Err(format!(
+            "Absolute link targets are not allowed: {}",
+            target_str
+        ));
+    }
+
+    let mut stack: Vec<String> = link_relative
+        .parent()
+        .map(|p| {
+            p.components()
+                .filter_map(|c| match c {
+                    Component::Normal(part) => Some(part.to_string_lossy().to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for part in target_str.split(['/', '\\']) {
+        match part {
+            "" | "." => continue,
+            ".." => {
+                if stack.pop().is_none() {
+                    return Err(format!(
+                        "Link {} escapes the extraction directory via {}",
+                        link_relative.display(),
+                        target_str
+                    ));
+                }
+            }
+            _ => stack.push(part.to_string()),
+        }
+    }
+
+    let resolved: PathBuf = stack.iter().collect();
+    ensure_no_symlink_escape(root, &resolved)?;
+    Ok(root.join(resolved))
+}
+
+/// Walks every existing prefix of `root/sanitized` and checks that symlinks found on
+/// the way point back inside `root`.
+fn ensure_no_symlink_escape(root: &Path, sanitized: &Path) -> Result<(), String> {
+    // Nothing below a missing root can be a symlink yet
+    let canonical_root = match fs::canonicalize(root) {
+        Ok(path) => path,
+        Err(_) => return Ok(()),
+    };
+
+    let mut current = root.to_path_buf();
+    for component in sanitized.components() {
+        current.push(component);
+
+        let metadata = match fs::symlink_metadata(&current) {
+            Ok(metadata) => metadata,
+            // The rest of the path does not exist yet, so it cannot contain symlinks
+            Err(_) => return Ok(()),
+        };
+
+        if metadata.file_type().is_symlink() {
+            let escapes = match fs::canonicalize(&current) {
+                Ok(target) => !target.starts_with(&canonical_root),
+                // Dangling link: following it on write could land anywhere
+                Err(_) => true,
+            };
+            if escapes {
+                return Err(format!(
+                    "Symlink {} points outside of {}",
+                    current.display(),
+                    root.display()
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_rejects_traversal_and_absolute_paths() {
+        let root = Path::new("/instances/pack/minecraft");
+        for malicious in [
+            "../../.bashrc",
+            "mods/../../escape.jar",
+            "..\\..\\AppData\\evil.dll",
+            "/etc/passwd",
+            "\\\\server\\share\\evil.jar",
+            "C:\\Windows\\System32\\evil.dll",
+            "C:evil.dll",
+            "mods/file.jar:stream",
+            "",
+            "./.",
+        ] {
+            assert!(
+                resolve_within(root, malicious).is_err(),
+                "accepted malicious path {:?}",
+                malicious
+            );
+        }
+    }
+
+    #[test]
+    fn test_accepts_regular_relative_paths() {
+        let root = Path::new("/instances/pack/minecraft");
+        assert_eq!(
+            resolve_within(root, "mods/./example.jar").unwrap(),
+            root.join("mods").join("example.jar")
+        );
+        assert_eq!(
+            resolve_within(root, "config\\mod\\settings.toml").unwrap(),
+            root.join("config").join("mod").join("settings.toml")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_symlink_escaping_root() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("minecraft");
+        let outside = dir.path().join("outside");
+        fs::create_dir_all(&root).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("mods")).unwrap();
+        std::os::unix::fs::symlink(root.join("config-real"), root.join("config")).unwrap();
+        fs::create_dir_all(root.join("config-real")).unwrap();
+
+        assert!(resolve_within(&root, "mods/evil.jar").is_err());
+        // Links that stay inside the root are fine
+        assert!(resolve_within(&root, "config/settings.toml").is_ok());
+    }
+
+    #[test]
+    fn test_link_targets_must_stay_inside_root() {
+        let root = Path::new("/runtime/jdk");
+        let link = Path::new("lib/legal/LICENSE");
+
+        assert_eq!(
+            resolve_link_target(root, link, Path::new("../../legal/LICENSE")).unwrap(),
+            root.join("legal").join("LICENSE")
+        );
+        assert!(resolve_link_target(root, link, Path::new("../../../../etc/shadow")).is_err());
+        assert!(resolve_link_target(root, link, Path::new("/etc/shadow")).is_err());
+    }
+
+    #[test]
+    fn test_sanitize_keeps_unicode_names() {
+        assert_eq!(
+            sanitize_relative_path("shaderpacks/Complementary Shaders ñ.zip").unwrap(),
+            PathBuf::from("shaderpacks").join("Complementary Shaders ñ.zip")
+        );
+    }
+}
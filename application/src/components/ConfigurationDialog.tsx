@@ -290,6 +290,20 @@ export const ConfigurationDialog = ({ isOpen, onClose }: ConfigurationDialogProp
                     </Select>
                 );
 
+            case "list":
+                // Una entrada por elemento separada por comas; cada una puede llevar argumentos
+                return (
+                    <Input
+                        {...commonInputProps}
+                        value={Array.isArray(value) ? value.join(', ') : ''}
+                        placeholder="gamemoderun, mangohud"
+                        onChange={(e) => handleConfigChange(key,
+                            // Las entradas vacías se ignoran al lanzar
+                            e.target.value.split(',').map((item) => item.trimStart())
+                        )}
+                    />
+                );
+
             default:
                 return (
                     <Input
@@ -45,7 +45,9 @@ const getStepDisplayName = (step: string): string => {
         ExtractingNatives: "Extrayendo librerías nativas",
         DownloadingForgeInstaller: "Descargando instalador de Forge",
         RunningForgeInstaller: "Ejecutando instalador de Forge",
-        CreatingLauncherProfiles: "Creando perfiles del launcher"
+        CreatingLauncherProfiles: "Creando perfiles del launcher",
+        VerifyingManifestSignature: "Verificando firma del modpack",
+        InstallingModLoader: "Instalando mod loader"
     };
     return stepNames[step] || step;
 };
@@ -26,6 +26,12 @@ import { useNavigate } from "react-router-dom";
 //                            onDelete={() => openDeleteDialog(instance)}
 
 
+const LOADER_NAMES: Record<string, string> = {
+    neoforge: "NeoForge",
+    fabric: "Fabric",
+    quilt: "Quilt",
+};
+
 export const InstanceCard = ({ instance, className = "", running, onInstanceRemoved, isBootstrapping }: { instance: any, className?: string, running?: boolean, onInstanceRemoved: () => void, isBootstrapping: boolean }) => {
     const [isOpen, setIsOpen] = useState(false)
     const [showDeleteAlert, setShowDeleteAlert] = useState(false)
@@ -172,6 +178,13 @@ export const InstanceCard = ({ instance, className = "", running, onInstanceRemo
                                                 </p>
                                             )
                                         }
+                                        {
+                                            !instance.forgeVersion && instance.loaderVersion && LOADER_NAMES[instance.loader] && (
+                                                <p className="text-xs text-gray-400">
+                                                    {LOADER_NAMES[instance.loader]} {instance.loaderVersion}
+                                                </p>
+                                            )
+                                        }
                                     </div>
 
                                 </div>
@@ -1,11 +1,21 @@
 import { createContext, useContext, useEffect, useState } from "react";
 import { listen } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { trackEvent } from "@aptabase/web";
 import { toast } from "sonner";
 import { playSound } from "@/utils/sounds";
 import { InstallationStage, StageEventPayload } from "@/types/InstallationStage";
 
+type RunningInstance = {
+    instanceId: string;
+    instanceName: string;
+    accountUuid?: string | null;
+    pid?: number | null;
+    startedAt: number;
+    state: "launching" | "running" | "stopping" | "stopped";
+};
+
 type InstanceState = {
     id: string;
     name: string;
@@ -120,7 +130,7 @@ export const InstancesProvider = ({ children }: { children: React.ReactNode }) =
             // Evento para cuando la instancia ha salido
             const exitedUnlisten = await listen("instance-exited", (e: any) => {
                 const { id, message, data, name: instanceName } = e.payload;
-                const { exitCode, possibleErrorCode } = data || { exitCode: "desconocido" };
+                const { exitCode, possibleErrorCode, killed } = data || { exitCode: "desconocido" };
                 console.log(e.payload);
 
                 trackEvent("instance_exited", {
@@ -138,7 +148,7 @@ export const InstancesProvider = ({ children }: { children: React.ReactNode }) =
                 window.unminimize();
                 window.setFocus();
 
-                if (exitCode !== 0) {
+                if (exitCode !== 0 && !killed) {
                     const errorDesc = possibleErrorCode === "UNKNOWN_ERROR"
                         ? `Esto puede ser causado por un error en la configuración de la instancia o un problema con tu instalación de Java.`
                         : `Código de error: ${exitCode}`;
@@ -238,6 +248,17 @@ export const InstancesProvider = ({ children }: { children: React.ReactNode }) =
             });
             unlistenList.push(installingForgeUnlisten);
 
+            const installingLoaderUnlisten = await listen("instance-installing-loader", (e: any) => {
+                const { id, message } = e.payload;
+                console.log("Installing loader event:", { id, message });
+
+                updateInstance(id, {
+                    status: "downloading-assets",
+                    message: message || "Instalando mod loader...",
+                });
+            });
+            unlistenList.push(installingLoaderUnlisten);
+
             // Update the existing downloading assets listener to handle stages
             const downloadingAssetsStageUnlisten = await listen("instance-downloading-assets", (e: any) => {
                 const { id, message, stage } = e.payload as StageEventPayload;
@@ -301,6 +322,35 @@ export const InstancesProvider = ({ children }: { children: React.ReactNode }) =
                 });
             });
             unlistenList.push(vanillaBootstrappedUnlisten);
+
+            for (const loader of ["neoforge", "fabric", "quilt"]) {
+                const loaderBootstrappedUnlisten = await listen(`${loader}-instance-bootstrapped`, (e: any) => {
+                    const { id, message } = e.payload;
+                    console.log("Loader bootstrapped event:", { loader, id, message });
+
+                    updateInstance(id, {
+                        status: "idle",
+                        message: message || "Bootstrap completado",
+                        stage: undefined
+                    });
+                });
+                unlistenList.push(loaderBootstrappedUnlisten);
+            }
+
+            // Tras recargar la UI, recuperar las instancias que siguen en ejecución
+            try {
+                const running = await invoke<RunningInstance[]>("get_running_instances");
+                running.forEach((instance) => addInstance({
+                    id: instance.instanceId,
+                    name: instance.instanceName,
+                    status: instance.state === "launching" ? "preparing" : "running",
+                    message: instance.state === "launching"
+                        ? "Iniciando la instancia..."
+                        : "Minecraft está ejecutándose",
+                }));
+            } catch (error) {
+                console.error("Error al obtener las instancias en ejecución:", error);
+            }
         };
 
         setupListeners();
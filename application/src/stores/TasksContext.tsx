@@ -17,9 +17,11 @@ export type BootstrapStep =
     | "ExtractingNatives"
     | "DownloadingForgeInstaller"
     | "RunningForgeInstaller"
-    | "CreatingLauncherProfiles";
+    | "CreatingLauncherProfiles"
+    | "VerifyingManifestSignature"
+    | "InstallingModLoader";
 
-export type ErrorCategory = "Network" | "Filesystem" | "Java" | "Forge" | "Configuration" | "Other";
+export type ErrorCategory = "Network" | "Filesystem" | "Java" | "Forge" | "Configuration" | "Security" | "Other";
 
 export type BootstrapError = {
     step: BootstrapStep;
@@ -160,7 +162,9 @@ export const TasksProvider = ({ children }: { children: React.ReactNode }) => {
             ExtractingNatives: "extrayendo librerías nativas",
             DownloadingForgeInstaller: "descargando instalador de Forge",
             RunningForgeInstaller: "ejecutando instalador de Forge",
-            CreatingLauncherProfiles: "creando perfiles del launcher"
+            CreatingLauncherProfiles: "creando perfiles del launcher",
+            VerifyingManifestSignature: "verificando firma del modpack",
+            InstallingModLoader: "instalando mod loader"
         };
 
         const stepName = stepMessages[error.step] || "realizando operación";
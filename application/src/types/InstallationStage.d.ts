@@ -34,13 +34,20 @@ export interface StageDownloadingModpackFiles {
     total: number;
 }
 
+export interface StageRunningForgeProcessors {
+    type: "RunningForgeProcessors";
+    current: number;
+    total: number;
+}
+
 export type InstallationStage = 
     | StageDownloadingFiles 
     | StageExtractingLibraries 
     | StageInstallingForge 
     | StageValidatingAssets
     | StageDownloadingForgeLibraries
-    | StageDownloadingModpackFiles;
+    | StageDownloadingModpackFiles
+    | StageRunningForgeProcessors;
 
 // Event payload type that includes stage information
 export interface StageEventPayload {
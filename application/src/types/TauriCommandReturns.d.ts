@@ -18,6 +18,59 @@ interface MinecraftInstance {
     minecraftVersion: string;
     instanceDirectory?: string;
     forgeVersion?: string;
+    loader?: "vanilla" | "forge" | "neoforge" | "fabric" | "quilt" | null;
+    loaderVersion?: string | null;
+    /** Java home used instead of the automatic selection while `javaPathPinned` is set */
+    javaPath?: string | null;
+    javaPathPinned?: boolean;
+    launchOptions?: LaunchOptions;
+}
+
+interface LaunchOptions {
+    maxMemory?: number | null;
+    minMemory?: number | null;
+    jvmArgs: string[];
+    gameArgs: string[];
+    env: Record<string, string>;
+    wrappers?: string[] | null;
+    hooks?: LaunchHooks;
+}
+
+interface LaunchHooks {
+    preLaunch?: string | null;
+    postExit?: string | null;
+    timeoutSecs?: number | null;
+}
+
+interface LaunchWrapper {
+    name: string;
+    path?: string | null;
+}
+
+interface LogRecord {
+    stream: "stdout" | "stderr";
+    timestamp: number;
+    level: "TRACE" | "DEBUG" | "INFO" | "WARN" | "ERROR" | "FATAL" | string;
+    thread?: string | null;
+    logger?: string | null;
+    message: string;
+    throwable?: string | null;
+}
+
+interface JavaProbe {
+    version?: string;
+    majorVersion?: number;
+    vendor?: string;
+    arch?: string;
+}
+
+interface JavaInstallation extends JavaProbe {
+    id?: string;
+    source: "managed" | "system";
+    path: string;
+    valid: boolean;
+    sizeBytes: number;
+    instances: { instanceId: string; instanceName: string; pinned: boolean }[];
 }
 
 export type TauriCommandReturns = {
@@ -30,4 +83,8 @@ export type TauriCommandReturns = {
     "search_instances": MinecraftInstance[];
     "create_instance": MinecraftInstance;
     "create_modpack_instance": MinecraftInstance;
+    "get_java_installations": JavaInstallation[];
+    "verify_java_installation": JavaProbe;
+    "detect_launch_wrappers": LaunchWrapper[];
+    "get_instance_log": LogRecord[];
 }
\ No newline at end of file
@@ -30,6 +30,10 @@ export function formatStageMessage(stage: InstallationStage | undefined, fallbac
             const modpackFilesPercentage = stage.total > 0 ? Number(((stage.current * 100) / stage.total).toFixed(1)) : 0;
             return `Descargando archivos del modpack: ${stage.current}/${stage.total} (${modpackFilesPercentage.toFixed(1)}%)`;
 
+        case "RunningForgeProcessors":
+            const processorsPercentage = stage.total > 0 ? Number(((stage.current * 100) / stage.total).toFixed(1)) : 0;
+            return `Ejecutando procesadores de Forge: ${stage.current}/${stage.total} (${processorsPercentage.toFixed(1)}%)`;
+
         default:
             return fallbackMessage;
     }
@@ -44,6 +48,7 @@ export function getStageProgress(stage: InstallationStage | undefined): number |
         case "ValidatingAssets":
         case "DownloadingForgeLibraries":
         case "DownloadingModpackFiles":
+        case "RunningForgeProcessors":
             return stage.total > 0 ? Number(((stage.current / stage.total) * 100).toFixed(1)) : 0;
         case "InstallingForge":
             return undefined; // No progress for Forge installation